@@ -0,0 +1,31 @@
+use std::time::Instant;
+
+use od_opencv::{ImageBuffer, Model};
+
+fn main() {
+    // Initialize ort runtime
+    ort::init().commit().expect("Failed to initialize ORT");
+
+    let net_width = 640;
+    let net_height = 640;
+
+    let mut model = Model::ort_v10(
+        "pretrained/yolov10n.onnx",
+        (net_width, net_height),
+    ).expect("Failed to load model");
+
+    let img = image::open("images/dog.jpg").expect("Failed to load image");
+    let img_buffer = ImageBuffer::from_dynamic_image(img);
+
+    let start = Instant::now();
+    let (bboxes, class_ids, confidences) = model.forward(&img_buffer, 0.25).expect("Inference failed");
+    println!("Inference time: {:?}", start.elapsed());
+
+    // Class names come from the export metadata
+    let class_names = model.class_names();
+    for (i, bbox) in bboxes.iter().enumerate() {
+        println!("Class: {}", class_names.get(class_ids[i]).map_or("unknown", String::as_str));
+        println!("\tBounding box: x={}, y={}, w={}, h={}", bbox.x, bbox.y, bbox.width, bbox.height);
+        println!("\tConfidence: {:.2}", confidences[i]);
+    }
+}
@@ -6,8 +6,6 @@ fn main() {
     // Initialize ort runtime
     ort::init().commit().expect("Failed to initialize ORT");
 
-    let classes_labels: Vec<&str> = vec!["person", "bicycle", "car", "motorbike", "aeroplane", "bus", "train", "truck", "boat", "traffic light", "fire hydrant", "stop sign", "parking meter", "bench", "bird", "cat", "dog", "horse", "sheep", "cow", "elephant", "bear", "zebra", "giraffe", "backpack", "umbrella", "handbag", "tie", "suitcase", "frisbee", "skis", "snowboard", "sports ball", "kite", "baseball bat", "baseball glove", "skateboard", "surfboard", "tennis racket", "bottle", "wine glass", "cup", "fork", "knife", "spoon", "bowl", "banana", "apple", "sandwich", "orange", "broccoli", "carrot", "hot dog", "pizza", "donut", "cake", "chair", "sofa", "pottedplant", "bed", "diningtable", "toilet", "tvmonitor", "laptop", "mouse", "remote", "keyboard", "cell phone", "microwave", "oven", "toaster", "sink", "refrigerator", "book", "clock", "vase", "scissors", "teddy bear", "hair drier", "toothbrush"];
-
     let net_width = 640;
     let net_height = 640;
 
@@ -23,8 +21,10 @@ fn main() {
     let (bboxes, class_ids, confidences) = model.forward(&img_buffer, 0.25, 0.4).expect("Inference failed");
     println!("Inference time: {:?}", start.elapsed());
 
+    // Class names come from the export metadata
+    let class_names = model.class_names();
     for (i, bbox) in bboxes.iter().enumerate() {
-        println!("Class: {}", classes_labels[class_ids[i]]);
+        println!("Class: {}", class_names.get(class_ids[i]).map_or("unknown", String::as_str));
         println!("\tBounding box: x={}, y={}, w={}, h={}", bbox.x, bbox.y, bbox.width, bbox.height);
         println!("\tConfidence: {:.2}", confidences[i]);
     }
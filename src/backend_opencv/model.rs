@@ -1,3 +1,5 @@
+use crate::model_trait::DetectionVecs;
+
 use super::model_format::{
     ModelFormat,
     ModelVersion
@@ -15,7 +17,7 @@ use opencv::{
 /// Just a trait wrapper for models
 /// Should be used in scenarios when you uncertain about model type in compile time
 pub trait ModelTrait {
-    fn forward(&mut self, image: &Mat, conf_threshold: f32, nms_threshold: f32) -> Result<(Vec<Rect>, Vec<usize>, Vec<f32>), Error>;
+    fn forward(&mut self, image: &Mat, conf_threshold: f32, nms_threshold: f32) -> Result<DetectionVecs<Rect>, Error>;
 }
 
 /// Creates model from file
@@ -1,15 +1,17 @@
 use opencv::{
-    core::Mat, core::Rect, core::Scalar, core::Size, core::Vector, core::VectorToVec, core::CV_32F,
-    dnn::blob_from_image, dnn::nms_boxes, dnn::read_net, dnn::read_net_from_onnx, dnn::Net,
+    core::Mat, core::Rect, core::Scalar, core::Size, core::Vector, core::CV_32F,
+    dnn::blob_from_image, dnn::blob_from_images, dnn::read_net, dnn::read_net_from_onnx, dnn::Net,
     imgproc::resize, imgproc::INTER_AREA, prelude::MatTraitConst, prelude::MatTraitConstManual,
     prelude::NetTrait, prelude::NetTraitConst, Error,
 };
 
-use crate::BBox;
+use crate::darknet_cfg::DarknetConfig;
+use crate::postprocess::NmsStrategy;
+use crate::model_trait::DetectionVecs;
 
 use super::model::ModelTrait;
 use super::model_format::ModelFormat;
-use super::utils::BACKEND_TARGET_VALID;
+use super::utils::{BACKEND_TARGET_VALID, nms_filter};
 
 const YOLO_BLOB_MEAN: (f64, f64, f64, f64) = (0.0, 0.0, 0.0, 0.0);
 
@@ -30,6 +32,8 @@ pub struct ModelYOLOClassic {
     out_layers: Vector<String>,
     // Set of classes which will be used to filter detections
     filter_classes: Vec<usize>,
+    // NMS algorithm applied to the detections
+    nms_strategy: NmsStrategy,
 }
 
 impl ModelYOLOClassic {
@@ -124,14 +128,21 @@ impl ModelYOLOClassic {
         )
     }
     /// Reads file in Darknet specification and prepares model
+    ///
+    /// The cfg file is parsed first: `net_size` is checked against its `[net]` width/height (or taken from it when `None`)
+    /// and `filter_classes` against its class count.
     pub fn new_from_darknet_file(
         weight_file_path: &str,
         cfg_file_path: &str,
-        net_size: (i32, i32),
+        net_size: impl Into<Option<(i32, i32)>>,
         backend_id: i32,
         target_id: i32,
         filter_classes: Vec<usize>,
     ) -> Result<Self, Error> {
+        let cfg = DarknetConfig::from_file(cfg_file_path).map_err(|e| Error::new(400, e.to_string()))?;
+        let net_size: Option<(i32, i32)> = net_size.into();
+        let net_size = net_size.unwrap_or(cfg.input_size());
+        cfg.validate(net_size, &filter_classes).map_err(|e| Error::new(400, e.to_string()))?;
         ModelYOLOClassic::new_from_dnn(
             read_net(weight_file_path, cfg_file_path, "Darknet")?,
             net_size,
@@ -180,14 +191,23 @@ impl ModelYOLOClassic {
             blob_name: "",
             out_layers: out_layers,
             filter_classes: filter_classes,
+            nms_strategy: NmsStrategy::default(),
         })
     }
+
+    /// Sets the NMS algorithm applied to the detections.
+    ///
+    /// Default is [`NmsStrategy::Greedy`] (OpenCV's `nms_boxes`).
+    pub fn set_nms_strategy(&mut self, strategy: NmsStrategy) {
+        self.nms_strategy = strategy;
+    }
+
     pub fn forward(
         &mut self,
         image: &Mat,
         conf_threshold: f32,
         nms_threshold: f32,
-    ) -> Result<(Vec<Rect>, Vec<usize>, Vec<f32>), Error> {
+    ) -> Result<DetectionVecs<Rect>, Error> {
         let image_width = image.cols();
         let image_height = image.rows();
         let image_width_f32 = image_width as f32;
@@ -235,102 +255,157 @@ impl ModelYOLOClassic {
         let mut confidences = Vector::<f32>::new();
         let mut class_ids = Vec::new();
 
-        // Specific to YOLOv3, YOLOv4, YOLOv7 reading detections vector
-        for layer in detections {
-            let num_boxes = layer.rows();
-            for index in 0..num_boxes {
-                let pred = layer.row(index)?;
-                let detection = pred.data_typed::<f32>()?;
-                let (center_x, center_y, width, height, confidence) = match &detection[0..5] {
-                    &[a,b,c,d,e] => (a * image_width_f32, b * image_height_f32, c * image_width_f32, d * image_height_f32, e),
-                    _ => {
-                        return Err(Error::new(500, "Can't extract (center_x, center_y, width, height, confidence) from detection vector"))
-                    }
-                };
-                let detected_classes = &detection[5..];
-                if confidence > conf_threshold {
-                    let mut class_index = -1;
-                    let mut score = 0.0;
-                    for (idx, &val) in detected_classes.iter().enumerate() {
-                        if val > score {
-                            class_index = idx as i32;
-                            score = val;
-                        }
-                    }
-                    if class_index > -1 && score > 0. {
-                        let class_id = class_index as usize;
-                        if self.filter_classes.len() > 0 && !self.filter_classes.contains(&class_id)
-                        {
-                            continue;
-                        }
-                        let left = center_x - width / 2.0;
-                        let top = center_y - height / 2.0;
-                        let bbox = Rect::new(
-                            left.floor() as i32,
-                            top.floor() as i32,
-                            width as i32,
-                            height as i32,
-                        );
-                        class_ids.push(class_id);
-                        confidences.push(confidence);
-                        bboxes.push(bbox);
-                    }
-                }
+        for layer in detections.iter() {
+            let cols = *layer.mat_size().last().unwrap_or(&0) as usize;
+            for (bbox, class_id, confidence) in self.decode_layer(layer.data_typed::<f32>()?, cols, (image_width_f32, image_height_f32), conf_threshold)? {
+                bboxes.push(bbox);
+                class_ids.push(class_id);
+                confidences.push(confidence);
             }
         }
 
         // Run NMS on collected detections to filter duplicates and overlappings
-        let mut indices = Vector::<i32>::new();
-        nms_boxes(
+        nms_filter(
             &bboxes,
             &confidences,
+            &class_ids,
             conf_threshold,
             nms_threshold,
-            &mut indices,
-            1.0,
-            0,
+            &self.nms_strategy,
+            false,
+        )
+    }
+
+    /// Runs forward pass on several images at once.
+    ///
+    /// Every image is resized to the network size and the results are stacked with
+    /// `blob_from_images` into a single `[B, 3, H, W]` blob. When the batched forward pass fails
+    /// or the outputs do not hold `B` images (fixed batch size networks), it falls back to one
+    /// forward pass per image.
+    ///
+    /// Returns one (bounding boxes, class IDs, confidences) tuple per image, in input order.
+    pub fn forward_batch(
+        &mut self,
+        images: &[Mat],
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<Vec<DetectionVecs<Rect>>, Error> {
+        if images.len() < 2 {
+            return images.iter().map(|image| self.forward(image, conf_threshold, nms_threshold)).collect();
+        }
+        let batch_size = images.len();
+
+        let mut resized_frames = Vector::<Mat>::new();
+        for image in images {
+            let mut resized_frame: Mat = Mat::default();
+            resize(image, &mut resized_frame, self.input_size, 1.0, 1.0, INTER_AREA)?;
+            resized_frames.push(resized_frame);
+        }
+        let blobimg = blob_from_images(
+            &resized_frames,
+            self.blob_scale,
+            self.input_size,
+            self.blob_mean,
+            true,
+            false,
+            CV_32F,
         )?;
 
-        let mut nms_bboxes = vec![];
-        let mut nms_classes_ids = vec![];
-        let mut nms_confidences = vec![];
+        let mut detections = Vector::<Mat>::new();
+        let batched = self
+            .net
+            .set_input(&blobimg, self.blob_name, 1.0, self.blob_mean)
+            .and_then(|_| self.net.forward(&mut detections, &self.out_layers));
+        // ONNX layers are [B, rows, cols], Darknet region layers stack the images along the rows
+        let layers_ok = batched.is_ok() && detections.iter().all(|layer| {
+            let cols = *layer.mat_size().last().unwrap_or(&0);
+            let holds_batch = match layer.dims() {
+                3 => layer.mat_size()[0] == batch_size as i32,
+                2 => layer.rows() % batch_size as i32 == 0,
+                _ => false,
+            };
+            cols > 5 && holds_batch
+        });
+        if !layers_ok {
+            return images.iter().map(|image| self.forward(image, conf_threshold, nms_threshold)).collect();
+        }
 
-        let indices_vec = indices.to_vec();
-        let mut bboxes = bboxes.to_vec();
-        nms_bboxes.extend(bboxes.drain(..).enumerate().filter_map(|(idx, item)| {
-            if indices_vec.contains(&(idx as i32)) {
-                Some(item)
-            } else {
-                None
-            }
-        }));
+        let mut results = Vec::with_capacity(batch_size);
+        for (batch_index, image) in images.iter().enumerate() {
+            let image_size = (image.cols() as f32, image.rows() as f32);
+            let mut bboxes = Vector::<Rect>::new();
+            let mut confidences = Vector::<f32>::new();
+            let mut class_ids = Vec::new();
 
-        nms_classes_ids.extend(class_ids.drain(..).enumerate().filter_map(|(idx, item)| {
-            if indices_vec.contains(&(idx as i32)) {
-                Some(item)
-            } else {
-                None
+            for layer in detections.iter() {
+                let cols = *layer.mat_size().last().unwrap_or(&0) as usize;
+                let rows_per_image = layer.total() / cols / batch_size;
+                let data = layer.data_typed::<f32>()?;
+                let image_rows = &data[batch_index * rows_per_image * cols..(batch_index + 1) * rows_per_image * cols];
+                for (bbox, class_id, confidence) in self.decode_layer(image_rows, cols, image_size, conf_threshold)? {
+                    bboxes.push(bbox);
+                    class_ids.push(class_id);
+                    confidences.push(confidence);
+                }
             }
-        }));
+            results.push(nms_filter(&bboxes, &confidences, &class_ids, conf_threshold, nms_threshold, &self.nms_strategy, false)?);
+        }
+        Ok(results)
+    }
 
-        nms_confidences.extend(confidences.to_vec().drain(..).enumerate().filter_map(
-            |(idx, item)| {
-                if indices_vec.contains(&(idx as i32)) {
-                    Some(item)
-                } else {
-                    None
-                }
-            },
-        ));
+    /// Decodes the `[center_x, center_y, width, height, confidence, class scores...]` rows of an output layer.
+    ///
+    /// Shared by `forward` and `forward_batch`: `layer_rows` holds the `cols`-wide rows of one image,
+    /// boxes are scaled to `image_size` (width, height). Returns (bounding box, class ID, confidence)
+    /// candidates before NMS.
+    fn decode_layer(&self, layer_rows: &[f32], cols: usize, image_size: (f32, f32), conf_threshold: f32) -> Result<Vec<(Rect, usize, f32)>, Error> {
+        if cols < 5 {
+            return Err(Error::new(500, "Can't extract (center_x, center_y, width, height, confidence) from detection vector"));
+        }
+        let (image_width, image_height) = image_size;
 
-        Ok((nms_bboxes, nms_classes_ids, nms_confidences))
+        let mut candidates = Vec::new();
+        // Specific to YOLOv3, YOLOv4, YOLOv7 reading detections vector
+        for detection in layer_rows.chunks_exact(cols) {
+            let confidence = detection[4];
+            if confidence <= conf_threshold {
+                continue;
+            }
+            let mut class_index = -1;
+            let mut score = 0.0;
+            for (idx, &val) in detection[5..].iter().enumerate() {
+                if val > score {
+                    class_index = idx as i32;
+                    score = val;
+                }
+            }
+            if class_index < 0 {
+                continue;
+            }
+            let class_id = class_index as usize;
+            if !self.filter_classes.is_empty() && !self.filter_classes.contains(&class_id) {
+                continue;
+            }
+            let center_x = detection[0] * image_width;
+            let center_y = detection[1] * image_height;
+            let width = detection[2] * image_width;
+            let height = detection[3] * image_height;
+            let bbox = Rect::new(
+                (center_x - width / 2.0).floor() as i32,
+                (center_y - height / 2.0).floor() as i32,
+                width as i32,
+                height as i32,
+            );
+            candidates.push((bbox, class_id, confidence));
+        }
+        Ok(candidates)
     }
 
     /// Runs forward pass and returns results with `BBox` instead of `opencv::core::Rect`.
     ///
     /// This is a convenience method for users who prefer the backend-agnostic `BBox` type.
     /// Internally calls `forward()` and converts the results.
-    pub fn forward_bbox(&mut self, image: &Mat, conf_threshold: f32, nms_threshold: f32) -> Result<(Vec<BBox>, Vec<usize>, Vec<f32>), Error> {
+    pub fn forward_bbox(&mut self, image: &Mat, conf_threshold: f32, nms_threshold: f32) -> Result<DetectionVecs, Error> {
         let (rects, class_ids, confidences) = self.forward(image, conf_threshold, nms_threshold)?;
         let bboxes = rects.into_iter().map(|r| r.into()).collect();
         Ok((bboxes, class_ids, confidences))
@@ -343,7 +418,7 @@ impl ModelTrait for ModelYOLOClassic {
         image: &Mat,
         conf_threshold: f32,
         nms_threshold: f32,
-    ) -> Result<(Vec<Rect>, Vec<usize>, Vec<f32>), Error> {
+    ) -> Result<DetectionVecs<Rect>, Error> {
         self.forward(image, conf_threshold, nms_threshold)
     }
 }
@@ -357,7 +432,20 @@ impl crate::ObjectDetector for ModelYOLOClassic {
         input: &Self::Input,
         conf_threshold: f32,
         nms_threshold: f32,
-    ) -> Result<(Vec<BBox>, Vec<usize>, Vec<f32>), Self::Error> {
+    ) -> Result<DetectionVecs, Self::Error> {
         self.forward_bbox(input, conf_threshold, nms_threshold)
     }
+
+    fn detect_batch(
+        &mut self,
+        inputs: &[Self::Input],
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<Vec<DetectionVecs>, Self::Error> {
+        let results = self.forward_batch(inputs, conf_threshold, nms_threshold)?;
+        Ok(results
+            .into_iter()
+            .map(|(rects, class_ids, confidences)| (rects.into_iter().map(|r| r.into()).collect(), class_ids, confidences))
+            .collect())
+    }
 }
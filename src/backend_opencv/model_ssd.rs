@@ -0,0 +1,205 @@
+use opencv::{
+    core::Mat, core::Rect, core::Scalar, core::Size, core::Vector, core::CV_32F,
+    dnn::blob_from_image, dnn::read_net, dnn::Net,
+    prelude::MatTraitConst, prelude::MatTraitConstManual, prelude::NetTrait, prelude::NetTraitConst, Error,
+};
+
+use crate::model_trait::DetectionVecs;
+
+use super::model::ModelTrait;
+use super::utils::BACKEND_TARGET_VALID;
+
+/// Number of values per row of the `DetectionOutput` layer: (image_id, label, confidence, x1, y1, x2, y2)
+const DETECTION_OUTPUT_ROW: usize = 7;
+
+/// Wrapper around SSD-style detectors (MobileNet-SSD, TF Object Detection API models, ...)
+/// Those networks end with a `DetectionOutput` layer which already does NMS and emits `[1, 1, N, 7]` rows of
+/// (image_id, label, confidence, x1, y1, x2, y2) with coordinates relative to the source image (0.0 - 1.0)
+pub struct ModelSSD {
+    // Underlying OpenCV's DNN Net implementation
+    net: Net,
+    // Input size a.k.a network size (width and height of input)
+    input_size: Size,
+    // Blob's mean. TF models expect (0, 0, 0), Caffe MobileNet-SSD expects (127.5, 127.5, 127.5)
+    blob_mean: Scalar,
+    // Blob's scale. TF models expect 1.0 (uint8 range), Caffe MobileNet-SSD expects 1/127.5
+    blob_scale: f64,
+    // Whether to swap red and blue channels. TF models are trained on RGB, Caffe ones on BGR
+    blob_swap_rb: bool,
+    // Layers to read results from
+    out_layers: Vector<String>,
+    // Set of classes which will be used to filter detections
+    filter_classes: Vec<usize>,
+}
+
+impl ModelSSD {
+    /// Read file (TensorFlow .pb + .pbtxt, Caffe .caffemodel + .prototxt, ONNX) for specified BACKEND and TARGET combo and then prepares model.
+    ///
+    /// Framework is deduced by OpenCV from file extensions. Default blob parameters are the TF ones, see `set_blob_params` for Caffe models.
+    ///
+    /// Basic usage:
+    ///
+    /// ```ignore
+    /// use opencv::dnn::{DNN_BACKEND_OPENCV, DNN_TARGET_CPU};
+    /// use opencv::imgcodecs::imread;
+    /// use od_opencv::model_ssd::ModelSSD;
+    /// let mut model = ModelSSD::new_from_file("pretrained/frozen_inference_graph.pb", Some("pretrained/ssd_mobilenet_v2_coco.pbtxt"), (300, 300), DNN_BACKEND_OPENCV, DNN_TARGET_CPU, vec![]).unwrap();
+    /// let mut frame = imread("images/dog.jpg", 1).unwrap();
+    /// let (bboxes, class_ids, confidences) = model.forward(&frame, 0.25).unwrap();
+    /// ```
+    ///
+    pub fn new_from_file(
+        weight_file_path: &str,
+        cfg_file_path: Option<&str>,
+        net_size: (i32, i32),
+        backend_id: i32,
+        target_id: i32,
+        filter_classes: Vec<usize>,
+    ) -> Result<Self, Error> {
+        if BACKEND_TARGET_VALID
+            .get(&backend_id)
+            .and_then(|map| map.get(&target_id))
+            .is_none()
+        {
+            return Err(Error::new(
+                400,
+                format!(
+                    "Combination of BACKEND '{}' and TARGET '{}' is not valid",
+                    backend_id, target_id
+                ),
+            ));
+        };
+        ModelSSD::new_from_dnn(
+            read_net(weight_file_path, cfg_file_path.unwrap_or(""), "")?,
+            net_size,
+            backend_id,
+            target_id,
+            filter_classes,
+        )
+    }
+    /// Prepares model from OpenCV's DNN neural network
+    pub fn new_from_dnn(
+        mut neural_net: Net,
+        net_size: (i32, i32),
+        backend_id: i32,
+        target_id: i32,
+        filter_classes: Vec<usize>,
+    ) -> Result<Self, Error> {
+        neural_net.set_preferable_backend(backend_id)?;
+        neural_net.set_preferable_target(target_id)?;
+        let out_layers = neural_net.get_unconnected_out_layers_names()?;
+        Ok(Self {
+            net: neural_net,
+            input_size: Size::new(net_size.0, net_size.1),
+            blob_mean: Scalar::new(0.0, 0.0, 0.0, 0.0),
+            blob_scale: 1.0,
+            blob_swap_rb: true,
+            out_layers: out_layers,
+            filter_classes: filter_classes,
+        })
+    }
+    /// Overrides blob parameters.
+    ///
+    /// For Caffe MobileNet-SSD use `set_blob_params(1.0 / 127.5, (127.5, 127.5, 127.5), false)`.
+    pub fn set_blob_params(&mut self, scale: f64, mean: (f64, f64, f64), swap_rb: bool) {
+        self.blob_scale = scale;
+        self.blob_mean = Scalar::new(mean.0, mean.1, mean.2, 0.0);
+        self.blob_swap_rb = swap_rb;
+    }
+    /// Runs forward pass. There is no NMS threshold since `DetectionOutput` layer already does NMS.
+    pub fn forward(
+        &mut self,
+        image: &Mat,
+        conf_threshold: f32,
+    ) -> Result<DetectionVecs<Rect>, Error> {
+        let image_width_f32 = image.cols() as f32;
+        let image_height_f32 = image.rows() as f32;
+        // blob_from_image resizes by itself
+        let blobimg = blob_from_image(
+            &image,
+            self.blob_scale,
+            self.input_size,
+            self.blob_mean,
+            self.blob_swap_rb,
+            false,
+            CV_32F,
+        )?;
+        let mut detections = Vector::<Mat>::new();
+        self.net.set_input(&blobimg, "", 1.0, Scalar::default())?;
+        self.net.forward(&mut detections, &self.out_layers)?;
+
+        let mut bboxes = vec![];
+        let mut class_ids = vec![];
+        let mut confidences = vec![];
+
+        for layer in detections {
+            let data = layer.data_typed::<f32>()?;
+            if data.len() % DETECTION_OUTPUT_ROW != 0 {
+                return Err(Error::new(500, format!("Can't split DetectionOutput of {} values into rows of {}", data.len(), DETECTION_OUTPUT_ROW)));
+            }
+            for detection in data.chunks_exact(DETECTION_OUTPUT_ROW) {
+                // Negative image id marks the end of valid detections
+                if detection[0] < 0.0 {
+                    break;
+                }
+                let confidence = detection[2];
+                if confidence < conf_threshold {
+                    continue;
+                }
+                let class_id = detection[1].max(0.0) as usize;
+                if self.filter_classes.len() > 0 && !self.filter_classes.contains(&class_id) {
+                    continue;
+                }
+                let left = detection[3] * image_width_f32;
+                let top = detection[4] * image_height_f32;
+                let width = detection[5] * image_width_f32 - left;
+                let height = detection[6] * image_height_f32 - top;
+                bboxes.push(Rect::new(
+                    left.floor() as i32,
+                    top.floor() as i32,
+                    width as i32,
+                    height as i32,
+                ));
+                class_ids.push(class_id);
+                confidences.push(confidence);
+            }
+        }
+
+        Ok((bboxes, class_ids, confidences))
+    }
+
+    /// Runs forward pass and returns results with `BBox` instead of `opencv::core::Rect`.
+    ///
+    /// This is a convenience method for users who prefer the backend-agnostic `BBox` type.
+    /// Internally calls `forward()` and converts the results.
+    pub fn forward_bbox(&mut self, image: &Mat, conf_threshold: f32) -> Result<DetectionVecs, Error> {
+        let (rects, class_ids, confidences) = self.forward(image, conf_threshold)?;
+        let bboxes = rects.into_iter().map(|r| r.into()).collect();
+        Ok((bboxes, class_ids, confidences))
+    }
+}
+
+impl ModelTrait for ModelSSD {
+    fn forward(
+        &mut self,
+        image: &Mat,
+        conf_threshold: f32,
+        _nms_threshold: f32,
+    ) -> Result<DetectionVecs<Rect>, Error> {
+        self.forward(image, conf_threshold)
+    }
+}
+
+impl crate::ObjectDetector for ModelSSD {
+    type Input = Mat;
+    type Error = Error;
+
+    fn detect(
+        &mut self,
+        input: &Self::Input,
+        conf_threshold: f32,
+        _nms_threshold: f32,
+    ) -> Result<DetectionVecs, Self::Error> {
+        self.forward_bbox(input, conf_threshold)
+    }
+}
@@ -2,37 +2,39 @@ use opencv::{
     prelude::NetTrait,
     prelude::NetTraitConst,
     prelude::MatTraitConst,
-    core::VectorToVec,
+    prelude::MatTraitConstManual,
     core::Scalar,
     core::Size,
     core::Mat,
     core::Vector,
     core::Rect,
     core::CV_32F,
+    imgproc::resize,
+    imgproc::INTER_LINEAR,
     dnn::read_net,
     dnn::read_net_from_onnx,
     dnn::blob_from_image,
-    dnn::nms_boxes,
+    dnn::blob_from_images,
     dnn::Net,
     Error
 };
 
-use crate::BBox;
+use crate::model_trait::DetectionVecs;
+use crate::postprocess::NmsStrategy;
+use crate::preprocessing::PreprocessMeta;
 
 #[cfg(feature = "letterbox")]
 use opencv::{
     core::CV_8UC3,
     core::BORDER_CONSTANT,
     core::copy_make_border,
-    imgproc::resize,
-    imgproc::INTER_LINEAR,
 };
 
 use super::model_format::ModelFormat;
 use super::model::ModelTrait;
 use super::utils::{
     BACKEND_TARGET_VALID,
-    min_max_loc_partial
+    nms_filter
 };
 
 const YOLO_BLOB_MEAN: (f64, f64, f64, f64) = (0.0, 0.0, 0.0, 0.0);
@@ -54,6 +56,10 @@ pub struct ModelUltralyticsV8 {
     out_layers: Vector<String>,
     // Set of classes which will be used to filter detections
     filter_classes: Vec<usize>,
+    // Whether every class above the threshold yields its own detection (instead of the best class only)
+    multi_label: bool,
+    // NMS algorithm applied to the detections
+    nms_strategy: NmsStrategy,
     // Reusable buffer for letterbox resize (avoids allocation per frame)
     #[cfg(feature = "letterbox")]
     letterbox_resized: Mat,
@@ -150,42 +156,53 @@ impl ModelUltralyticsV8 {
             blob_name: "",
             out_layers,
             filter_classes,
+            multi_label: false,
+            nms_strategy: NmsStrategy::default(),
             #[cfg(feature = "letterbox")]
             letterbox_resized: Mat::default(),  // size varies with input aspect ratio
             #[cfg(feature = "letterbox")]
             letterbox_padded,
         })
     }
-    pub fn forward(&mut self, image: &Mat, conf_threshold: f32, nms_threshold: f32) -> Result<(Vec<Rect>, Vec<usize>, Vec<f32>), Error>{
+
+    /// Enables or disables multi-label output.
+    ///
+    /// When enabled, every class scoring above the threshold produces its own detection
+    /// (e.g. `person` and `worker` for the same box) and NMS is applied per class.
+    /// Default is `false` (best class only, class-agnostic NMS).
+    pub fn set_multi_label(&mut self, enabled: bool) {
+        self.multi_label = enabled;
+    }
+
+    /// Sets the NMS algorithm applied to the detections.
+    ///
+    /// Default is [`NmsStrategy::Greedy`] (OpenCV's `nms_boxes`).
+    pub fn set_nms_strategy(&mut self, strategy: NmsStrategy) {
+        self.nms_strategy = strategy;
+    }
+
+    pub fn forward(&mut self, image: &Mat, conf_threshold: f32, nms_threshold: f32) -> Result<DetectionVecs<Rect>, Error>{
         let image_width = image.cols();
         let image_height = image.rows();
 
         // Preprocessing and coordinate conversion factors depend on feature flag
         #[cfg(feature = "letterbox")]
-        let (blobimg, scale, pad_left, pad_top) = {
+        let (blobimg, meta) = {
             // Letterbox preprocessing: resize maintaining aspect ratio, then pad
-            let scale = f32::min(
-                self.input_size.width as f32 / image_width as f32,
-                self.input_size.height as f32 / image_height as f32
-            );
-            let new_width = (image_width as f32 * scale).round() as i32;
-            let new_height = (image_height as f32 * scale).round() as i32;
-            let pad_left = (self.input_size.width - new_width) / 2;
-            let pad_top = (self.input_size.height - new_height) / 2;
+            let meta = crate::preprocessing::LetterboxMeta::new(image_width, image_height, self.input_size.width, self.input_size.height, None);
+            let (new_width, new_height) = meta.resized_size();
 
             let blob = if image_width != self.input_size.width || image_height != self.input_size.height {
                 // Resize maintaining aspect ratio (reuses buffer if size matches)
                 resize(&image, &mut self.letterbox_resized, Size::new(new_width, new_height), 0.0, 0.0, INTER_LINEAR)?;
                 // Pad to target size with gray (114, 114, 114)
-                let pad_right = self.input_size.width - new_width - pad_left;
-                let pad_bottom = self.input_size.height - new_height - pad_top;
                 copy_make_border(
                     &self.letterbox_resized,
                     &mut self.letterbox_padded,
-                    pad_top,
-                    pad_bottom,
-                    pad_left,
-                    pad_right,
+                    meta.pad_top,
+                    meta.pad_bottom,
+                    meta.pad_left,
+                    meta.pad_right,
                     BORDER_CONSTANT,
                     Scalar::new(114.0, 114.0, 114.0, 0.0)
                 )?;
@@ -195,16 +212,20 @@ impl ModelUltralyticsV8 {
             } else {
                 blob_from_image(&image, self.blob_scale, self.input_size, self.blob_mean, true, false, CV_32F)?
             };
-            (blob, scale, pad_left, pad_top)
+            (blob, PreprocessMeta::Letterbox(meta))
         };
 
         #[cfg(not(feature = "letterbox"))]
-        let (blobimg, scale_x, scale_y) = {
+        let (blobimg, meta) = {
             // Stretch preprocessing: direct resize to input_size (faster but may distort)
-            let scale_x = image_width as f32 / self.input_size.width as f32;
-            let scale_y = image_height as f32 / self.input_size.height as f32;
+            let meta = crate::preprocessing::StretchMeta {
+                scale_x: image_width as f32 / self.input_size.width as f32,
+                scale_y: image_height as f32 / self.input_size.height as f32,
+                original_width: image_width,
+                original_height: image_height,
+            };
             let blob = blob_from_image(&image, self.blob_scale, self.input_size, self.blob_mean, true, false, CV_32F)?;
-            (blob, scale_x, scale_y)
+            (blob, PreprocessMeta::Stretch(meta))
         };
 
         let mut detections = Vector::<Mat>::new();
@@ -215,100 +236,156 @@ impl ModelUltralyticsV8 {
         let mut bboxes = Vector::<Rect>::new();
         let mut confidences = Vector::<f32>::new();
         let mut class_ids = Vec::new();
+        for layer in detections.iter() {
+            for (bbox, class_index, score) in self.decode_layer(&layer, 0, conf_threshold, &meta)? {
+                bboxes.push(bbox);
+                confidences.push(score);
+                class_ids.push(class_index);
+            }
+        }
+        // Run NMS on collected detections to filter duplicates and overlappings
+        // (within each class only in multi-label mode, so overlapping labels of one object are kept)
+        nms_filter(&bboxes, &confidences, &class_ids, conf_threshold, nms_threshold, &self.nms_strategy, self.multi_label)
+    }
 
-        // Specific to YOLOv8 reading detections vector
-        // See the ref. https://github.com/ultralytics/ultralytics/blob/main/examples/YOLOv8-OpenCV-ONNX-Python/main.py#L65
-        for layer in detections {
-            let mat_size = layer.mat_size();
-            let cols = mat_size[1];
-            let rows: i32 = mat_size[2];
-            for i in 0..rows{
-                // Access elements along the second dimension
-                let object_data: Vec<&f32> = (0..cols)
-                    .map(|j| layer.at_3d::<f32>(0, j, i).unwrap())
-                    .collect();
-                // Access elements as if transposed
-                let classes_scores: Vec<&f32> = object_data[4..].to_vec();
-                // Find min and max scores and locations
-                let (_, max_score, _, max_class_index) = match min_max_loc_partial(&classes_scores) {
-                    Some((a, b, c, d)) => {
-                        (a, b, c, d)
-                    },
-                    None => {
-                        return Err(Error::new(500, "Can't execute min_max_loc_partial due the class_scores is an empty array"));
-                    }
-                };
-                if max_score >= 0.25 {
-                    if self.filter_classes.len() > 0 && !self.filter_classes.contains(&max_class_index) {
-                        continue;
-                    }
+    /// Runs forward pass on several images at once.
+    ///
+    /// Every image is resized (letterbox or stretch, as in `forward`) and the results are stacked
+    /// with `blob_from_images` into a single `[B, 3, H, W]` blob. When the batched forward pass fails
+    /// or the outputs do not have a batch dimension of `B` (e.g. ONNX exports without dynamic axes),
+    /// it falls back to one forward pass per image.
+    ///
+    /// Returns one (bounding boxes, class IDs, confidences) tuple per image, in input order.
+    pub fn forward_batch(&mut self, images: &[Mat], conf_threshold: f32, nms_threshold: f32) -> Result<Vec<DetectionVecs<Rect>>, Error> {
+        if images.len() < 2 {
+            return images.iter().map(|image| self.forward(image, conf_threshold, nms_threshold)).collect();
+        }
 
-                    // Coordinate conversion: model outputs pixel coordinates in input space
-                    #[cfg(feature = "letterbox")]
-                    let (x_center, y_center, width, height) = {
-                        // Letterbox: subtract padding offset, divide by scale factor
-                        (
-                            (*object_data[0] - pad_left as f32) / scale,
-                            (*object_data[1] - pad_top as f32) / scale,
-                            *object_data[2] / scale,
-                            *object_data[3] / scale,
-                        )
-                    };
+        let mut prepared = Vector::<Mat>::new();
+        let mut metas = Vec::with_capacity(images.len());
+        for image in images {
+            let (resized, meta) = self.prepare_image(image)?;
+            prepared.push(resized);
+            metas.push(meta);
+        }
+        // Size(0,0): prepared images already have the network size
+        let blobimg = blob_from_images(&prepared, self.blob_scale, Size::new(0, 0), self.blob_mean, true, false, CV_32F)?;
 
-                    #[cfg(not(feature = "letterbox"))]
-                    let (x_center, y_center, width, height) = {
-                        // Stretch: scale back from input_size to original image size
-                        (
-                            *object_data[0] * scale_x,
-                            *object_data[1] * scale_y,
-                            *object_data[2] * scale_x,
-                            *object_data[3] * scale_y,
-                        )
-                    };
+        let mut detections = Vector::<Mat>::new();
+        let batched = self
+            .net
+            .set_input(&blobimg, self.blob_name, 1.0, self.blob_mean)
+            .and_then(|_| self.net.forward(&mut detections, &self.out_layers));
+        if batched.is_err() || detections.iter().any(|layer| layer.dims() != 3 || layer.mat_size()[0] != images.len() as i32) {
+            return images.iter().map(|image| self.forward(image, conf_threshold, nms_threshold)).collect();
+        }
 
-                    // Convert from center to top-left corner
-                    let bbox_cv = Rect::new(
-                        (x_center - width / 2.0).round() as i32,
-                        (y_center - height / 2.0).round() as i32,
-                        width.round() as i32,
-                        height.round() as i32
-                    );
-                    bboxes.push(bbox_cv);
-                    confidences.push(max_score);
-                    class_ids.push(max_class_index);
+        let mut results = Vec::with_capacity(images.len());
+        for (batch_index, meta) in metas.iter().enumerate() {
+            let mut bboxes = Vector::<Rect>::new();
+            let mut confidences = Vector::<f32>::new();
+            let mut class_ids = Vec::new();
+            for layer in detections.iter() {
+                for (bbox, class_index, score) in self.decode_layer(&layer, batch_index, conf_threshold, meta)? {
+                    bboxes.push(bbox);
+                    confidences.push(score);
+                    class_ids.push(class_index);
                 }
             }
+            results.push(nms_filter(&bboxes, &confidences, &class_ids, conf_threshold, nms_threshold, &self.nms_strategy, self.multi_label)?);
         }
-        // Run NMS on collected detections to filter duplicates and overlappings
-        let mut indices = Vector::<i32>::new();
-        nms_boxes(&bboxes, &confidences, conf_threshold, nms_threshold, &mut indices, 1.0, 0)?;
-
-        let mut nms_bboxes = vec![];
-        let mut nms_classes_ids = vec![];
-        let mut nms_confidences = vec![];
+        Ok(results)
+    }
 
-        let indices_vec = indices.to_vec();
-        let mut bboxes = bboxes.to_vec();
-        nms_bboxes.extend(bboxes.drain(..)
-            .enumerate()
-            .filter_map(|(idx, item)| if indices_vec.contains(&(idx as i32)) {Some(item)} else {None}));
+    /// Decodes the `batch_index`-th image of a `[B, 4 + num_classes, num_predictions]` output layer.
+    ///
+    /// Shared by `forward` and `forward_batch`: predictions scoring below `conf_threshold` or
+    /// filtered out by class are skipped, boxes are mapped back to the original image.
+    /// Returns (bounding box, class ID, confidence) candidates before NMS.
+    fn decode_layer(&self, layer: &Mat, batch_index: usize, conf_threshold: f32, meta: &PreprocessMeta) -> Result<Vec<(Rect, usize, f32)>, Error> {
+        // Specific to YOLOv8 reading detections vector
+        // See the ref. https://github.com/ultralytics/ultralytics/blob/main/examples/YOLOv8-OpenCV-ONNX-Python/main.py#L65
+        let mat_size = layer.mat_size();
+        let cols = mat_size[1] as usize;
+        let rows = mat_size[2] as usize;
+        let data = layer.data_typed::<f32>()?;
+        let image_data = &data[batch_index * cols * rows..(batch_index + 1) * cols * rows];
 
-        nms_classes_ids.extend(class_ids.drain(..)
-            .enumerate()
-            .filter_map(|(idx, item)| if indices_vec.contains(&(idx as i32)) {Some(item)} else {None}));
+        let mut candidates = Vec::new();
+        for i in 0..rows {
+            // Access elements as if transposed
+            let value = |j: usize| image_data[j * rows + i];
+            let scores = (4..cols).map(|j| (j - 4, value(j)));
+            let labels: Vec<(usize, f32)> = if self.multi_label {
+                // Every class above the threshold is a separate detection
+                scores.filter(|(_, score)| *score >= conf_threshold).collect()
+            } else {
+                let best = scores.fold((0, f32::MIN), |best, current| if current.1 > best.1 { current } else { best });
+                // The single-label decoder keeps its fixed 0.25 score floor
+                if best.1 >= 0.25 { vec![best] } else { Vec::new() }
+            };
+            let (x_center, y_center, width, height) = meta.inverse_transform(value(0), value(1), value(2), value(3));
+            for (class_index, score) in labels {
+                if score < conf_threshold {
+                    continue;
+                }
+                if !self.filter_classes.is_empty() && !self.filter_classes.contains(&class_index) {
+                    continue;
+                }
+                // Convert from center to top-left corner
+                let bbox = Rect::new(
+                    (x_center - width / 2.0).round() as i32,
+                    (y_center - height / 2.0).round() as i32,
+                    width.round() as i32,
+                    height.round() as i32
+                );
+                candidates.push((bbox, class_index, score));
+            }
+        }
+        Ok(candidates)
+    }
 
-        nms_confidences.extend(confidences.to_vec().drain(..)
-            .enumerate()
-            .filter_map(|(idx, item)| if indices_vec.contains(&(idx as i32)) {Some(item)} else {None}));
+    /// Resizes an image to the network size for batching, returns metadata for inverse transform.
+    fn prepare_image(&self, image: &Mat) -> Result<(Mat, PreprocessMeta), Error> {
+        #[cfg(feature = "letterbox")]
+        {
+            let meta = crate::preprocessing::LetterboxMeta::new(image.cols(), image.rows(), self.input_size.width, self.input_size.height, None);
+            let (new_width, new_height) = meta.resized_size();
+            let mut resized = Mat::default();
+            resize(image, &mut resized, Size::new(new_width, new_height), 0.0, 0.0, INTER_LINEAR)?;
+            let mut padded = Mat::default();
+            copy_make_border(
+                &resized,
+                &mut padded,
+                meta.pad_top,
+                meta.pad_bottom,
+                meta.pad_left,
+                meta.pad_right,
+                BORDER_CONSTANT,
+                Scalar::new(114.0, 114.0, 114.0, 0.0)
+            )?;
+            Ok((padded, PreprocessMeta::Letterbox(meta)))
+        }
 
-        Ok((nms_bboxes, nms_classes_ids, nms_confidences))
+        #[cfg(not(feature = "letterbox"))]
+        {
+            let meta = crate::preprocessing::StretchMeta {
+                scale_x: image.cols() as f32 / self.input_size.width as f32,
+                scale_y: image.rows() as f32 / self.input_size.height as f32,
+                original_width: image.cols(),
+                original_height: image.rows(),
+            };
+            let mut resized = Mat::default();
+            resize(image, &mut resized, self.input_size, 0.0, 0.0, INTER_LINEAR)?;
+            Ok((resized, PreprocessMeta::Stretch(meta)))
+        }
     }
 
     /// Runs forward pass and returns results with `BBox` instead of `opencv::core::Rect`.
     ///
     /// This is a convenience method for users who prefer the backend-agnostic `BBox` type.
     /// Internally calls `forward()` and converts the results.
-    pub fn forward_bbox(&mut self, image: &Mat, conf_threshold: f32, nms_threshold: f32) -> Result<(Vec<BBox>, Vec<usize>, Vec<f32>), Error> {
+    pub fn forward_bbox(&mut self, image: &Mat, conf_threshold: f32, nms_threshold: f32) -> Result<DetectionVecs, Error> {
         let (rects, class_ids, confidences) = self.forward(image, conf_threshold, nms_threshold)?;
         let bboxes = rects.into_iter().map(|r| r.into()).collect();
         Ok((bboxes, class_ids, confidences))
@@ -316,7 +393,7 @@ impl ModelUltralyticsV8 {
 }
 
 impl ModelTrait for ModelUltralyticsV8 {
-    fn forward(&mut self, image: &Mat, conf_threshold: f32, nms_threshold: f32) -> Result<(Vec<Rect>, Vec<usize>, Vec<f32>), Error>{
+    fn forward(&mut self, image: &Mat, conf_threshold: f32, nms_threshold: f32) -> Result<DetectionVecs<Rect>, Error>{
         self.forward(image, conf_threshold, nms_threshold)
     }
 }
@@ -330,7 +407,20 @@ impl crate::ObjectDetector for ModelUltralyticsV8 {
         input: &Self::Input,
         conf_threshold: f32,
         nms_threshold: f32,
-    ) -> Result<(Vec<BBox>, Vec<usize>, Vec<f32>), Self::Error> {
+    ) -> Result<DetectionVecs, Self::Error> {
         self.forward_bbox(input, conf_threshold, nms_threshold)
     }
+
+    fn detect_batch(
+        &mut self,
+        inputs: &[Self::Input],
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<Vec<DetectionVecs>, Self::Error> {
+        let results = self.forward_batch(inputs, conf_threshold, nms_threshold)?;
+        Ok(results
+            .into_iter()
+            .map(|(rects, class_ids, confidences)| (rects.into_iter().map(|r| r.into()).collect(), class_ids, confidences))
+            .collect())
+    }
 }
\ No newline at end of file
@@ -11,7 +11,16 @@ use opencv::{
     dnn::DNN_TARGET_CUDA,
     dnn::DNN_TARGET_CUDA_FP16,
     dnn::DNN_TARGET_HDDL,
+    dnn::nms_boxes,
+    dnn::nms_boxes_batched,
+    core::Rect,
+    core::Vector,
+    core::VectorToVec,
+    Error,
 };
+use crate::BBox;
+use crate::model_trait::DetectionVecs;
+use crate::postprocess::{Detection, NmsStrategy};
 use std::collections::{HashMap, HashSet};
 use std::iter::FromIterator;
 use super::model_format::{
@@ -72,6 +81,58 @@ pub fn min_max_loc_partial<T: PartialOrd + Copy>(data: &[&T]) -> Option<(T, T, u
     Some((*min_val, *max_val, min_loc, max_loc))
 }
 
+/// Runs NMS on collected detections and keeps the selected ones in their original order.
+///
+/// Greedy NMS uses OpenCV's `nms_boxes` (`nms_boxes_batched` when `per_class` is set or for
+/// [`NmsStrategy::ClassAware`]), other strategies run on the crate's own implementation
+/// and may lower the returned confidences.
+/// Returns (bounding boxes, class IDs, confidences).
+pub(crate) fn nms_filter(
+    bboxes: &Vector<Rect>,
+    confidences: &Vector<f32>,
+    class_ids: &[usize],
+    conf_threshold: f32,
+    nms_threshold: f32,
+    strategy: &NmsStrategy,
+    per_class: bool,
+) -> Result<DetectionVecs<Rect>, Error> {
+    let mut kept: Vec<(usize, f32)> = match strategy {
+        NmsStrategy::Greedy | NmsStrategy::ClassAware => {
+            let mut indices = Vector::<i32>::new();
+            if per_class || *strategy == NmsStrategy::ClassAware {
+                let class_ids_cv: Vector<i32> = class_ids.iter().map(|&class_id| class_id as i32).collect();
+                nms_boxes_batched(bboxes, confidences, &class_ids_cv, conf_threshold, nms_threshold, &mut indices, 1.0, 0)?;
+            } else {
+                nms_boxes(bboxes, confidences, conf_threshold, nms_threshold, &mut indices, 1.0, 0)?;
+            }
+            indices.iter().map(|index| Ok((index as usize, confidences.get(index as usize)?))).collect::<Result<_, Error>>()?
+        },
+        _ => {
+            let detections: Vec<Detection> = bboxes.iter()
+                .zip(class_ids.iter().zip(confidences.iter()))
+                .map(|(bbox, (&class_id, confidence))| Detection::new(BBox::new(bbox.x, bbox.y, bbox.width, bbox.height), class_id, confidence))
+                .collect();
+            if per_class {
+                strategy.apply_per_class_indices(&detections, nms_threshold, conf_threshold)
+            } else {
+                strategy.apply_indices(&detections, nms_threshold, conf_threshold)
+            }
+        }
+    };
+    kept.sort_unstable_by_key(|&(index, _)| index);
+
+    let mut nms_bboxes = Vec::with_capacity(kept.len());
+    let mut nms_classes_ids = Vec::with_capacity(kept.len());
+    let mut nms_confidences = Vec::with_capacity(kept.len());
+    for (index, confidence) in kept {
+        nms_bboxes.push(bboxes.get(index)?);
+        nms_classes_ids.push(class_ids[index]);
+        nms_confidences.push(confidence);
+    }
+    Ok((nms_bboxes, nms_classes_ids, nms_confidences))
+}
+
+
 mod tests {
     #[test]
     fn test_min_max_loc_partial() {
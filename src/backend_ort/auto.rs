@@ -0,0 +1,428 @@
+//! Automatic selection of the decoder matching an ONNX detection model.
+
+use ort::session::Session;
+
+use crate::image_buffer::ImageBuffer;
+use crate::model_trait::ObjectDetector;
+
+use super::metadata::{UltralyticsMetadata, DEFAULT_INPUT_SIZE};
+use super::model_end2end::NUM_DETS_NAMES;
+use super::{
+    ModelEnd2EndOrt, ModelFaceOrt, ModelRTDETROrt, ModelSSDOrt, ModelUltralyticsObbOrt,
+    ModelUltralyticsOrt, ModelUltralyticsPoseOrt, ModelUltralyticsSegOrt, ModelYOLONASOrt,
+    ModelYOLOv10Ort, ModelYOLOv5Ort, OrtModelError,
+};
+
+/// Maximum number of rows of query-based outputs (RT-DETR queries, YOLOv10 top-k).
+///
+/// Anchor-based heads produce thousands of predictions (8400 at 640x640).
+const MAX_QUERIES: usize = 300;
+
+/// Detector selected at runtime by [`OutputLayout::load`].
+pub type BoxedDetector = Box<dyn ObjectDetector<Input = ImageBuffer, Error = OrtModelError>>;
+
+/// Output layout of an ONNX detection model, i.e. the decoder able to read it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputLayout {
+    /// `[1, 4 + C, N]` channel-first, no objectness (YOLOv8/v9/v11)
+    Ultralytics,
+    /// `[1, N, 4 + C]` row-major, no objectness (transposed YOLOv8/v9/v11 exports)
+    UltralyticsTransposed,
+    /// Ultralytics segmentation (`task: segment` metadata)
+    UltralyticsSeg,
+    /// Ultralytics pose estimation (`task: pose` metadata)
+    UltralyticsPose,
+    /// Ultralytics oriented bounding boxes (`task: obb` metadata)
+    UltralyticsObb,
+    /// `[1, N, 5 + C]` row-major with objectness (YOLOv5/v7)
+    YoloV5,
+    /// `[1, K, 6]` NMS-free detections (YOLOv10)
+    YoloV10,
+    /// `[1, Q, 4 + C]` object queries, NMS-free (RT-DETR)
+    RtDetr,
+    /// `[1, N, 4]` boxes and `[1, N, C]` scores (YOLO-NAS)
+    YoloNas,
+    /// NMS inside the graph (EfficientNMS or ONNX `NonMaxSuppression`)
+    End2End,
+    /// TF Object Detection API outputs (`detection_boxes`, ...)
+    Ssd,
+    /// Score/box/landmark outputs per stride (SCRFD face detector)
+    Scrfd,
+}
+
+/// Name and shape (`-1` for dynamic dimensions) of a model input or output.
+#[derive(Debug, Clone)]
+struct TensorSignature {
+    name: String,
+    shape: Vec<i64>,
+}
+
+impl TensorSignature {
+    /// Returns the static dimensions, or `None` if any of them is dynamic.
+    fn static_dims(&self) -> Option<Vec<usize>> {
+        self.shape.iter().map(|&d| usize::try_from(d).ok().filter(|&d| d > 0)).collect()
+    }
+
+    /// Returns the last dimension if it is static.
+    fn last_dim(&self) -> Option<usize> {
+        self.shape.last().and_then(|&d| usize::try_from(d).ok()).filter(|&d| d > 0)
+    }
+}
+
+impl std::fmt::Display for TensorSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {:?}", self.name, self.shape)
+    }
+}
+
+/// Model signature the layout is detected from.
+struct ModelSignature {
+    inputs: Vec<TensorSignature>,
+    outputs: Vec<TensorSignature>,
+    metadata: UltralyticsMetadata,
+}
+
+impl ModelSignature {
+    fn from_session(session: &Session) -> Self {
+        let signature = |name: &str, value_type: &ort::value::ValueType| TensorSignature {
+            name: name.to_string(),
+            shape: value_type.tensor_shape().map(|shape| shape.to_vec()).unwrap_or_default(),
+        };
+        Self {
+            inputs: session.inputs.iter().map(|i| signature(&i.name, &i.input_type)).collect(),
+            outputs: session.outputs.iter().map(|o| signature(&o.name, &o.output_type)).collect(),
+            metadata: UltralyticsMetadata::from_session(session),
+        }
+    }
+
+    fn num_classes(&self) -> Option<usize> {
+        let names = &self.metadata.class_names;
+        (!names.is_empty()).then_some(names.len())
+    }
+
+    fn has_output(&self, names: &[&str]) -> bool {
+        self.outputs.iter().any(|o| names.contains(&o.name.as_str()))
+    }
+
+    /// Matches the signature against the known layouts.
+    fn layout(&self) -> Option<OutputLayout> {
+        // A single image input: text-prompted models (YOLO-World) need extra inputs
+        if self.inputs.len() != 1 || self.inputs[0].shape.len() != 4 || self.outputs.is_empty() {
+            return None;
+        }
+
+        match self.metadata.task.as_deref() {
+            Some("segment") => return (self.outputs.len() == 2).then_some(OutputLayout::UltralyticsSeg),
+            Some("pose") => return (self.outputs.len() == 1).then_some(OutputLayout::UltralyticsPose),
+            Some("obb") => return (self.outputs.len() == 1).then_some(OutputLayout::UltralyticsObb),
+            Some("detect") | None => {}
+            Some(_) => return None,
+        }
+
+        if self.has_output(&["detection_boxes"]) {
+            return Some(OutputLayout::Ssd);
+        }
+        if self.outputs.len() == 4 && self.has_output(&NUM_DETS_NAMES) {
+            return Some(OutputLayout::End2End);
+        }
+        if self.outputs.len() >= 6
+            && self.outputs.iter().all(|o| matches!(o.last_dim(), Some(1 | 4 | 10)))
+        {
+            return Some(OutputLayout::Scrfd);
+        }
+        if self.outputs.len() == 2 {
+            return self.two_outputs_layout();
+        }
+        if self.outputs.len() != 1 {
+            return None;
+        }
+
+        let output = &self.outputs[0];
+        if output.shape.len() == 2 && output.last_dim() == Some(7) {
+            return Some(OutputLayout::End2End);
+        }
+        if output.shape.len() != 3 || !matches!(output.shape[0], 1 | -1) {
+            return None;
+        }
+        let dim = |i: usize| usize::try_from(output.shape[i]).ok().filter(|&d| d > 0);
+        match (dim(1), dim(2)) {
+            (Some(rows), Some(cols)) => Some(self.single_output_layout(rows, cols)),
+            (rows, cols) => self.dynamic_output_layout(rows, cols),
+        }
+    }
+
+    /// Matches a single output with dynamic axes (`dynamic=True` exports), e.g. `[-1, 84, -1]`.
+    ///
+    /// Only the static feature axis, compared with the class count, tells the layout apart.
+    fn dynamic_output_layout(&self, rows: Option<usize>, cols: Option<usize>) -> Option<OutputLayout> {
+        let num_classes = self.num_classes()?;
+        if rows == Some(4 + num_classes) {
+            Some(OutputLayout::Ultralytics)
+        } else if cols == Some(4 + num_classes) {
+            Some(OutputLayout::UltralyticsTransposed)
+        } else if cols == Some(5 + num_classes) {
+            Some(OutputLayout::YoloV5)
+        } else {
+            None
+        }
+    }
+
+    fn two_outputs_layout(&self) -> Option<OutputLayout> {
+        let dims: Vec<Vec<usize>> =
+            self.outputs.iter().map(|o| o.static_dims()).collect::<Option<_>>()?;
+        let both_rank3 = dims.iter().all(|d| d.len() == 3);
+        let same_rows = dims[0].get(1) == dims[1].get(1);
+        let boxes = dims.iter().filter(|d| d.last() == Some(&4)).count();
+        (both_rank3 && same_rows && boxes >= 1).then_some(OutputLayout::YoloNas)
+    }
+
+    fn single_output_layout(&self, rows: usize, cols: usize) -> OutputLayout {
+        if let Some(num_classes) = self.num_classes() {
+            if rows == 4 + num_classes && rows < cols {
+                return OutputLayout::Ultralytics;
+            }
+            if cols == 6 && rows <= MAX_QUERIES {
+                return OutputLayout::YoloV10;
+            }
+            if cols == 4 + num_classes {
+                return if rows <= MAX_QUERIES {
+                    OutputLayout::RtDetr
+                } else {
+                    OutputLayout::UltralyticsTransposed
+                };
+            }
+            if cols == 5 + num_classes {
+                return OutputLayout::YoloV5;
+            }
+        }
+
+        // Without class names: features are fewer than predictions in channel-first
+        // outputs, and row-major exports without metadata are usually YOLOv5/v7
+        if rows < cols {
+            OutputLayout::Ultralytics
+        } else if cols == 6 && rows <= MAX_QUERIES {
+            OutputLayout::YoloV10
+        } else if rows <= MAX_QUERIES {
+            OutputLayout::RtDetr
+        } else {
+            OutputLayout::YoloV5
+        }
+    }
+
+    /// Input size (width, height): requested, from the metadata, from a static input shape, or default.
+    fn input_size(&self, requested: Option<(u32, u32)>) -> (u32, u32) {
+        let from_shape = self.inputs.first().and_then(|input| match input.shape.as_slice() {
+            &[_, 3, h, w] | &[_, h, w, 3] if h > 0 && w > 0 => Some((w as u32, h as u32)),
+            _ => None,
+        });
+        requested
+            .or(self.metadata.input_size)
+            .or(from_shape)
+            .unwrap_or(DEFAULT_INPUT_SIZE)
+    }
+
+    fn describe(&self) -> String {
+        let join = |tensors: &[TensorSignature]| {
+            tensors.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(", ")
+        };
+        format!(
+            "no decoder matches inputs [{}], outputs [{}], task {}, {} class names",
+            join(&self.inputs),
+            join(&self.outputs),
+            self.metadata.task.as_deref().unwrap_or("unknown"),
+            self.metadata.class_names.len(),
+        )
+    }
+}
+
+impl OutputLayout {
+    /// Detects the layout of a model from its input/output names and shapes and
+    /// its Ultralytics metadata.
+    ///
+    /// YOLOX exports share the YOLOv5 layout and are detected as such: load them
+    /// with [`super::ModelYOLOXOrt`] directly.
+    pub fn from_session(session: &Session) -> Result<Self, OrtModelError> {
+        let signature = ModelSignature::from_session(session);
+        signature
+            .layout()
+            .ok_or_else(|| OrtModelError::UnsupportedModel(signature.describe()))
+    }
+
+    /// Creates the detector matching the layout of the model.
+    ///
+    /// # Arguments
+    /// * `session` - ORT session of the model
+    /// * `input_size` - Model input size as (width, height), or `None` to take it from
+    ///   the metadata or the input shape (640x640 if both are unknown)
+    pub fn load(
+        session: Session,
+        input_size: Option<(u32, u32)>,
+    ) -> Result<BoxedDetector, OrtModelError> {
+        let signature = ModelSignature::from_session(&session);
+        let layout = signature
+            .layout()
+            .ok_or_else(|| OrtModelError::UnsupportedModel(signature.describe()))?;
+        let input_size = signature.input_size(input_size);
+
+        let detector: BoxedDetector = match layout {
+            OutputLayout::Ultralytics => {
+                Box::new(ModelUltralyticsOrt::from_session(session, input_size, vec![]))
+            }
+            OutputLayout::UltralyticsTransposed => {
+                let mut model = ModelUltralyticsOrt::from_session(session, input_size, vec![]);
+                model.set_transposed(true);
+                Box::new(model)
+            }
+            OutputLayout::UltralyticsSeg => {
+                Box::new(ModelUltralyticsSegOrt::from_session(session, input_size, vec![]))
+            }
+            OutputLayout::UltralyticsPose => {
+                let mut model = ModelUltralyticsPoseOrt::from_session(session, input_size, vec![]);
+                if let Some(num_classes) = signature.num_classes() {
+                    model.set_num_classes(num_classes);
+                }
+                Box::new(model)
+            }
+            OutputLayout::UltralyticsObb => {
+                Box::new(ModelUltralyticsObbOrt::from_session(session, input_size, vec![]))
+            }
+            OutputLayout::YoloV5 => {
+                Box::new(ModelYOLOv5Ort::from_session(session, input_size, vec![]))
+            }
+            OutputLayout::YoloV10 => {
+                Box::new(ModelYOLOv10Ort::from_session(session, input_size, vec![]))
+            }
+            OutputLayout::RtDetr => {
+                Box::new(ModelRTDETROrt::from_session(session, input_size, vec![]))
+            }
+            OutputLayout::YoloNas => {
+                Box::new(ModelYOLONASOrt::from_session(session, input_size, vec![]))
+            }
+            OutputLayout::End2End => {
+                Box::new(ModelEnd2EndOrt::from_session(session, input_size, vec![]))
+            }
+            OutputLayout::Ssd => Box::new(ModelSSDOrt::from_session(session, input_size, vec![])),
+            OutputLayout::Scrfd => Box::new(ModelFaceOrt::from_session(session, input_size)),
+        };
+        Ok(detector)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tensor(name: &str, shape: &[i64]) -> TensorSignature {
+        TensorSignature { name: name.to_string(), shape: shape.to_vec() }
+    }
+
+    fn signature(outputs: Vec<TensorSignature>, task: Option<&str>, num_classes: usize) -> ModelSignature {
+        ModelSignature {
+            inputs: vec![tensor("images", &[1, 3, 640, 640])],
+            outputs,
+            metadata: UltralyticsMetadata {
+                class_names: (0..num_classes).map(|i| i.to_string()).collect(),
+                task: task.map(str::to_string),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_single_output_layouts() {
+        let cases = [
+            (&[1, 84, 8400], 80, OutputLayout::Ultralytics),
+            (&[1, 8400, 84], 80, OutputLayout::UltralyticsTransposed),
+            (&[1, 25200, 85], 80, OutputLayout::YoloV5),
+            (&[1, 300, 6], 80, OutputLayout::YoloV10),
+            (&[1, 300, 84], 80, OutputLayout::RtDetr),
+            // Without metadata
+            (&[1, 84, 8400], 0, OutputLayout::Ultralytics),
+            (&[1, 25200, 85], 0, OutputLayout::YoloV5),
+            (&[1, 300, 6], 0, OutputLayout::YoloV10),
+            (&[1, 300, 84], 0, OutputLayout::RtDetr),
+        ];
+        for (shape, num_classes, expected) in cases {
+            let signature = signature(vec![tensor("output0", shape)], None, num_classes);
+            assert_eq!(signature.layout(), Some(expected), "{:?} with {} classes", shape, num_classes);
+        }
+    }
+
+    #[test]
+    fn test_dynamic_output_layouts() {
+        let cases = [
+            (&[-1, 84, -1], OutputLayout::Ultralytics),
+            (&[1, 84, -1], OutputLayout::Ultralytics),
+            (&[-1, -1, 84], OutputLayout::UltralyticsTransposed),
+            (&[-1, -1, 85], OutputLayout::YoloV5),
+            (&[-1, 84, 8400], OutputLayout::Ultralytics),
+        ];
+        for (shape, expected) in cases {
+            let signature = signature(vec![tensor("output0", shape)], None, 80);
+            assert_eq!(signature.layout(), Some(expected), "{:?}", shape);
+        }
+
+        // The class count is needed to find the feature axis
+        let unknown = signature(vec![tensor("output0", &[-1, 84, -1])], None, 0);
+        assert_eq!(unknown.layout(), None);
+    }
+
+    #[test]
+    fn test_multi_output_layouts() {
+        let end2end = signature(
+            vec![
+                tensor("num_dets", &[1, 1]),
+                tensor("det_boxes", &[1, 100, 4]),
+                tensor("det_scores", &[1, 100]),
+                tensor("det_classes", &[1, 100]),
+            ],
+            None,
+            0,
+        );
+        assert_eq!(end2end.layout(), Some(OutputLayout::End2End));
+
+        let nms = signature(vec![tensor("output", &[-1, 7])], None, 0);
+        assert_eq!(nms.layout(), Some(OutputLayout::End2End));
+
+        let nas = signature(
+            vec![tensor("boxes", &[1, 8400, 4]), tensor("scores", &[1, 8400, 80])],
+            None,
+            0,
+        );
+        assert_eq!(nas.layout(), Some(OutputLayout::YoloNas));
+
+        let seg = signature(
+            vec![tensor("output0", &[1, 116, 8400]), tensor("output1", &[1, 32, 160, 160])],
+            Some("segment"),
+            80,
+        );
+        assert_eq!(seg.layout(), Some(OutputLayout::UltralyticsSeg));
+    }
+
+    #[test]
+    fn test_unsupported_layouts() {
+        let classify = signature(vec![tensor("output0", &[1, 1000])], Some("classify"), 1000);
+        assert_eq!(classify.layout(), None);
+
+        let dynamic = signature(vec![tensor("output0", &[1, -1, -1])], None, 0);
+        assert_eq!(dynamic.layout(), None);
+        assert_eq!(
+            dynamic.describe(),
+            "no decoder matches inputs [images [1, 3, 640, 640]], outputs [output0 [1, -1, -1]], task unknown, 0 class names"
+        );
+    }
+
+    #[test]
+    fn test_input_size() {
+        let mut signature = signature(vec![tensor("output0", &[1, 84, 8400])], None, 0);
+        assert_eq!(signature.input_size(Some((320, 320))), (320, 320));
+        assert_eq!(signature.input_size(None), (640, 640));
+
+        signature.inputs = vec![tensor("images", &[1, 3, 480, 640])];
+        assert_eq!(signature.input_size(None), (640, 480));
+
+        signature.inputs = vec![tensor("images", &[-1, 3, -1, -1])];
+        signature.metadata.input_size = Some((1280, 1280));
+        assert_eq!(signature.input_size(None), (1280, 1280));
+    }
+}
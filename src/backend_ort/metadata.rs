@@ -0,0 +1,145 @@
+//! Ultralytics ONNX export metadata.
+
+use ort::session::Session;
+
+/// Input size used when neither the caller nor the model metadata provide one.
+pub(crate) const DEFAULT_INPUT_SIZE: (u32, u32) = (640, 640);
+
+/// Metadata embedded by Ultralytics in the custom properties of its ONNX exports.
+///
+/// Every field is optional: models from other exporters simply have no metadata.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UltralyticsMetadata {
+    /// Class names, indexed by class ID (`names`)
+    pub class_names: Vec<String>,
+    /// Input size as (width, height) (`imgsz`)
+    pub input_size: Option<(u32, u32)>,
+    /// Maximum stride of the model (`stride`)
+    pub stride: Option<u32>,
+    /// Task the model was trained for: `detect`, `segment`, `pose`, `obb` or `classify` (`task`)
+    pub task: Option<String>,
+    /// Export batch size (`batch`)
+    pub batch: Option<u32>,
+}
+
+impl UltralyticsMetadata {
+    /// Reads the metadata of a session. Missing or malformed properties are ignored.
+    pub fn from_session(session: &Session) -> Self {
+        match session.metadata() {
+            Ok(metadata) => Self::from_properties(|key| metadata.custom(key).ok().flatten()),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Builds the metadata from a custom property lookup.
+    pub(crate) fn from_properties<F: Fn(&str) -> Option<String>>(property: F) -> Self {
+        Self {
+            class_names: property("names").and_then(|v| parse_names(&v)).unwrap_or_default(),
+            input_size: property("imgsz").and_then(|v| parse_imgsz(&v)),
+            stride: property("stride").and_then(|v| v.trim().parse().ok()),
+            task: property("task").map(|v| v.trim().to_string()).filter(|v| !v.is_empty()),
+            batch: property("batch").and_then(|v| v.trim().parse().ok()),
+        }
+    }
+}
+
+/// Parses a Python dict literal such as `{0: 'person', 1: "person's bag"}` into names ordered by key.
+fn parse_names(value: &str) -> Option<Vec<String>> {
+    let body = value.trim().strip_prefix('{')?.strip_suffix('}')?;
+    let mut chars = body.chars().peekable();
+    let mut entries: Vec<(usize, String)> = Vec::new();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace() || *c == ',').is_some() {}
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let mut key = String::new();
+        while let Some(c) = chars.next_if(|c| *c != ':') {
+            key.push(c);
+        }
+        chars.next()?;
+        let key: usize = key.trim().parse().ok()?;
+
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let quote = chars.next().filter(|c| *c == '\'' || *c == '"')?;
+        let mut name = String::new();
+        loop {
+            match chars.next()? {
+                '\\' => name.push(chars.next()?),
+                c if c == quote => break,
+                c => name.push(c),
+            }
+        }
+        entries.push((key, name));
+    }
+
+    entries.sort_by_key(|(key, _)| *key);
+    // Keys must be 0..N so that names can be indexed by class ID
+    if entries.iter().enumerate().any(|(i, (key, _))| i != *key) {
+        return None;
+    }
+    Some(entries.into_iter().map(|(_, name)| name).collect())
+}
+
+/// Parses `[640, 640]` (height, width) or `640` into (width, height).
+fn parse_imgsz(value: &str) -> Option<(u32, u32)> {
+    let body = value.trim().trim_start_matches(['[', '(']).trim_end_matches([']', ')']);
+    let sizes: Vec<u32> = body
+        .split(',')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(|v| v.parse().ok())
+        .collect::<Option<_>>()?;
+    match sizes.as_slice() {
+        [size] => Some((*size, *size)),
+        [height, width] => Some((*width, *height)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_properties() {
+        let metadata = UltralyticsMetadata::from_properties(|key| {
+            match key {
+                "names" => Some("{0: 'person', 1: \"person's bag\", 2: 'traffic light'}".to_string()),
+                "imgsz" => Some("[480, 640]".to_string()),
+                "stride" => Some("32".to_string()),
+                "task" => Some("detect".to_string()),
+                "batch" => Some("1".to_string()),
+                _ => None,
+            }
+        });
+
+        assert_eq!(metadata.class_names, vec!["person", "person's bag", "traffic light"]);
+        assert_eq!(metadata.input_size, Some((640, 480)));
+        assert_eq!(metadata.stride, Some(32));
+        assert_eq!(metadata.task.as_deref(), Some("detect"));
+        assert_eq!(metadata.batch, Some(1));
+    }
+
+    #[test]
+    fn test_missing_or_malformed_properties() {
+        assert_eq!(UltralyticsMetadata::from_properties(|_| None), UltralyticsMetadata::default());
+
+        let metadata = UltralyticsMetadata::from_properties(|key| match key {
+            "names" => Some("{1: 'gap'}".to_string()),
+            "imgsz" => Some("[1, 2, 3]".to_string()),
+            "stride" => Some("abc".to_string()),
+            _ => None,
+        });
+        assert!(metadata.class_names.is_empty());
+        assert_eq!(metadata.input_size, None);
+        assert_eq!(metadata.stride, None);
+    }
+
+    #[test]
+    fn test_parse_imgsz_single() {
+        assert_eq!(parse_imgsz("320"), Some((320, 320)));
+    }
+}
@@ -3,7 +3,43 @@
 //! This module provides YOLO model implementations using ONNX Runtime (`ort` crate).
 //! It does not require OpenCV and uses pure Rust for preprocessing.
 
+pub(crate) mod session;
+mod tensor;
+mod tensor_names;
+mod metadata;
 mod model_ultralytics;
+mod model_v10;
+mod model_v5;
+mod model_classic;
+mod model_yolox;
+mod model_rtdetr;
+mod model_nas;
+mod model_end2end;
+mod model_ssd;
+mod model_seg;
+mod model_pose;
+mod model_obb;
+mod model_cls;
+mod model_face;
+mod model_world;
+mod auto;
 
 pub use model_ultralytics::ModelUltralyticsOrt;
 pub use model_ultralytics::OrtModelError;
+pub use metadata::UltralyticsMetadata;
+pub use tensor_names::TensorNames;
+pub use model_v10::ModelYOLOv10Ort;
+pub use model_v5::ModelYOLOv5Ort;
+pub use model_classic::ModelYOLOClassicOrt;
+pub use model_yolox::ModelYOLOXOrt;
+pub use model_rtdetr::ModelRTDETROrt;
+pub use model_nas::ModelYOLONASOrt;
+pub use model_end2end::ModelEnd2EndOrt;
+pub use model_ssd::ModelSSDOrt;
+pub use model_seg::ModelUltralyticsSegOrt;
+pub use model_pose::ModelUltralyticsPoseOrt;
+pub use model_obb::ModelUltralyticsObbOrt;
+pub use model_cls::ModelClassifierOrt;
+pub use model_face::{FaceModelFormat, ModelFaceOrt};
+pub use model_world::{ModelYOLOWorldOrt, TextEmbeddings};
+pub use auto::{BoxedDetector, OutputLayout};
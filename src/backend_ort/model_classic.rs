@@ -0,0 +1,389 @@
+//! "Classic" YOLO models (v3, v4, v7) exported to ONNX, using ONNX Runtime.
+
+use ort::session::Session;
+use ort::inputs;
+
+use crate::bbox::BBox;
+use crate::model_trait::DetectionVecs;
+use crate::image_buffer::ImageBuffer;
+use crate::postprocess::{Detection, YoloLayer, NmsStrategy, filter_by_class, detections_to_vecs, argmax, decode_yolo_layer};
+use crate::preprocessing::{preprocess, PreprocessMeta};
+
+use super::OrtModelError;
+use super::tensor::{extract_output, input_element_type, input_value};
+use super::tensor_names::TensorNames;
+
+/// Classic YOLO model (v3, v4, v7) using ONNX Runtime.
+///
+/// This is the ORT counterpart of `ModelYOLOClassic` from the OpenCV backend.
+/// It expects ONNX exports with already decoded YOLO layers: every output tensor
+/// is `[N, 5 + num_classes]` (optionally with leading unit dimensions) where each
+/// row is `(cx, cy, w, h, objectness, class probabilities...)` and coordinates are
+/// relative to the network input (0.0 - 1.0). Models with several YOLO layers may
+/// expose one output tensor per layer, all of them are aggregated.
+///
+/// Exports that keep the raw `[1, A * (5 + num_classes), H, W]` YOLO heads are
+/// supported too once their anchors are provided via [`Self::set_yolo_layers`].
+pub struct ModelYOLOClassicOrt {
+    session: Session,
+    tensor_names: TensorNames,
+    input_width: u32,
+    input_height: u32,
+    class_filters: Vec<usize>,
+    nms_strategy: NmsStrategy,
+    use_letterbox: bool,
+    yolo_layers: Vec<YoloLayer>,
+}
+
+impl ModelYOLOClassicOrt {
+    /// Creates a new model from an ONNX file.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let model = ModelYOLOClassicOrt::new_from_file(
+    ///     "yolov4-tiny.onnx",
+    ///     (416, 416),
+    ///     vec![],  // detect all classes
+    /// )?;
+    /// ```
+    pub fn new_from_file(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with CUDA acceleration.
+    ///
+    /// Requires the `ort-cuda-backend` feature.
+    #[cfg(feature = "ort-cuda-backend")]
+    pub fn new_from_file_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_cuda(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with TensorRT acceleration.
+    ///
+    /// Requires the `ort-tensorrt-backend` feature.
+    #[cfg(feature = "ort-tensorrt-backend")]
+    pub fn new_from_file_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_tensorrt(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model with custom session options.
+    ///
+    /// # Arguments
+    /// * `session` - Pre-configured ORT session
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect
+    pub fn from_session(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Self {
+        // Darknet converters do not agree on the input name ("input", "000_net", ...)
+        let num_outputs = session.outputs.len().max(1);
+        let tensor_names = TensorNames::from_session(&session, num_outputs);
+        Self {
+            session,
+            tensor_names,
+            input_width: input_size.0,
+            input_height: input_size.1,
+            class_filters,
+            nms_strategy: NmsStrategy::default(),
+            #[cfg(feature = "letterbox")]
+            use_letterbox: true,
+            #[cfg(not(feature = "letterbox"))]
+            use_letterbox: false,
+            yolo_layers: Vec::new(),
+        }
+    }
+
+    /// Creates a new model from an ONNX file with custom tensor names.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn new_from_file_with_names(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Self::from_session_with_names(session, input_size, class_filters, tensor_names)
+    }
+
+    /// Creates a new model with custom session options and tensor names.
+    ///
+    /// Every named output is read as one YOLO layer, in the given order.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn from_session_with_names(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        tensor_names.validate(&session, tensor_names.outputs().len().max(1))?;
+        let mut model = Self::from_session(session, input_size, class_filters);
+        model.tensor_names = tensor_names;
+        Ok(model)
+    }
+
+    /// Enables or disables letterbox preprocessing.
+    ///
+    /// Letterbox preserves aspect ratio by padding with gray.
+    /// Default is `false` (stretch mode).
+    pub fn set_letterbox(&mut self, enabled: bool) {
+        self.use_letterbox = enabled;
+    }
+
+    /// Sets the NMS algorithm applied to the detections.
+    ///
+    /// Default is [`NmsStrategy::Greedy`].
+    pub fn set_nms_strategy(&mut self, strategy: NmsStrategy) {
+        self.nms_strategy = strategy;
+    }
+
+    /// Returns the input size (width, height).
+    pub fn input_size(&self) -> (u32, u32) {
+        (self.input_width, self.input_height)
+    }
+
+    /// Returns the input and output tensor names.
+    pub fn tensor_names(&self) -> &TensorNames {
+        &self.tensor_names
+    }
+
+    /// Sets the YOLO head configurations for models with raw (undecoded) outputs.
+    ///
+    /// One layer per output tensor, in the order of [`Self::tensor_names`]. When empty
+    /// (the default) outputs are expected to be already decoded.
+    ///
+    /// The confidence of decoded outputs is the objectness, as in the OpenCV backend,
+    /// so the layers are switched to [`YoloLayer::with_objectness_confidence`] to report
+    /// the same.
+    pub fn set_yolo_layers(&mut self, yolo_layers: Vec<YoloLayer>) {
+        self.yolo_layers = yolo_layers
+            .into_iter()
+            .map(|layer| layer.with_objectness_confidence(true))
+            .collect();
+    }
+
+    /// Returns the YOLO head configurations used for raw outputs.
+    pub fn yolo_layers(&self) -> &[YoloLayer] {
+        &self.yolo_layers
+    }
+
+    /// Runs inference on an image.
+    ///
+    /// # Arguments
+    /// * `image` - Input image buffer
+    /// * `conf_threshold` - Confidence threshold (0.0 - 1.0)
+    /// * `nms_threshold` - NMS IoU threshold (0.0 - 1.0)
+    ///
+    /// # Returns
+    /// Tuple of (bounding boxes, class IDs, confidence scores)
+    pub fn forward(
+        &mut self,
+        image: &ImageBuffer,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs, OrtModelError> {
+        let (tensor, meta) = preprocess(
+            image,
+            self.input_width,
+            self.input_height,
+            self.use_letterbox,
+        );
+
+        let input_type = input_element_type(&self.session, self.tensor_names.input());
+        let outputs = self.session.run(
+            inputs![self.tensor_names.input() => input_value(&tensor, input_type)?]
+        )?;
+
+        let output_names = self.tensor_names.outputs();
+        if !self.yolo_layers.is_empty() && self.yolo_layers.len() != output_names.len() {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Model has {} outputs, but {} YOLO layers are configured",
+                output_names.len(),
+                self.yolo_layers.len()
+            )));
+        }
+
+        // Aggregate detections from every YOLO layer
+        let mut detections = Vec::new();
+        for (i, name) in output_names.iter().enumerate() {
+            let output = extract_output(&outputs, name)?;
+            if let Some(layer) = self.yolo_layers.get(i) {
+                detections.extend(
+                    decode_yolo_layer(&output.view(), layer, conf_threshold, &meta)
+                        .map_err(|e| OrtModelError::InvalidOutputShape(e.to_string()))?,
+                );
+            } else {
+                detections.extend(Self::parse_output_array_static(
+                    &output.view(),
+                    conf_threshold,
+                    (self.input_width, self.input_height),
+                    &meta,
+                )?);
+            }
+        }
+
+        let filtered = filter_by_class(&detections, &self.class_filters);
+        let final_detections = self.nms_strategy.apply(&filtered, nms_threshold, conf_threshold);
+
+        Ok(detections_to_vecs(final_detections))
+    }
+
+    /// Parses a single decoded YOLO layer output into detections.
+    ///
+    /// Coordinates are relative to the network input, so they are scaled by
+    /// `input_size` before undoing the preprocessing.
+    fn parse_output_array_static(
+        output: &ndarray::ArrayViewD<f32>,
+        conf_threshold: f32,
+        input_size: (u32, u32),
+        meta: &PreprocessMeta,
+    ) -> Result<Vec<Detection>, OrtModelError> {
+        let shape = output.shape();
+        let num_features = shape.last().copied().unwrap_or(0);
+        let leading_ok = shape.len() >= 2 && shape[..shape.len() - 2].iter().all(|&d| d == 1);
+
+        if !leading_ok || num_features < 6 {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected shape [N, 5 + num_classes], got {:?}",
+                shape
+            )));
+        }
+
+        let num_predictions = shape[shape.len() - 2];
+        let rows = output
+            .to_shape((num_predictions, num_features))
+            .map_err(|e| OrtModelError::InvalidOutputShape(e.to_string()))?;
+
+        let input_width = input_size.0 as f32;
+        let input_height = input_size.1 as f32;
+
+        let mut detections = Vec::new();
+
+        for row in rows.rows() {
+            // Same semantics as the OpenCV backend: objectness is the confidence,
+            // the class is the best scoring one among the class probabilities
+            let objectness = row[4];
+            if objectness <= conf_threshold {
+                continue;
+            }
+
+            let class_scores: Vec<f32> = row.iter().skip(5).copied().collect();
+            if let Some((class_idx, class_score)) = argmax(&class_scores)
+                && class_score > 0.0
+            {
+                let (cx, cy, w, h) = meta.inverse_transform(
+                    row[0] * input_width,
+                    row[1] * input_height,
+                    row[2] * input_width,
+                    row[3] * input_height,
+                );
+
+                detections.push(Detection::new(BBox::from_center(cx, cy, w, h), class_idx, objectness));
+            }
+        }
+
+        Ok(detections)
+    }
+}
+
+impl crate::ObjectDetector for ModelYOLOClassicOrt {
+    type Input = ImageBuffer;
+    type Error = OrtModelError;
+
+    fn detect(
+        &mut self,
+        input: &Self::Input,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs, Self::Error> {
+        self.forward(input, conf_threshold, nms_threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{Array2, Array3};
+
+    #[test]
+    fn test_parse_output_normalized() {
+        // 2 predictions, 3 classes
+        let mut output = Array2::<f32>::zeros((2, 8));
+        output[[0, 0]] = 0.5;
+        output[[0, 1]] = 0.5;
+        output[[0, 2]] = 0.25;
+        output[[0, 3]] = 0.5;
+        output[[0, 4]] = 0.8;
+        output[[0, 7]] = 0.7;
+        // Objectness below threshold
+        output[[1, 4]] = 0.1;
+        output[[1, 5]] = 0.9;
+
+        let detections = ModelYOLOClassicOrt::parse_output_array_static(
+            &output.view().into_dyn(),
+            0.25,
+            (416, 416),
+            &PreprocessMeta::stretch((832, 416), (416, 416)),
+        )
+        .unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].class_id, 2);
+        assert!((detections[0].confidence - 0.8).abs() < 0.001);
+        assert_eq!(detections[0].bbox, BBox::new(312, 104, 208, 208));
+    }
+
+    #[test]
+    fn test_parse_output_leading_batch_dimension() {
+        let mut output = Array3::<f32>::zeros((1, 1, 6));
+        output[[0, 0, 4]] = 0.9;
+        output[[0, 0, 5]] = 0.9;
+
+        let detections = ModelYOLOClassicOrt::parse_output_array_static(
+            &output.view().into_dyn(),
+            0.25,
+            (416, 416),
+            &PreprocessMeta::stretch((832, 416), (416, 416)),
+        )
+        .unwrap();
+
+        assert_eq!(detections.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_output_invalid_shape() {
+        let output = Array3::<f32>::zeros((2, 10, 85));
+        let result = ModelYOLOClassicOrt::parse_output_array_static(
+            &output.view().into_dyn(),
+            0.25,
+            (416, 416),
+            &PreprocessMeta::stretch((832, 416), (416, 416)),
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,252 @@
+//! Image classification models (YOLOv8-cls, YOLO11-cls, generic ImageNet classifiers) using ONNX Runtime.
+
+use ort::session::Session;
+use ort::inputs;
+
+use crate::image_buffer::ImageBuffer;
+use crate::postprocess::{softmax, top_k_classes};
+use crate::preprocessing::{normalize_mean_std, resize_center_crop, resize, to_nchw_tensor};
+
+use super::OrtModelError;
+use super::tensor::{extract_output, input_element_type, input_value};
+use super::tensor_names::TensorNames;
+
+/// Image classification model using ONNX Runtime.
+///
+/// The model takes a `[1, 3, H, W]` RGB input and produces `[1, num_classes]` scores.
+///
+/// Defaults match Ultralytics `-cls` exports: center crop, pixels in 0.0 - 1.0 without
+/// mean/std normalization, and probabilities already softmaxed by the model.
+/// For torchvision-style classifiers use [`Self::set_normalization`] with
+/// [`IMAGENET_MEAN`](crate::preprocessing::IMAGENET_MEAN) / [`IMAGENET_STD`](crate::preprocessing::IMAGENET_STD)
+/// and [`Self::set_apply_softmax`], since those emit raw logits.
+pub struct ModelClassifierOrt {
+    session: Session,
+    tensor_names: TensorNames,
+    input_width: u32,
+    input_height: u32,
+    center_crop: bool,
+    normalization: Option<([f32; 3], [f32; 3])>,
+    apply_softmax: bool,
+}
+
+impl ModelClassifierOrt {
+    /// Creates a new model from an ONNX file.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let model = ModelClassifierOrt::new_from_file("yolov8n-cls.onnx", (224, 224))?;
+    /// ```
+    pub fn new_from_file(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Ok(Self::from_session(session, input_size))
+    }
+
+    /// Creates a new model from an ONNX file with CUDA acceleration.
+    ///
+    /// Requires the `ort-cuda-backend` feature.
+    #[cfg(feature = "ort-cuda-backend")]
+    pub fn new_from_file_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_cuda(model_path)?;
+        Ok(Self::from_session(session, input_size))
+    }
+
+    /// Creates a new model from an ONNX file with TensorRT acceleration.
+    ///
+    /// Requires the `ort-tensorrt-backend` feature.
+    #[cfg(feature = "ort-tensorrt-backend")]
+    pub fn new_from_file_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_tensorrt(model_path)?;
+        Ok(Self::from_session(session, input_size))
+    }
+
+    /// Creates a new model with custom session options.
+    ///
+    /// # Arguments
+    /// * `session` - Pre-configured ORT session
+    /// * `input_size` - Model input size as (width, height)
+    pub fn from_session(session: Session, input_size: (u32, u32)) -> Self {
+        // Ultralytics names the input "images", torchvision exports usually "input"
+        let tensor_names = TensorNames::from_session(&session, 1);
+        Self {
+            session,
+            tensor_names,
+            input_width: input_size.0,
+            input_height: input_size.1,
+            center_crop: true,
+            normalization: None,
+            apply_softmax: false,
+        }
+    }
+
+    /// Creates a new model from an ONNX file with custom tensor names.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn new_from_file_with_names(
+        model_path: &str,
+        input_size: (u32, u32),
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Self::from_session_with_names(session, input_size, tensor_names)
+    }
+
+    /// Creates a new model with custom session options and tensor names.
+    ///
+    /// The model reads one output.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn from_session_with_names(
+        session: Session,
+        input_size: (u32, u32),
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        tensor_names.validate(&session, 1)?;
+        let mut model = Self::from_session(session, input_size);
+        model.tensor_names = tensor_names;
+        Ok(model)
+    }
+
+    /// Enables or disables center crop preprocessing.
+    ///
+    /// When disabled the image is stretched to the input size.
+    /// Default is `true`.
+    pub fn set_center_crop(&mut self, enabled: bool) {
+        self.center_crop = enabled;
+    }
+
+    /// Sets per-channel (RGB) mean/std normalization, applied after scaling pixels to 0.0 - 1.0.
+    ///
+    /// Default is `None` (no normalization).
+    pub fn set_normalization(&mut self, mean_std: Option<([f32; 3], [f32; 3])>) {
+        self.normalization = mean_std;
+    }
+
+    /// Enables or disables applying softmax to the model output.
+    ///
+    /// Default is `false` (output is already probabilities).
+    pub fn set_apply_softmax(&mut self, enabled: bool) {
+        self.apply_softmax = enabled;
+    }
+
+    /// Returns the input size (width, height).
+    pub fn input_size(&self) -> (u32, u32) {
+        (self.input_width, self.input_height)
+    }
+
+    /// Returns the input and output tensor names.
+    pub fn tensor_names(&self) -> &TensorNames {
+        &self.tensor_names
+    }
+
+    /// Runs inference on an image.
+    ///
+    /// # Arguments
+    /// * `image` - Input image buffer
+    /// * `top_k` - Maximum number of classes to return
+    ///
+    /// # Returns
+    /// Up to `top_k` pairs of (class ID, probability), sorted by descending probability
+    pub fn forward(
+        &mut self,
+        image: &ImageBuffer,
+        top_k: usize,
+    ) -> Result<Vec<(usize, f32)>, OrtModelError> {
+        let resized = if self.center_crop {
+            resize_center_crop(image, self.input_width, self.input_height)
+        } else {
+            resize(image, self.input_width, self.input_height, false).0
+        };
+        let mut tensor = to_nchw_tensor(&resized);
+        if let Some((mean, std)) = self.normalization {
+            normalize_mean_std(&mut tensor, mean, std);
+        }
+
+        let input_type = input_element_type(&self.session, self.tensor_names.input());
+        let outputs = self.session.run(
+            inputs![self.tensor_names.input() => input_value(&tensor, input_type)?]
+        )?;
+
+        let output = extract_output(&outputs, self.tensor_names.output(0))?;
+
+        Self::parse_output_array_static(&output.view(), self.apply_softmax, top_k)
+    }
+
+    /// Parses the `[1, num_classes]` output into the top-k classes.
+    fn parse_output_array_static(
+        output: &ndarray::ArrayViewD<f32>,
+        apply_softmax: bool,
+        top_k: usize,
+    ) -> Result<Vec<(usize, f32)>, OrtModelError> {
+        let shape = output.shape();
+
+        if shape.len() != 2 || shape[0] != 1 || shape[1] == 0 {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected shape [1, num_classes], got {:?}",
+                shape
+            )));
+        }
+
+        let scores: Vec<f32> = output.iter().copied().collect();
+        let scores = if apply_softmax { softmax(&scores) } else { scores };
+
+        Ok(top_k_classes(&scores, top_k))
+    }
+}
+
+impl crate::ImageClassifier for ModelClassifierOrt {
+    type Input = ImageBuffer;
+    type Error = OrtModelError;
+
+    fn classify(
+        &mut self,
+        input: &Self::Input,
+        top_k: usize,
+    ) -> Result<Vec<(usize, f32)>, Self::Error> {
+        self.forward(input, top_k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::Array2;
+
+    #[test]
+    fn test_parse_output_probabilities() {
+        let output = Array2::from_shape_vec((1, 4), vec![0.1, 0.6, 0.05, 0.25]).unwrap();
+        let top = ModelClassifierOrt::parse_output_array_static(&output.view().into_dyn(), false, 2).unwrap();
+        assert_eq!(top, vec![(1, 0.6), (3, 0.25)]);
+    }
+
+    #[test]
+    fn test_parse_output_logits() {
+        let output = Array2::from_shape_vec((1, 3), vec![0.0, 2.0, 0.0]).unwrap();
+        let top = ModelClassifierOrt::parse_output_array_static(&output.view().into_dyn(), true, 1).unwrap();
+        assert_eq!(top[0].0, 1);
+        let expected = 2.0f32.exp() / (2.0f32.exp() + 2.0);
+        assert!((top[0].1 - expected).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_parse_output_invalid_shape() {
+        let output = Array2::<f32>::zeros((2, 10));
+        let result = ModelClassifierOrt::parse_output_array_static(&output.view().into_dyn(), false, 5);
+        assert!(result.is_err());
+    }
+}
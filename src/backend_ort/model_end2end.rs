@@ -0,0 +1,477 @@
+//! Models with embedded NMS (end-to-end exports) using ONNX Runtime.
+
+use ort::session::Session;
+use ort::inputs;
+
+use crate::bbox::BBox;
+use crate::model_trait::DetectionVecs;
+use crate::image_buffer::ImageBuffer;
+use crate::postprocess::{Detection, filter_by_class, detections_to_vecs};
+use crate::preprocessing::{preprocess, PreprocessMeta};
+
+use super::OrtModelError;
+use super::tensor::{extract_output, input_element_type, input_value};
+use super::tensor_names::TensorNames;
+
+/// Accepted names of the EfficientNMS outputs, by exporter.
+pub(super) const NUM_DETS_NAMES: [&str; 2] = ["num_dets", "num_detections"];
+const BOXES_NAMES: [&str; 3] = ["det_boxes", "bboxes", "boxes"];
+const SCORES_NAMES: [&str; 2] = ["det_scores", "scores"];
+const CLASSES_NAMES: [&str; 3] = ["det_classes", "labels", "classes"];
+
+/// YOLO model exported with NMS inside the graph, using ONNX Runtime.
+///
+/// Two output signatures are recognised:
+/// - EfficientNMS (TensorRT-oriented exports): `num_dets [1, 1]`, `det_boxes [1, K, 4]`,
+///   `det_scores [1, K]` and `det_classes [1, K]`, boxes are `(x1, y1, x2, y2)`.
+/// - ONNX `NonMaxSuppression` (YOLOv7 `--end2end` for ONNX Runtime): a single
+///   `[N, 7]` output of `(batch_id, x1, y1, x2, y2, class_id, score)` rows.
+///
+/// Detections are returned as is: only the confidence threshold, class filter and
+/// inverse letterbox/stretch transform are applied on the Rust side.
+pub struct ModelEnd2EndOrt {
+    session: Session,
+    tensor_names: TensorNames,
+    input_width: u32,
+    input_height: u32,
+    class_filters: Vec<usize>,
+    use_letterbox: bool,
+}
+
+impl ModelEnd2EndOrt {
+    /// Creates a new model from an ONNX file.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let model = ModelEnd2EndOrt::new_from_file(
+    ///     "yolov7-tiny-nms.onnx",
+    ///     (640, 640),
+    ///     vec![],  // detect all classes
+    /// )?;
+    /// ```
+    pub fn new_from_file(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with CUDA acceleration.
+    ///
+    /// Requires the `ort-cuda-backend` feature.
+    #[cfg(feature = "ort-cuda-backend")]
+    pub fn new_from_file_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_cuda(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with TensorRT acceleration.
+    ///
+    /// Requires the `ort-tensorrt-backend` feature. This is the only execution
+    /// provider able to run the EfficientNMS plugin.
+    #[cfg(feature = "ort-tensorrt-backend")]
+    pub fn new_from_file_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_tensorrt(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model with custom session options.
+    ///
+    /// # Arguments
+    /// * `session` - Pre-configured ORT session
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect
+    pub fn from_session(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Self {
+        let tensor_names = TensorNames::from_session(&session, session.outputs.len().max(1));
+        Self {
+            session,
+            tensor_names,
+            input_width: input_size.0,
+            input_height: input_size.1,
+            class_filters,
+            #[cfg(feature = "letterbox")]
+            use_letterbox: true,
+            #[cfg(not(feature = "letterbox"))]
+            use_letterbox: false,
+        }
+    }
+
+    /// Creates a new model from an ONNX file with custom tensor names.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn new_from_file_with_names(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Self::from_session_with_names(session, input_size, class_filters, tensor_names)
+    }
+
+    /// Creates a new model with custom session options and tensor names.
+    ///
+    /// The model reads either the four EfficientNMS outputs, in the `num_dets`, boxes,
+    /// scores, classes order when the names are not the standard ones, or the single
+    /// NonMaxSuppression output.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn from_session_with_names(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        // A single name selects the NonMaxSuppression output, anything else the EfficientNMS ones
+        let num_outputs = if tensor_names.outputs().len() == 1 { 1 } else { 4 };
+        tensor_names.validate(&session, num_outputs)?;
+        let mut model = Self::from_session(session, input_size, class_filters);
+        model.tensor_names = tensor_names;
+        Ok(model)
+    }
+
+    /// Enables or disables letterbox preprocessing.
+    ///
+    /// Letterbox preserves aspect ratio by padding with gray.
+    /// Default is `false` (stretch mode).
+    pub fn set_letterbox(&mut self, enabled: bool) {
+        self.use_letterbox = enabled;
+    }
+
+    /// Returns the input size (width, height).
+    pub fn input_size(&self) -> (u32, u32) {
+        (self.input_width, self.input_height)
+    }
+
+    /// Returns the input and output tensor names.
+    pub fn tensor_names(&self) -> &TensorNames {
+        &self.tensor_names
+    }
+
+    /// Runs inference on an image.
+    ///
+    /// No NMS is performed since the model outputs final detections.
+    ///
+    /// # Arguments
+    /// * `image` - Input image buffer
+    /// * `conf_threshold` - Confidence threshold (0.0 - 1.0)
+    ///
+    /// # Returns
+    /// Tuple of (bounding boxes, class IDs, confidence scores)
+    pub fn forward(
+        &mut self,
+        image: &ImageBuffer,
+        conf_threshold: f32,
+    ) -> Result<DetectionVecs, OrtModelError> {
+        let (tensor, meta) = preprocess(
+            image,
+            self.input_width,
+            self.input_height,
+            self.use_letterbox,
+        );
+
+        let input_type = input_element_type(&self.session, self.tensor_names.input());
+        let outputs = self.session.run(
+            inputs![self.tensor_names.input() => input_value(&tensor, input_type)?]
+        )?;
+
+        // Class and count outputs are integer tensors, convert everything to f32
+        let output_names = self.tensor_names.outputs();
+        let mut arrays = Vec::with_capacity(output_names.len());
+        for name in output_names {
+            arrays.push((name.clone(), extract_output(&outputs, name)?));
+        }
+
+        let detections = match arrays.len() {
+            4 => {
+                let [num_dets, boxes, scores, classes] = Self::select_efficient_nms_outputs(&arrays);
+                Self::parse_efficient_nms_static(
+                    &arrays[num_dets].1.view(),
+                    &arrays[boxes].1.view(),
+                    &arrays[scores].1.view(),
+                    &arrays[classes].1.view(),
+                    conf_threshold,
+                    &meta,
+                )?
+            }
+            1 => Self::parse_nms_rows_static(&arrays[0].1.view(), conf_threshold, &meta)?,
+            n => {
+                return Err(OrtModelError::InvalidOutputShape(format!(
+                    "Expected 4 EfficientNMS outputs or a single NonMaxSuppression output, got {} outputs",
+                    n
+                )));
+            }
+        };
+
+        let filtered = filter_by_class(&detections, &self.class_filters);
+
+        Ok(detections_to_vecs(filtered))
+    }
+
+    /// Finds the indices of the `num_dets`, boxes, scores and classes outputs.
+    ///
+    /// Known names are matched first, then the count (`[1, 1]`) and boxes (`[1, K, 4]`)
+    /// outputs by shape. The remaining outputs are assigned in the EfficientNMS plugin
+    /// output order, so every role gets a distinct output.
+    fn select_efficient_nms_outputs(arrays: &[(String, ndarray::ArrayD<f32>)]) -> [usize; 4] {
+        let names: [&[&str]; 4] = [&NUM_DETS_NAMES, &BOXES_NAMES, &SCORES_NAMES, &CLASSES_NAMES];
+        let shape_matches = |role: usize, shape: &[usize]| match role {
+            0 => shape.iter().product::<usize>() == 1,
+            1 => shape.len() == 3 && shape[2] == 4,
+            _ => false,
+        };
+
+        let mut selected: [Option<usize>; 4] = [None; 4];
+        let mut used = vec![false; arrays.len()];
+        // By name, then by shape, then in plugin order
+        for pass in 0..3 {
+            for (role, slot) in selected.iter_mut().enumerate() {
+                if slot.is_some() {
+                    continue;
+                }
+                let found = arrays.iter().enumerate().position(|(i, (name, array))| {
+                    !used[i]
+                        && match pass {
+                            0 => names[role].contains(&name.as_str()),
+                            1 => shape_matches(role, array.shape()),
+                            _ => true,
+                        }
+                });
+                if let Some(index) = found {
+                    *slot = Some(index);
+                    used[index] = true;
+                }
+            }
+        }
+
+        // `forward` only calls this with four outputs, so every role is assigned
+        selected.map(|index| index.unwrap_or(0))
+    }
+
+    /// Parses EfficientNMS outputs into detections.
+    fn parse_efficient_nms_static(
+        num_dets: &ndarray::ArrayViewD<f32>,
+        boxes: &ndarray::ArrayViewD<f32>,
+        scores: &ndarray::ArrayViewD<f32>,
+        classes: &ndarray::ArrayViewD<f32>,
+        conf_threshold: f32,
+        meta: &PreprocessMeta,
+    ) -> Result<Vec<Detection>, OrtModelError> {
+        let boxes_shape = boxes.shape();
+
+        if boxes_shape.len() != 3 || boxes_shape[0] != 1 || boxes_shape[2] != 4 {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected det_boxes shape [1, K, 4], got {:?}",
+                boxes_shape
+            )));
+        }
+
+        let max_dets = boxes_shape[1];
+        if scores.len() != max_dets || classes.len() != max_dets {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected det_scores and det_classes with {} elements, got {:?} and {:?}",
+                max_dets,
+                scores.shape(),
+                classes.shape()
+            )));
+        }
+
+        let count = num_dets
+            .iter()
+            .next()
+            .map(|&n| (n.max(0.0) as usize).min(max_dets))
+            .unwrap_or(0);
+
+        let mut detections = Vec::with_capacity(count);
+
+        for (i, (&score, &class_id)) in scores.iter().zip(classes.iter()).take(count).enumerate() {
+            if score < conf_threshold {
+                continue;
+            }
+
+            detections.push(Detection::new(
+                Self::xyxy_to_bbox(
+                    boxes[[0, i, 0]],
+                    boxes[[0, i, 1]],
+                    boxes[[0, i, 2]],
+                    boxes[[0, i, 3]],
+                    meta,
+                ),
+                class_id.round().max(0.0) as usize,
+                score,
+            ));
+        }
+
+        Ok(detections)
+    }
+
+    /// Parses `[N, 7]` NonMaxSuppression rows into detections.
+    ///
+    /// Rows from other batch items than the first one are ignored.
+    fn parse_nms_rows_static(
+        output: &ndarray::ArrayViewD<f32>,
+        conf_threshold: f32,
+        meta: &PreprocessMeta,
+    ) -> Result<Vec<Detection>, OrtModelError> {
+        let shape = output.shape();
+
+        if shape.len() != 2 || shape[1] != 7 {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected shape [N, 7], got {:?}",
+                shape
+            )));
+        }
+
+        let mut detections = Vec::new();
+
+        for i in 0..shape[0] {
+            let batch_id = output[[i, 0]];
+            let score = output[[i, 6]];
+            if batch_id != 0.0 || score < conf_threshold {
+                continue;
+            }
+
+            detections.push(Detection::new(
+                Self::xyxy_to_bbox(output[[i, 1]], output[[i, 2]], output[[i, 3]], output[[i, 4]], meta),
+                output[[i, 5]].round().max(0.0) as usize,
+                score,
+            ));
+        }
+
+        Ok(detections)
+    }
+
+    /// Maps an input space `(x1, y1, x2, y2)` box back to the original image.
+    fn xyxy_to_bbox(x1: f32, y1: f32, x2: f32, y2: f32, meta: &PreprocessMeta) -> BBox {
+        let (cx, cy, w, h) = meta.inverse_transform(
+            (x1 + x2) / 2.0,
+            (y1 + y2) / 2.0,
+            x2 - x1,
+            y2 - y1,
+        );
+        BBox::from_center(cx, cy, w, h)
+    }
+}
+
+impl crate::ObjectDetector for ModelEnd2EndOrt {
+    type Input = ImageBuffer;
+    type Error = OrtModelError;
+
+    /// Runs detection. `nms_threshold` is ignored since NMS is part of the model.
+    fn detect(
+        &mut self,
+        input: &Self::Input,
+        conf_threshold: f32,
+        _nms_threshold: f32,
+    ) -> Result<DetectionVecs, Self::Error> {
+        self.forward(input, conf_threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{Array2, Array3, ArrayD, IxDyn};
+
+    #[test]
+    fn test_parse_efficient_nms() {
+        let num_dets = Array2::<f32>::from_elem((1, 1), 2.0);
+        let mut boxes = Array3::<f32>::zeros((1, 100, 4));
+        boxes[[0, 0, 0]] = 10.0;
+        boxes[[0, 0, 1]] = 100.0;
+        boxes[[0, 0, 2]] = 60.0;
+        boxes[[0, 0, 3]] = 150.0;
+        let mut scores = Array2::<f32>::zeros((1, 100));
+        scores[[0, 0]] = 0.9;
+        scores[[0, 1]] = 0.1;
+        // Padding after num_dets must be ignored
+        scores[[0, 2]] = 0.9;
+        let mut classes = Array2::<f32>::zeros((1, 100));
+        classes[[0, 0]] = 3.0;
+
+        let detections = ModelEnd2EndOrt::parse_efficient_nms_static(
+            &num_dets.view().into_dyn(),
+            &boxes.view().into_dyn(),
+            &scores.view().into_dyn(),
+            &classes.view().into_dyn(),
+            0.25,
+            &PreprocessMeta::letterbox((1280, 960), (640, 640)),
+        )
+        .unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].class_id, 3);
+        assert_eq!(detections[0].bbox, BBox::new(20, 40, 100, 100));
+    }
+
+    #[test]
+    fn test_parse_nms_rows() {
+        let mut output = Array2::<f32>::zeros((3, 7));
+        output.row_mut(0).assign(&ndarray::arr1(&[0.0, 10.0, 100.0, 60.0, 150.0, 1.0, 0.8]));
+        output.row_mut(1).assign(&ndarray::arr1(&[0.0, 10.0, 100.0, 60.0, 150.0, 2.0, 0.1]));
+        // Second batch item
+        output.row_mut(2).assign(&ndarray::arr1(&[1.0, 10.0, 100.0, 60.0, 150.0, 2.0, 0.9]));
+
+        let detections =
+            ModelEnd2EndOrt::parse_nms_rows_static(&output.view().into_dyn(), 0.25, &PreprocessMeta::letterbox((1280, 960), (640, 640))).unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].class_id, 1);
+        assert_eq!(detections[0].bbox, BBox::new(20, 40, 100, 100));
+    }
+
+    #[test]
+    fn test_select_efficient_nms_outputs() {
+        let arrays = vec![
+            ("num_dets".to_string(), ArrayD::<f32>::zeros(IxDyn(&[1, 1]))),
+            ("labels".to_string(), ArrayD::<f32>::zeros(IxDyn(&[1, 100]))),
+            ("scores".to_string(), ArrayD::<f32>::zeros(IxDyn(&[1, 100]))),
+            ("bboxes".to_string(), ArrayD::<f32>::zeros(IxDyn(&[1, 100, 4]))),
+        ];
+
+        assert_eq!(ModelEnd2EndOrt::select_efficient_nms_outputs(&arrays), [0, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_select_efficient_nms_outputs_unknown_names() {
+        // Unknown names: the count and boxes outputs are found by shape, the fallbacks
+        // do not reuse the positions they took
+        let arrays = vec![
+            ("output_boxes".to_string(), ArrayD::<f32>::zeros(IxDyn(&[1, 100, 4]))),
+            ("output_count".to_string(), ArrayD::<f32>::zeros(IxDyn(&[1, 1]))),
+            ("output_scores".to_string(), ArrayD::<f32>::zeros(IxDyn(&[1, 100]))),
+            ("output_classes".to_string(), ArrayD::<f32>::zeros(IxDyn(&[1, 100]))),
+        ];
+        assert_eq!(ModelEnd2EndOrt::select_efficient_nms_outputs(&arrays), [1, 0, 2, 3]);
+
+        // Only the scores are named: the other roles take the unused positions
+        let arrays = vec![
+            ("scores".to_string(), ArrayD::<f32>::zeros(IxDyn(&[1, 100]))),
+            ("a".to_string(), ArrayD::<f32>::zeros(IxDyn(&[1, 1]))),
+            ("b".to_string(), ArrayD::<f32>::zeros(IxDyn(&[1, 100, 4]))),
+            ("c".to_string(), ArrayD::<f32>::zeros(IxDyn(&[1, 100]))),
+        ];
+        assert_eq!(ModelEnd2EndOrt::select_efficient_nms_outputs(&arrays), [1, 2, 0, 3]);
+    }
+}
@@ -0,0 +1,492 @@
+//! Face detection models with facial landmarks (SCRFD, YOLOv8-face) using ONNX Runtime.
+
+use ort::session::Session;
+use ort::inputs;
+
+use crate::bbox::BBox;
+use crate::model_trait::DetectionVecs;
+use crate::image_buffer::ImageBuffer;
+use crate::keypoint::Keypoint;
+use crate::postprocess::{Detection, NmsStrategy};
+use crate::preprocessing::{normalize_mean_std, preprocess, resize, to_nchw_tensor, PreprocessMeta};
+
+use super::tensor::{extract_output, input_element_type, input_value};
+use super::tensor_names::TensorNames;
+use super::OrtModelError;
+
+/// SCRFD input normalization `(x - 127.5) / 128` expressed for pixels in 0.0 - 1.0.
+const SCRFD_MEAN: [f32; 3] = [0.5, 0.5, 0.5];
+const SCRFD_STD: [f32; 3] = [128.0 / 255.0, 128.0 / 255.0, 128.0 / 255.0];
+
+/// Output layout of a face detection model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FaceModelFormat {
+    /// InsightFace SCRFD: per-stride score `[N, 1]`, box distance `[N, 4]` and
+    /// landmark offset `[N, 10]` outputs, anchors on a `stride` grid
+    Scrfd,
+    /// YOLOv8-face: single `[1, 5 + num_landmarks * 3, N]` output, like a pose model
+    YoloV8Face,
+}
+
+/// Face detection model with landmarks using ONNX Runtime.
+///
+/// The format is detected from the number of outputs: a single output is
+/// YOLOv8-face, several outputs are SCRFD (3 or 5 strides, with or without landmarks).
+///
+/// Landmarks are returned as [`Keypoint`]s in original image coordinates. SCRFD has
+/// no landmark visibility, so its landmarks have a confidence of 1.0.
+pub struct ModelFaceOrt {
+    session: Session,
+    tensor_names: TensorNames,
+    input_width: u32,
+    input_height: u32,
+    format: FaceModelFormat,
+    use_letterbox: bool,
+    nms_strategy: NmsStrategy,
+}
+
+impl ModelFaceOrt {
+    /// Creates a new model from an ONNX file.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let model = ModelFaceOrt::new_from_file("scrfd_2.5g_kps.onnx", (640, 640))?;
+    /// ```
+    pub fn new_from_file(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Ok(Self::from_session(session, input_size))
+    }
+
+    /// Creates a new model from an ONNX file with CUDA acceleration.
+    ///
+    /// Requires the `ort-cuda-backend` feature.
+    #[cfg(feature = "ort-cuda-backend")]
+    pub fn new_from_file_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_cuda(model_path)?;
+        Ok(Self::from_session(session, input_size))
+    }
+
+    /// Creates a new model from an ONNX file with TensorRT acceleration.
+    ///
+    /// Requires the `ort-tensorrt-backend` feature.
+    #[cfg(feature = "ort-tensorrt-backend")]
+    pub fn new_from_file_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_tensorrt(model_path)?;
+        Ok(Self::from_session(session, input_size))
+    }
+
+    /// Creates a new model with custom session options.
+    ///
+    /// # Arguments
+    /// * `session` - Pre-configured ORT session
+    /// * `input_size` - Model input size as (width, height)
+    pub fn from_session(session: Session, input_size: (u32, u32)) -> Self {
+        // SCRFD names the input "input.1", YOLOv8-face "images"
+        let tensor_names = TensorNames::from_session(&session, session.outputs.len().max(1));
+        Self::with_tensor_names(session, input_size, tensor_names)
+    }
+
+    /// Builds the model reading the given tensors, the format follows the number of outputs.
+    fn with_tensor_names(session: Session, input_size: (u32, u32), tensor_names: TensorNames) -> Self {
+        let format = if tensor_names.outputs().len() == 1 {
+            FaceModelFormat::YoloV8Face
+        } else {
+            FaceModelFormat::Scrfd
+        };
+        Self {
+            session,
+            tensor_names,
+            input_width: input_size.0,
+            input_height: input_size.1,
+            format,
+            // SCRFD is trained on aspect-preserving resizes
+            use_letterbox: format == FaceModelFormat::Scrfd || cfg!(feature = "letterbox"),
+            nms_strategy: NmsStrategy::default(),
+        }
+    }
+
+    /// Creates a new model from an ONNX file with custom tensor names.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn new_from_file_with_names(
+        model_path: &str,
+        input_size: (u32, u32),
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Self::from_session_with_names(session, input_size, tensor_names)
+    }
+
+    /// Creates a new model with custom session options and tensor names.
+    ///
+    /// One output name selects YOLOv8-face, several the SCRFD stride outputs.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn from_session_with_names(
+        session: Session,
+        input_size: (u32, u32),
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        tensor_names.validate(&session, tensor_names.outputs().len().max(1))?;
+        Ok(Self::with_tensor_names(session, input_size, tensor_names))
+    }
+
+    /// Enables or disables letterbox preprocessing.
+    ///
+    /// Letterbox preserves aspect ratio by padding with gray.
+    /// Default is `true` for SCRFD, `false` (stretch mode) for YOLOv8-face.
+    pub fn set_letterbox(&mut self, enabled: bool) {
+        self.use_letterbox = enabled;
+    }
+
+    /// Sets the NMS algorithm applied to the detections.
+    ///
+    /// Default is [`NmsStrategy::Greedy`].
+    pub fn set_nms_strategy(&mut self, strategy: NmsStrategy) {
+        self.nms_strategy = strategy;
+    }
+
+    /// Returns the detected output format.
+    pub fn format(&self) -> FaceModelFormat {
+        self.format
+    }
+
+    /// Returns the input size (width, height).
+    pub fn input_size(&self) -> (u32, u32) {
+        (self.input_width, self.input_height)
+    }
+
+    /// Returns the input and output tensor names.
+    pub fn tensor_names(&self) -> &TensorNames {
+        &self.tensor_names
+    }
+
+    /// Runs inference on an image, returning face bounding boxes only.
+    ///
+    /// Use [`Self::forward_landmarks`] to get landmarks as well.
+    ///
+    /// # Arguments
+    /// * `image` - Input image buffer
+    /// * `conf_threshold` - Confidence threshold (0.0 - 1.0)
+    /// * `nms_threshold` - NMS IoU threshold (0.0 - 1.0)
+    ///
+    /// # Returns
+    /// Tuple of (bounding boxes, class IDs (always 0), confidence scores)
+    pub fn forward(
+        &mut self,
+        image: &ImageBuffer,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs, OrtModelError> {
+        let (bboxes, class_ids, confidences, _) = self.forward_landmarks(image, conf_threshold, nms_threshold)?;
+        Ok((bboxes, class_ids, confidences))
+    }
+
+    /// Runs inference on an image, returning face bounding boxes and their landmarks.
+    ///
+    /// # Arguments
+    /// * `image` - Input image buffer
+    /// * `conf_threshold` - Confidence threshold (0.0 - 1.0)
+    /// * `nms_threshold` - NMS IoU threshold (0.0 - 1.0)
+    ///
+    /// # Returns
+    /// Tuple of (bounding boxes, class IDs (always 0), confidence scores, landmarks per face)
+    #[allow(clippy::type_complexity)]
+    pub fn forward_landmarks(
+        &mut self,
+        image: &ImageBuffer,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<(Vec<BBox>, Vec<usize>, Vec<f32>, Vec<Vec<Keypoint>>), OrtModelError> {
+        let (tensor, meta) = match self.format {
+            FaceModelFormat::Scrfd => {
+                let (resized, meta) = resize(image, self.input_width, self.input_height, self.use_letterbox);
+                let mut tensor = to_nchw_tensor(&resized);
+                normalize_mean_std(&mut tensor, SCRFD_MEAN, SCRFD_STD);
+                (tensor, meta)
+            }
+            FaceModelFormat::YoloV8Face => {
+                preprocess(image, self.input_width, self.input_height, self.use_letterbox)
+            }
+        };
+
+        let input_type = input_element_type(&self.session, self.tensor_names.input());
+        let outputs = self.session.run(
+            inputs![self.tensor_names.input() => input_value(&tensor, input_type)?]
+        )?;
+
+        let output_names = self.tensor_names.outputs();
+        let mut arrays = Vec::with_capacity(output_names.len());
+        for name in output_names {
+            arrays.push(extract_output(&outputs, name)?);
+        }
+
+        let (detections, landmarks) = match self.format {
+            FaceModelFormat::Scrfd => {
+                let views: Vec<_> = arrays.iter().map(|a| a.view()).collect();
+                Self::parse_scrfd_static(&views, (self.input_width, self.input_height), conf_threshold, &meta)?
+            }
+            FaceModelFormat::YoloV8Face => {
+                Self::parse_yolo_face_static(&arrays[0].view(), conf_threshold, &meta)?
+            }
+        };
+
+        let kept = self.nms_strategy.apply_indices(&detections, nms_threshold, conf_threshold);
+
+        let mut bboxes = Vec::with_capacity(kept.len());
+        let mut class_ids = Vec::with_capacity(kept.len());
+        let mut confidences = Vec::with_capacity(kept.len());
+        let mut kept_landmarks = Vec::with_capacity(kept.len());
+        for (idx, confidence) in kept {
+            bboxes.push(detections[idx].bbox);
+            class_ids.push(0);
+            confidences.push(confidence);
+            kept_landmarks.push(landmarks[idx].clone());
+        }
+
+        Ok((bboxes, class_ids, confidences, kept_landmarks))
+    }
+
+    /// Decodes SCRFD outputs.
+    ///
+    /// Outputs are grouped by their last dimension (1: scores, 4: boxes, 10: landmarks)
+    /// and matched to strides by their number of rows, the finest stride having the most.
+    fn parse_scrfd_static(
+        outputs: &[ndarray::ArrayViewD<f32>],
+        input_size: (u32, u32),
+        conf_threshold: f32,
+        meta: &PreprocessMeta,
+    ) -> Result<(Vec<Detection>, Vec<Vec<Keypoint>>), OrtModelError> {
+        let group = |width: usize| {
+            let mut group: Vec<&ndarray::ArrayViewD<f32>> = outputs
+                .iter()
+                .filter(|o| o.ndim() >= 2 && o.shape()[o.ndim() - 1] == width)
+                .collect();
+            group.sort_by_key(|o| std::cmp::Reverse(o.len() / width));
+            group
+        };
+        let scores = group(1);
+        let boxes = group(4);
+        let kps = group(10);
+
+        let strides: &[u32] = match scores.len() {
+            3 => &[8, 16, 32],
+            5 => &[8, 16, 32, 64, 128],
+            _ => &[],
+        };
+        if strides.is_empty() || boxes.len() != scores.len() || (!kps.is_empty() && kps.len() != scores.len()) {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected score [N, 1], bbox [N, 4] and optional kps [N, 10] outputs for 3 or 5 strides, got {:?}",
+                outputs.iter().map(|o| o.shape().to_vec()).collect::<Vec<_>>()
+            )));
+        }
+
+        let mut detections = Vec::new();
+        let mut landmarks = Vec::new();
+
+        for (level, &stride) in strides.iter().enumerate() {
+            let grid_w = input_size.0.div_ceil(stride) as usize;
+            let grid_h = input_size.1.div_ceil(stride) as usize;
+            let num_rows = scores[level].len();
+            let num_anchors = num_rows / (grid_w * grid_h).max(1);
+            if num_anchors == 0
+                || num_rows != grid_w * grid_h * num_anchors
+                || boxes[level].len() != num_rows * 4
+                || kps.get(level).is_some_and(|k| k.len() != num_rows * 10)
+            {
+                return Err(OrtModelError::InvalidOutputShape(format!(
+                    "Stride {} outputs do not match a {}x{} grid: {} scores",
+                    stride, grid_w, grid_h, num_rows
+                )));
+            }
+
+            let level_scores: Vec<f32> = scores[level].iter().copied().collect();
+            let level_boxes: Vec<f32> = boxes[level].iter().copied().collect();
+            let level_kps: Option<Vec<f32>> = kps.get(level).map(|k| k.iter().copied().collect());
+            let stride_f = stride as f32;
+
+            for (row, &score) in level_scores.iter().enumerate() {
+                if score < conf_threshold {
+                    continue;
+                }
+
+                // Anchors are laid out row by row, `num_anchors` per grid cell
+                let cell = row / num_anchors;
+                let ax = (cell % grid_w) as f32 * stride_f;
+                let ay = (cell / grid_w) as f32 * stride_f;
+
+                let d = &level_boxes[row * 4..row * 4 + 4];
+                let x1 = ax - d[0] * stride_f;
+                let y1 = ay - d[1] * stride_f;
+                let x2 = ax + d[2] * stride_f;
+                let y2 = ay + d[3] * stride_f;
+                let (cx, cy, w, h) = meta.inverse_transform((x1 + x2) / 2.0, (y1 + y2) / 2.0, x2 - x1, y2 - y1);
+                detections.push(Detection::new(BBox::from_center(cx, cy, w, h), 0, score));
+
+                let face_landmarks = match &level_kps {
+                    Some(level_kps) => level_kps[row * 10..row * 10 + 10]
+                        .chunks_exact(2)
+                        .map(|p| {
+                            let (x, y, _, _) = meta.inverse_transform(ax + p[0] * stride_f, ay + p[1] * stride_f, 0.0, 0.0);
+                            Keypoint::new(x, y, 1.0)
+                        })
+                        .collect(),
+                    None => Vec::new(),
+                };
+                landmarks.push(face_landmarks);
+            }
+        }
+
+        Ok((detections, landmarks))
+    }
+
+    /// Decodes the YOLOv8-face `[1, 5 + num_landmarks * 3, N]` output.
+    fn parse_yolo_face_static(
+        output: &ndarray::ArrayViewD<f32>,
+        conf_threshold: f32,
+        meta: &PreprocessMeta,
+    ) -> Result<(Vec<Detection>, Vec<Vec<Keypoint>>), OrtModelError> {
+        let shape = output.shape();
+
+        if shape.len() != 3 || shape[0] != 1 || shape[1] < 5 || !(shape[1] - 5).is_multiple_of(3) {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected shape [1, 5 + num_landmarks * 3, N], got {:?}",
+                shape
+            )));
+        }
+
+        let num_landmarks = (shape[1] - 5) / 3;
+        let num_predictions = shape[2];
+
+        let mut detections = Vec::new();
+        let mut landmarks = Vec::new();
+
+        for i in 0..num_predictions {
+            let score = output[[0, 4, i]];
+            if score < conf_threshold {
+                continue;
+            }
+
+            let (cx, cy, w, h) = meta.inverse_transform(
+                output[[0, 0, i]],
+                output[[0, 1, i]],
+                output[[0, 2, i]],
+                output[[0, 3, i]],
+            );
+            detections.push(Detection::new(BBox::from_center(cx, cy, w, h), 0, score));
+            landmarks.push(
+                (0..num_landmarks)
+                    .map(|k| {
+                        let offset = 5 + k * 3;
+                        let (x, y, _, _) = meta.inverse_transform(output[[0, offset, i]], output[[0, offset + 1, i]], 0.0, 0.0);
+                        Keypoint::new(x, y, output[[0, offset + 2, i]])
+                    })
+                    .collect(),
+            );
+        }
+
+        Ok((detections, landmarks))
+    }
+}
+
+impl crate::ObjectDetector for ModelFaceOrt {
+    type Input = ImageBuffer;
+    type Error = OrtModelError;
+
+    fn detect(
+        &mut self,
+        input: &Self::Input,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs, Self::Error> {
+        self.forward(input, conf_threshold, nms_threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{Array2, Array3};
+
+    #[test]
+    fn test_parse_scrfd() {
+        // 32x32 input, strides 8/16/32 with 2 anchors: 32, 8 and 2 rows
+        let mut outputs = Vec::new();
+        for rows in [32, 8, 2] {
+            outputs.push(Array2::<f32>::zeros((rows, 1)).into_dyn());
+        }
+        for rows in [32, 8, 2] {
+            outputs.push(Array2::<f32>::zeros((rows, 4)).into_dyn());
+        }
+        for rows in [32, 8, 2] {
+            outputs.push(Array2::<f32>::zeros((rows, 10)).into_dyn());
+        }
+
+        // Stride 16, cell (1, 0), second anchor: row 3, anchor center (16, 0)
+        outputs[1][[3, 0]] = 0.9;
+        outputs[4][[3, 0]] = 0.5;
+        outputs[4][[3, 2]] = 0.5;
+        outputs[4][[3, 3]] = 1.0;
+        outputs[7][[3, 0]] = -0.25;
+        outputs[7][[3, 1]] = 0.5;
+
+        // Below threshold
+        outputs[0][[0, 0]] = 0.1;
+
+        // Output order is shuffled to check grouping by shape
+        outputs.swap(0, 8);
+        let views: Vec<_> = outputs.iter().map(|o| o.view()).collect();
+        let (detections, landmarks) =
+            ModelFaceOrt::parse_scrfd_static(&views, (32, 32), 0.5, &PreprocessMeta::identity(32, 32)).unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].bbox, BBox::new(8, 0, 16, 16));
+        assert_eq!(landmarks[0].len(), 5);
+        assert_eq!(landmarks[0][0], Keypoint::new(12.0, 8.0, 1.0));
+        assert_eq!(landmarks[0][1], Keypoint::new(16.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_parse_scrfd_invalid_outputs() {
+        let outputs = [Array2::<f32>::zeros((32, 1)).into_dyn()];
+        let views: Vec<_> = outputs.iter().map(|o| o.view()).collect();
+        assert!(ModelFaceOrt::parse_scrfd_static(&views, (32, 32), 0.5, &PreprocessMeta::identity(32, 32)).is_err());
+    }
+
+    #[test]
+    fn test_parse_yolo_face() {
+        let mut output = Array3::<f32>::zeros((1, 20, 2));
+        output[[0, 0, 1]] = 16.0;
+        output[[0, 1, 1]] = 16.0;
+        output[[0, 2, 1]] = 8.0;
+        output[[0, 3, 1]] = 10.0;
+        output[[0, 4, 1]] = 0.8;
+        // Left eye
+        output[[0, 5, 1]] = 14.0;
+        output[[0, 6, 1]] = 13.0;
+        output[[0, 7, 1]] = 0.9;
+
+        let (detections, landmarks) =
+            ModelFaceOrt::parse_yolo_face_static(&output.view().into_dyn(), 0.5, &PreprocessMeta::identity(32, 32)).unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].bbox, BBox::new(12, 11, 8, 10));
+        assert_eq!(landmarks[0].len(), 5);
+        assert_eq!(landmarks[0][0], Keypoint::new(14.0, 13.0, 0.9));
+    }
+}
@@ -0,0 +1,358 @@
+//! YOLO-NAS models (two-output exports) using ONNX Runtime.
+
+use ort::session::Session;
+use ort::inputs;
+
+use crate::bbox::BBox;
+use crate::model_trait::DetectionVecs;
+use crate::image_buffer::ImageBuffer;
+use crate::postprocess::{Detection, NmsStrategy, filter_by_class, detections_to_vecs, argmax};
+use crate::preprocessing::{preprocess, PreprocessMeta};
+
+use super::OrtModelError;
+use super::tensor::{extract_output, input_element_type, input_value};
+use super::tensor_names::TensorNames;
+
+/// YOLO-NAS model using ONNX Runtime.
+///
+/// YOLO-NAS exports (without the built-in NMS) produce two outputs: boxes as
+/// `[1, N, 4]` rows of `(x1, y1, x2, y2)` in input space, and class scores as
+/// `[1, N, num_classes]`.
+///
+/// Output tensor names depend on the exporter, so the boxes output is the one
+/// whose last dimension is 4 and the other one holds the scores. When both have a
+/// last dimension of 4 (4-class models), the first one is taken as the boxes: pass
+/// the names in (boxes, scores) order with [`Self::new_from_file_with_names`].
+pub struct ModelYOLONASOrt {
+    session: Session,
+    tensor_names: TensorNames,
+    input_width: u32,
+    input_height: u32,
+    class_filters: Vec<usize>,
+    nms_strategy: NmsStrategy,
+    use_letterbox: bool,
+}
+
+impl ModelYOLONASOrt {
+    /// Creates a new model from an ONNX file.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let model = ModelYOLONASOrt::new_from_file(
+    ///     "yolo_nas_s.onnx",
+    ///     (640, 640),
+    ///     vec![],  // detect all classes
+    /// )?;
+    /// ```
+    pub fn new_from_file(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with CUDA acceleration.
+    ///
+    /// Requires the `ort-cuda-backend` feature.
+    #[cfg(feature = "ort-cuda-backend")]
+    pub fn new_from_file_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_cuda(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with TensorRT acceleration.
+    ///
+    /// Requires the `ort-tensorrt-backend` feature.
+    #[cfg(feature = "ort-tensorrt-backend")]
+    pub fn new_from_file_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_tensorrt(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model with custom session options.
+    ///
+    /// # Arguments
+    /// * `session` - Pre-configured ORT session
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect
+    pub fn from_session(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Self {
+        // super-gradients exports name the input "input.1" or "input"
+        let tensor_names = TensorNames::from_session(&session, 2);
+        Self {
+            session,
+            tensor_names,
+            input_width: input_size.0,
+            input_height: input_size.1,
+            class_filters,
+            nms_strategy: NmsStrategy::default(),
+            #[cfg(feature = "letterbox")]
+            use_letterbox: true,
+            #[cfg(not(feature = "letterbox"))]
+            use_letterbox: false,
+        }
+    }
+
+    /// Creates a new model from an ONNX file with custom tensor names.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn new_from_file_with_names(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Self::from_session_with_names(session, input_size, class_filters, tensor_names)
+    }
+
+    /// Creates a new model with custom session options and tensor names.
+    ///
+    /// The model reads two outputs: boxes, then scores.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn from_session_with_names(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        tensor_names.validate(&session, 2)?;
+        let mut model = Self::from_session(session, input_size, class_filters);
+        model.tensor_names = tensor_names;
+        Ok(model)
+    }
+
+    /// Enables or disables letterbox preprocessing.
+    ///
+    /// Letterbox preserves aspect ratio by padding with gray.
+    /// Default is `false` (stretch mode).
+    pub fn set_letterbox(&mut self, enabled: bool) {
+        self.use_letterbox = enabled;
+    }
+
+    /// Sets the NMS algorithm applied to the detections.
+    ///
+    /// Default is [`NmsStrategy::Greedy`].
+    pub fn set_nms_strategy(&mut self, strategy: NmsStrategy) {
+        self.nms_strategy = strategy;
+    }
+
+    /// Returns the input size (width, height).
+    pub fn input_size(&self) -> (u32, u32) {
+        (self.input_width, self.input_height)
+    }
+
+    /// Returns the input and output tensor names.
+    pub fn tensor_names(&self) -> &TensorNames {
+        &self.tensor_names
+    }
+
+    /// Runs inference on an image.
+    ///
+    /// # Arguments
+    /// * `image` - Input image buffer
+    /// * `conf_threshold` - Confidence threshold (0.0 - 1.0)
+    /// * `nms_threshold` - NMS IoU threshold (0.0 - 1.0)
+    ///
+    /// # Returns
+    /// Tuple of (bounding boxes, class IDs, confidence scores)
+    pub fn forward(
+        &mut self,
+        image: &ImageBuffer,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs, OrtModelError> {
+        let (tensor, meta) = preprocess(
+            image,
+            self.input_width,
+            self.input_height,
+            self.use_letterbox,
+        );
+
+        let input_type = input_element_type(&self.session, self.tensor_names.input());
+        let outputs = self.session.run(
+            inputs![self.tensor_names.input() => input_value(&tensor, input_type)?]
+        )?;
+
+        let arrays = [
+            extract_output(&outputs, self.tensor_names.output(0))?,
+            extract_output(&outputs, self.tensor_names.output(1))?,
+        ];
+
+        let (boxes_idx, scores_idx) = Self::select_outputs(&arrays)?;
+
+        let detections = Self::parse_output_arrays_static(
+            &arrays[boxes_idx].view(),
+            &arrays[scores_idx].view(),
+            conf_threshold,
+            &meta,
+        )?;
+        let filtered = filter_by_class(&detections, &self.class_filters);
+        let final_detections = self.nms_strategy.apply(&filtered, nms_threshold, conf_threshold);
+
+        Ok(detections_to_vecs(final_detections))
+    }
+
+    /// Finds the indices of the boxes and scores outputs.
+    ///
+    /// The boxes output is the first one whose last dimension is 4.
+    fn select_outputs(arrays: &[ndarray::ArrayD<f32>; 2]) -> Result<(usize, usize), OrtModelError> {
+        let boxes_idx = arrays
+            .iter()
+            .position(|a| a.shape().last() == Some(&4))
+            .ok_or_else(|| OrtModelError::InvalidOutputShape("No [1, N, 4] boxes output found".to_string()))?;
+
+        Ok((boxes_idx, 1 - boxes_idx))
+    }
+
+    /// Parses the `[1, N, 4]` boxes and `[1, N, num_classes]` scores outputs into detections.
+    fn parse_output_arrays_static(
+        boxes: &ndarray::ArrayViewD<f32>,
+        scores: &ndarray::ArrayViewD<f32>,
+        conf_threshold: f32,
+        meta: &PreprocessMeta,
+    ) -> Result<Vec<Detection>, OrtModelError> {
+        let boxes_shape = boxes.shape();
+        let scores_shape = scores.shape();
+
+        if boxes_shape.len() != 3 || boxes_shape[0] != 1 || boxes_shape[2] != 4 {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected boxes shape [1, N, 4], got {:?}",
+                boxes_shape
+            )));
+        }
+
+        if scores_shape.len() != 3 || scores_shape[0] != 1 || scores_shape[1] != boxes_shape[1] {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected scores shape [1, {}, num_classes], got {:?}",
+                boxes_shape[1], scores_shape
+            )));
+        }
+
+        let num_predictions = boxes_shape[1];
+        let num_classes = scores_shape[2];
+
+        let mut detections = Vec::new();
+
+        for i in 0..num_predictions {
+            let class_scores: Vec<f32> = (0..num_classes)
+                .map(|j| scores[[0, i, j]])
+                .collect();
+
+            if let Some((class_idx, score)) = argmax(&class_scores) {
+                if score < conf_threshold {
+                    continue;
+                }
+
+                let x1 = boxes[[0, i, 0]];
+                let y1 = boxes[[0, i, 1]];
+                let x2 = boxes[[0, i, 2]];
+                let y2 = boxes[[0, i, 3]];
+
+                let (cx, cy, w, h) = meta.inverse_transform(
+                    (x1 + x2) / 2.0,
+                    (y1 + y2) / 2.0,
+                    x2 - x1,
+                    y2 - y1,
+                );
+
+                detections.push(Detection::new(BBox::from_center(cx, cy, w, h), class_idx, score));
+            }
+        }
+
+        Ok(detections)
+    }
+}
+
+impl crate::ObjectDetector for ModelYOLONASOrt {
+    type Input = ImageBuffer;
+    type Error = OrtModelError;
+
+    fn detect(
+        &mut self,
+        input: &Self::Input,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs, Self::Error> {
+        self.forward(input, conf_threshold, nms_threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{Array3, ArrayD, IxDyn};
+
+    #[test]
+    fn test_parse_output_arrays() {
+        // 2 predictions, 3 classes
+        let mut boxes = Array3::<f32>::zeros((1, 2, 4));
+        boxes[[0, 0, 0]] = 10.0;
+        boxes[[0, 0, 1]] = 20.0;
+        boxes[[0, 0, 2]] = 110.0;
+        boxes[[0, 0, 3]] = 70.0;
+        let mut scores = Array3::<f32>::zeros((1, 2, 3));
+        scores[[0, 0, 1]] = 0.9;
+        scores[[0, 1, 2]] = 0.1;
+
+        let detections = ModelYOLONASOrt::parse_output_arrays_static(
+            &boxes.view().into_dyn(),
+            &scores.view().into_dyn(),
+            0.25,
+            &PreprocessMeta::identity(640, 640),
+        )
+        .unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].class_id, 1);
+        assert_eq!(detections[0].bbox, BBox::new(10, 20, 100, 50));
+    }
+
+    #[test]
+    fn test_parse_output_arrays_mismatch() {
+        let boxes = Array3::<f32>::zeros((1, 2, 4));
+        let scores = Array3::<f32>::zeros((1, 3, 80));
+        let result = ModelYOLONASOrt::parse_output_arrays_static(
+            &boxes.view().into_dyn(),
+            &scores.view().into_dyn(),
+            0.25,
+            &PreprocessMeta::identity(640, 640),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_select_outputs() {
+        let scores = ArrayD::<f32>::zeros(IxDyn(&[1, 8400, 80]));
+        let boxes = ArrayD::<f32>::zeros(IxDyn(&[1, 8400, 4]));
+        assert_eq!(ModelYOLONASOrt::select_outputs(&[scores.clone(), boxes.clone()]).unwrap(), (1, 0));
+        assert_eq!(ModelYOLONASOrt::select_outputs(&[boxes.clone(), scores.clone()]).unwrap(), (0, 1));
+
+        // 4-class models: the first output is taken as the boxes
+        let scores_4 = ArrayD::<f32>::zeros(IxDyn(&[1, 8400, 4]));
+        assert_eq!(ModelYOLONASOrt::select_outputs(&[boxes, scores_4]).unwrap(), (0, 1));
+
+        assert!(ModelYOLONASOrt::select_outputs(&[scores.clone(), scores]).is_err());
+    }
+}
@@ -0,0 +1,320 @@
+//! Ultralytics YOLO oriented bounding box models (v8-obb, v11-obb) using ONNX Runtime.
+
+use ort::session::Session;
+use ort::inputs;
+
+use crate::model_trait::DetectionVecs;
+use crate::image_buffer::ImageBuffer;
+use crate::postprocess::{RotatedDetection, NmsStrategy, argmax};
+use crate::rotated_bbox::RotatedBBox;
+use crate::preprocessing::{preprocess, PreprocessMeta};
+
+use super::OrtModelError;
+use super::tensor_names::TensorNames;
+use super::tensor::{extract_output, input_element_type, input_value};
+
+/// Ultralytics YOLO oriented bounding box model using ONNX Runtime.
+///
+/// `-obb` exports produce a single `[1, 4 + num_classes + 1, N]` output: the usual
+/// detection rows followed by the rotation angle in radians (DOTA models: `[1, 20, N]`).
+pub struct ModelUltralyticsObbOrt {
+    session: Session,
+    tensor_names: TensorNames,
+    input_width: u32,
+    input_height: u32,
+    class_filters: Vec<usize>,
+    nms_strategy: NmsStrategy,
+    use_letterbox: bool,
+}
+
+impl ModelUltralyticsObbOrt {
+    /// Creates a new model from an ONNX file.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let model = ModelUltralyticsObbOrt::new_from_file(
+    ///     "yolov8n-obb.onnx",
+    ///     (1024, 1024),
+    ///     vec![],  // detect all classes
+    /// )?;
+    /// ```
+    pub fn new_from_file(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with CUDA acceleration.
+    ///
+    /// Requires the `ort-cuda-backend` feature.
+    #[cfg(feature = "ort-cuda-backend")]
+    pub fn new_from_file_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_cuda(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with TensorRT acceleration.
+    ///
+    /// Requires the `ort-tensorrt-backend` feature.
+    #[cfg(feature = "ort-tensorrt-backend")]
+    pub fn new_from_file_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_tensorrt(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model with custom session options.
+    ///
+    /// # Arguments
+    /// * `session` - Pre-configured ORT session
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect
+    pub fn from_session(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Self {
+        let tensor_names = TensorNames::from_session(&session, 1);
+        Self {
+            session,
+            tensor_names,
+            input_width: input_size.0,
+            input_height: input_size.1,
+            class_filters,
+            nms_strategy: NmsStrategy::default(),
+            #[cfg(feature = "letterbox")]
+            use_letterbox: true,
+            #[cfg(not(feature = "letterbox"))]
+            use_letterbox: false,
+        }
+    }
+
+    /// Creates a new model from an ONNX file with custom tensor names.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn new_from_file_with_names(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Self::from_session_with_names(session, input_size, class_filters, tensor_names)
+    }
+
+    /// Creates a new model with custom session options and tensor names.
+    ///
+    /// The model reads one output.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn from_session_with_names(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        tensor_names.validate(&session, 1)?;
+        let mut model = Self::from_session(session, input_size, class_filters);
+        model.tensor_names = tensor_names;
+        Ok(model)
+    }
+
+    /// Enables or disables letterbox preprocessing.
+    ///
+    /// Letterbox preserves aspect ratio by padding with gray.
+    /// Default is `false` (stretch mode).
+    pub fn set_letterbox(&mut self, enabled: bool) {
+        self.use_letterbox = enabled;
+    }
+
+    /// Sets the NMS algorithm applied to the detections.
+    ///
+    /// Default is [`NmsStrategy::Greedy`].
+    pub fn set_nms_strategy(&mut self, strategy: NmsStrategy) {
+        self.nms_strategy = strategy;
+    }
+
+    /// Returns the input size (width, height).
+    pub fn input_size(&self) -> (u32, u32) {
+        (self.input_width, self.input_height)
+    }
+
+    /// Returns the input and output tensor names.
+    pub fn tensor_names(&self) -> &TensorNames {
+        &self.tensor_names
+    }
+
+    /// Runs inference on an image.
+    ///
+    /// # Arguments
+    /// * `image` - Input image buffer
+    /// * `conf_threshold` - Confidence threshold (0.0 - 1.0)
+    /// * `nms_threshold` - Rotated NMS IoU threshold (0.0 - 1.0)
+    ///
+    /// # Returns
+    /// Tuple of (rotated boxes, class IDs, confidence scores)
+    pub fn forward(
+        &mut self,
+        image: &ImageBuffer,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs<RotatedBBox>, OrtModelError> {
+        let (tensor, meta) = preprocess(
+            image,
+            self.input_width,
+            self.input_height,
+            self.use_letterbox,
+        );
+
+        let input_type = input_element_type(&self.session, self.tensor_names.input());
+        let outputs = self.session.run(
+            inputs![self.tensor_names.input() => input_value(&tensor, input_type)?]
+        )?;
+
+        let output = extract_output(&outputs, self.tensor_names.output(0))?;
+
+        let detections = Self::parse_output_array_static(&output.view(), conf_threshold, &meta)?;
+        let filtered: Vec<RotatedDetection> = if self.class_filters.is_empty() {
+            detections
+        } else {
+            detections
+                .into_iter()
+                .filter(|d| self.class_filters.contains(&d.class_id))
+                .collect()
+        };
+        let kept = self.nms_strategy.apply_rotated(&filtered, nms_threshold, conf_threshold);
+
+        let mut rboxes = Vec::with_capacity(kept.len());
+        let mut class_ids = Vec::with_capacity(kept.len());
+        let mut confidences = Vec::with_capacity(kept.len());
+        for detection in kept {
+            rboxes.push(detection.rbox);
+            class_ids.push(detection.class_id);
+            confidences.push(detection.confidence);
+        }
+
+        Ok((rboxes, class_ids, confidences))
+    }
+
+    /// Parses the `[1, 4 + num_classes + 1, N]` output into oriented detections.
+    fn parse_output_array_static(
+        output: &ndarray::ArrayViewD<f32>,
+        conf_threshold: f32,
+        meta: &PreprocessMeta,
+    ) -> Result<Vec<RotatedDetection>, OrtModelError> {
+        let shape = output.shape();
+
+        if shape.len() != 3 || shape[0] != 1 || shape[1] < 6 {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected shape [1, 4 + num_classes + 1, N], got {:?}",
+                shape
+            )));
+        }
+
+        let num_features = shape[1];
+        let num_predictions = shape[2];
+        let angle_idx = num_features - 1;
+
+        let mut detections = Vec::new();
+
+        for i in 0..num_predictions {
+            let class_scores: Vec<f32> = (4..angle_idx)
+                .map(|j| output[[0, j, i]])
+                .collect();
+
+            if let Some((class_idx, max_score)) = argmax(&class_scores)
+                && max_score >= conf_threshold
+            {
+                let rbox = meta.inverse_transform_rotated(&RotatedBBox::new(
+                    output[[0, 0, i]],
+                    output[[0, 1, i]],
+                    output[[0, 2, i]],
+                    output[[0, 3, i]],
+                    output[[0, angle_idx, i]],
+                ));
+                detections.push(RotatedDetection::new(rbox, class_idx, max_score));
+            }
+        }
+
+        Ok(detections)
+    }
+}
+
+impl crate::ObjectDetector for ModelUltralyticsObbOrt {
+    type Input = ImageBuffer;
+    type Error = OrtModelError;
+
+    /// Returns the axis-aligned bounds of the rotated boxes.
+    fn detect(
+        &mut self,
+        input: &Self::Input,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs, Self::Error> {
+        let (rboxes, class_ids, confidences) = self.forward(input, conf_threshold, nms_threshold)?;
+        let bboxes = rboxes.iter().map(RotatedBBox::bounding_box).collect();
+        Ok((bboxes, class_ids, confidences))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::Array3;
+
+    #[test]
+    fn test_parse_output_obb() {
+        let meta = PreprocessMeta::letterbox((2048, 1536), (1024, 1024));
+
+        // 2 predictions, 15 classes (DOTA), angle last
+        let mut output = Array3::<f32>::zeros((1, 20, 2));
+        output[[0, 0, 0]] = 512.0;
+        output[[0, 1, 0]] = 528.0;
+        output[[0, 2, 0]] = 100.0;
+        output[[0, 3, 0]] = 40.0;
+        output[[0, 13, 0]] = 0.8;
+        output[[0, 19, 0]] = 0.5;
+        // Below threshold
+        output[[0, 4, 1]] = 0.1;
+
+        let detections = ModelUltralyticsObbOrt::parse_output_array_static(
+            &output.view().into_dyn(),
+            0.25,
+            &meta,
+        )
+        .unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].class_id, 9);
+        assert_eq!(detections[0].rbox, RotatedBBox::new(1024.0, 800.0, 200.0, 80.0, 0.5));
+    }
+
+    #[test]
+    fn test_parse_output_invalid_shape() {
+        let meta = PreprocessMeta::letterbox((640, 640), (640, 640));
+        let output = Array3::<f32>::zeros((1, 5, 10));
+        let result = ModelUltralyticsObbOrt::parse_output_array_static(
+            &output.view().into_dyn(),
+            0.25,
+            &meta,
+        );
+        assert!(result.is_err());
+    }
+}
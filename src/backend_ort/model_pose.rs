@@ -0,0 +1,407 @@
+//! Ultralytics YOLO pose estimation models (v8-pose, v11-pose) using ONNX Runtime.
+
+use ort::session::Session;
+use ort::inputs;
+
+use crate::bbox::BBox;
+use crate::model_trait::DetectionVecs;
+use crate::image_buffer::ImageBuffer;
+use crate::keypoint::Keypoint;
+use crate::postprocess::{Detection, NmsStrategy, argmax};
+use crate::preprocessing::{preprocess, PreprocessMeta};
+
+use super::OrtModelError;
+use super::tensor_names::TensorNames;
+use super::tensor::{extract_output, input_element_type, input_value};
+
+/// Ultralytics YOLO pose estimation model using ONNX Runtime.
+///
+/// `-pose` exports produce a single `[1, 4 + num_classes + num_keypoints * 3, N]` output:
+/// the usual detection rows followed by (x, y, visibility) triplets in input coordinates.
+/// COCO pose models have 1 class and 17 keypoints, i.e. `[1, 56, N]`.
+///
+/// The number of keypoints is read from the output shape, given the number of classes
+/// (see [`Self::set_num_classes`]).
+pub struct ModelUltralyticsPoseOrt {
+    session: Session,
+    tensor_names: TensorNames,
+    input_width: u32,
+    input_height: u32,
+    class_filters: Vec<usize>,
+    nms_strategy: NmsStrategy,
+    num_classes: usize,
+    use_letterbox: bool,
+}
+
+impl ModelUltralyticsPoseOrt {
+    /// Creates a new model from an ONNX file.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let model = ModelUltralyticsPoseOrt::new_from_file(
+    ///     "yolov8n-pose.onnx",
+    ///     (640, 640),
+    ///     vec![],  // detect all classes
+    /// )?;
+    /// ```
+    pub fn new_from_file(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with CUDA acceleration.
+    ///
+    /// Requires the `ort-cuda-backend` feature.
+    #[cfg(feature = "ort-cuda-backend")]
+    pub fn new_from_file_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_cuda(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with TensorRT acceleration.
+    ///
+    /// Requires the `ort-tensorrt-backend` feature.
+    #[cfg(feature = "ort-tensorrt-backend")]
+    pub fn new_from_file_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_tensorrt(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model with custom session options.
+    ///
+    /// # Arguments
+    /// * `session` - Pre-configured ORT session
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect
+    pub fn from_session(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Self {
+        let tensor_names = TensorNames::from_session(&session, 1);
+        Self {
+            session,
+            tensor_names,
+            input_width: input_size.0,
+            input_height: input_size.1,
+            class_filters,
+            nms_strategy: NmsStrategy::default(),
+            num_classes: 1,
+            #[cfg(feature = "letterbox")]
+            use_letterbox: true,
+            #[cfg(not(feature = "letterbox"))]
+            use_letterbox: false,
+        }
+    }
+
+    /// Creates a new model from an ONNX file with custom tensor names.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn new_from_file_with_names(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Self::from_session_with_names(session, input_size, class_filters, tensor_names)
+    }
+
+    /// Creates a new model with custom session options and tensor names.
+    ///
+    /// The model reads one output.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn from_session_with_names(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        tensor_names.validate(&session, 1)?;
+        let mut model = Self::from_session(session, input_size, class_filters);
+        model.tensor_names = tensor_names;
+        Ok(model)
+    }
+
+    /// Enables or disables letterbox preprocessing.
+    ///
+    /// Letterbox preserves aspect ratio by padding with gray.
+    /// Default is `false` (stretch mode).
+    pub fn set_letterbox(&mut self, enabled: bool) {
+        self.use_letterbox = enabled;
+    }
+
+    /// Sets the NMS algorithm applied to the detections.
+    ///
+    /// Default is [`NmsStrategy::Greedy`].
+    pub fn set_nms_strategy(&mut self, strategy: NmsStrategy) {
+        self.nms_strategy = strategy;
+    }
+
+    /// Sets the number of classes of the model.
+    ///
+    /// Default is 1 (person), which is the case of all official pose models.
+    pub fn set_num_classes(&mut self, num_classes: usize) {
+        self.num_classes = num_classes;
+    }
+
+    /// Returns the input size (width, height).
+    pub fn input_size(&self) -> (u32, u32) {
+        (self.input_width, self.input_height)
+    }
+
+    /// Returns the input and output tensor names.
+    pub fn tensor_names(&self) -> &TensorNames {
+        &self.tensor_names
+    }
+
+    /// Runs inference on an image, returning bounding boxes only.
+    ///
+    /// Use [`Self::forward_keypoints`] to get keypoints as well.
+    ///
+    /// # Arguments
+    /// * `image` - Input image buffer
+    /// * `conf_threshold` - Confidence threshold (0.0 - 1.0)
+    /// * `nms_threshold` - NMS IoU threshold (0.0 - 1.0)
+    ///
+    /// # Returns
+    /// Tuple of (bounding boxes, class IDs, confidence scores)
+    pub fn forward(
+        &mut self,
+        image: &ImageBuffer,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs, OrtModelError> {
+        let (bboxes, class_ids, confidences, _) = self.forward_keypoints(image, conf_threshold, nms_threshold)?;
+        Ok((bboxes, class_ids, confidences))
+    }
+
+    /// Runs inference on an image, returning bounding boxes and their keypoints.
+    ///
+    /// # Arguments
+    /// * `image` - Input image buffer
+    /// * `conf_threshold` - Confidence threshold (0.0 - 1.0)
+    /// * `nms_threshold` - NMS IoU threshold (0.0 - 1.0)
+    ///
+    /// # Returns
+    /// Tuple of (bounding boxes, class IDs, confidence scores, keypoints per box)
+    #[allow(clippy::type_complexity)]
+    pub fn forward_keypoints(
+        &mut self,
+        image: &ImageBuffer,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<(Vec<BBox>, Vec<usize>, Vec<f32>, Vec<Vec<Keypoint>>), OrtModelError> {
+        let (tensor, meta) = preprocess(
+            image,
+            self.input_width,
+            self.input_height,
+            self.use_letterbox,
+        );
+
+        let input_type = input_element_type(&self.session, self.tensor_names.input());
+        let outputs = self.session.run(
+            inputs![self.tensor_names.input() => input_value(&tensor, input_type)?]
+        )?;
+
+        let output = extract_output(&outputs, self.tensor_names.output(0))?;
+
+        let (detections, keypoints) =
+            Self::parse_output_array_static(&output.view(), self.num_classes, conf_threshold, &meta)?;
+
+        // Class filtering and NMS work on indices to keep keypoints aligned
+        let candidates: Vec<usize> = (0..detections.len())
+            .filter(|&i| self.class_filters.is_empty() || self.class_filters.contains(&detections[i].class_id))
+            .collect();
+        let filtered: Vec<Detection> = candidates.iter().map(|&i| detections[i].clone()).collect();
+        let kept = self.nms_strategy.apply_indices(&filtered, nms_threshold, conf_threshold);
+
+        let mut bboxes = Vec::with_capacity(kept.len());
+        let mut class_ids = Vec::with_capacity(kept.len());
+        let mut confidences = Vec::with_capacity(kept.len());
+        let mut kept_keypoints = Vec::with_capacity(kept.len());
+
+        for (idx, confidence) in kept {
+            let original_idx = candidates[idx];
+            let detection = &detections[original_idx];
+            bboxes.push(detection.bbox);
+            class_ids.push(detection.class_id);
+            confidences.push(confidence);
+            kept_keypoints.push(keypoints[original_idx].clone());
+        }
+
+        Ok((bboxes, class_ids, confidences, kept_keypoints))
+    }
+
+    /// Parses the `[1, 4 + num_classes + num_keypoints * 3, N]` output into detections
+    /// and their keypoints.
+    fn parse_output_array_static(
+        output: &ndarray::ArrayViewD<f32>,
+        num_classes: usize,
+        conf_threshold: f32,
+        meta: &PreprocessMeta,
+    ) -> Result<(Vec<Detection>, Vec<Vec<Keypoint>>), OrtModelError> {
+        let shape = output.shape();
+
+        if shape.len() != 3
+            || shape[0] != 1
+            || num_classes == 0
+            || shape[1] < 4 + num_classes
+            || !(shape[1] - 4 - num_classes).is_multiple_of(3)
+        {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected shape [1, 4 + {} + num_keypoints * 3, N], got {:?}",
+                num_classes, shape
+            )));
+        }
+
+        let num_features = shape[1];
+        let num_predictions = shape[2];
+        let keypoints_start = 4 + num_classes;
+        let num_keypoints = (num_features - keypoints_start) / 3;
+
+        let mut detections = Vec::new();
+        let mut keypoints = Vec::new();
+
+        for i in 0..num_predictions {
+            let class_scores: Vec<f32> = (4..keypoints_start)
+                .map(|j| output[[0, j, i]])
+                .collect();
+
+            if let Some((class_idx, max_score)) = argmax(&class_scores)
+                && max_score >= conf_threshold
+            {
+                let (cx, cy, w, h) = meta.inverse_transform(
+                    output[[0, 0, i]],
+                    output[[0, 1, i]],
+                    output[[0, 2, i]],
+                    output[[0, 3, i]],
+                );
+
+                detections.push(Detection::new(BBox::from_center(cx, cy, w, h), class_idx, max_score));
+                keypoints.push(
+                    (0..num_keypoints)
+                        .map(|k| {
+                            let offset = keypoints_start + k * 3;
+                            let (x, y, _, _) = meta.inverse_transform(
+                                output[[0, offset, i]],
+                                output[[0, offset + 1, i]],
+                                0.0,
+                                0.0,
+                            );
+                            Keypoint::new(x, y, output[[0, offset + 2, i]])
+                        })
+                        .collect(),
+                );
+            }
+        }
+
+        Ok((detections, keypoints))
+    }
+}
+
+impl crate::ObjectDetector for ModelUltralyticsPoseOrt {
+    type Input = ImageBuffer;
+    type Error = OrtModelError;
+
+    fn detect(
+        &mut self,
+        input: &Self::Input,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs, Self::Error> {
+        self.forward(input, conf_threshold, nms_threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::Array3;
+
+    #[test]
+    fn test_parse_output_coco_pose() {
+        // 2 predictions, 1 class, 17 keypoints
+        let mut output = Array3::<f32>::zeros((1, 56, 2));
+        output[[0, 0, 0]] = 320.0;
+        output[[0, 1, 0]] = 320.0;
+        output[[0, 2, 0]] = 100.0;
+        output[[0, 3, 0]] = 200.0;
+        output[[0, 4, 0]] = 0.9;
+        // Nose
+        output[[0, 5, 0]] = 310.0;
+        output[[0, 6, 0]] = 250.0;
+        output[[0, 7, 0]] = 0.8;
+        // Right ankle
+        output[[0, 53, 0]] = 330.0;
+        output[[0, 54, 0]] = 410.0;
+        output[[0, 55, 0]] = 0.3;
+        // Below threshold
+        output[[0, 4, 1]] = 0.1;
+
+        let (detections, keypoints) = ModelUltralyticsPoseOrt::parse_output_array_static(
+            &output.view().into_dyn(),
+            1,
+            0.25,
+            &PreprocessMeta::letterbox((640, 480), (640, 640)),
+        )
+        .unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].bbox, BBox::new(270, 140, 100, 200));
+        assert_eq!(keypoints[0].len(), 17);
+        assert_eq!(keypoints[0][0], Keypoint::new(310.0, 170.0, 0.8));
+        assert_eq!(keypoints[0][16], Keypoint::new(330.0, 330.0, 0.3));
+        assert!(keypoints[0][0].is_visible(0.5));
+        assert!(!keypoints[0][16].is_visible(0.5));
+    }
+
+    #[test]
+    fn test_parse_output_custom_keypoints() {
+        // 2 classes, 4 keypoints
+        let mut output = Array3::<f32>::zeros((1, 18, 1));
+        output[[0, 5, 0]] = 0.7;
+        let (detections, keypoints) = ModelUltralyticsPoseOrt::parse_output_array_static(
+            &output.view().into_dyn(),
+            2,
+            0.25,
+            &PreprocessMeta::letterbox((640, 480), (640, 640)),
+        )
+        .unwrap();
+
+        assert_eq!(detections[0].class_id, 1);
+        assert_eq!(keypoints[0].len(), 4);
+    }
+
+    #[test]
+    fn test_parse_output_invalid_shape() {
+        let output = Array3::<f32>::zeros((1, 57, 10));
+        let result = ModelUltralyticsPoseOrt::parse_output_array_static(
+            &output.view().into_dyn(),
+            1,
+            0.25,
+            &PreprocessMeta::letterbox((640, 480), (640, 640)),
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,597 @@
+//! RT-DETR (real-time detection transformer) models using ONNX Runtime.
+
+use std::borrow::Cow;
+
+use ndarray::{arr2, concatenate, ArrayD, ArrayViewD, Axis};
+use ort::session::Session;
+use ort::inputs;
+
+use crate::bbox::BBox;
+use crate::model_trait::DetectionVecs;
+use crate::image_buffer::ImageBuffer;
+use crate::postprocess::{Detection, filter_by_class, detections_to_vecs, argmax, sigmoid, top_k};
+use crate::preprocessing::{preprocess, PreprocessMeta};
+
+use super::OrtModelError;
+use super::tensor::{extract_output, input_element_type, input_value};
+use super::tensor_names::TensorNames;
+
+/// Image size and scale inputs of PaddlePaddle exports.
+const PADDLE_IM_SHAPE: &str = "im_shape";
+const PADDLE_SCALE_FACTOR: &str = "scale_factor";
+
+/// RT-DETR model using ONNX Runtime.
+///
+/// RT-DETR outputs one prediction per object query as `[1, num_queries, 4 + num_classes]`
+/// rows of `(cx, cy, w, h, class scores...)`, where the box is normalized to the
+/// network input (0.0 - 1.0). Queries do not produce duplicates, so the best
+/// `max_detections` predictions are kept (top-k) instead of running NMS.
+///
+/// Ultralytics exports emit sigmoid scores. PaddlePaddle exports also take the
+/// `im_shape` and `scale_factor` inputs next to the image, they are fed when the model
+/// declares them, and come in two flavours:
+/// * with the post-processing step: `[N, 6]` rows of `(class, score, x1, y1, x2, y2)`
+///   in original image pixels, plus the `bbox_num` row count;
+/// * `exclude_post_process` exports: separate `[1, num_queries, 4]` boxes and
+///   `[1, num_queries, num_classes]` raw logits, use [`Self::set_apply_sigmoid`] for them.
+///
+/// The PaddlePaddle post-processing scales the boxes itself assuming a plain resize,
+/// so letterbox is disabled by default for models taking `scale_factor`.
+pub struct ModelRTDETROrt {
+    session: Session,
+    tensor_names: TensorNames,
+    im_shape_input: Option<String>,
+    scale_factor_input: Option<String>,
+    input_width: u32,
+    input_height: u32,
+    class_filters: Vec<usize>,
+    use_letterbox: bool,
+    apply_sigmoid: bool,
+    max_detections: usize,
+}
+
+impl ModelRTDETROrt {
+    /// Creates a new model from an ONNX file.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let model = ModelRTDETROrt::new_from_file(
+    ///     "rtdetr-l.onnx",
+    ///     (640, 640),
+    ///     vec![],  // detect all classes
+    /// )?;
+    /// ```
+    pub fn new_from_file(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with CUDA acceleration.
+    ///
+    /// Requires the `ort-cuda-backend` feature.
+    #[cfg(feature = "ort-cuda-backend")]
+    pub fn new_from_file_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_cuda(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with TensorRT acceleration.
+    ///
+    /// Requires the `ort-tensorrt-backend` feature.
+    #[cfg(feature = "ort-tensorrt-backend")]
+    pub fn new_from_file_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_tensorrt(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model with custom session options.
+    ///
+    /// # Arguments
+    /// * `session` - Pre-configured ORT session
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect
+    pub fn from_session(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Self {
+        // Ultralytics names the input "images", PaddlePaddle exports use "image"
+        let input_names: Vec<&str> = session.inputs.iter().map(|input| input.name.as_str()).collect();
+        let (image_input, im_shape_input, scale_factor_input) = Self::select_inputs(&input_names);
+        let discovered = TensorNames::from_session(&session, session.outputs.len().clamp(1, 2));
+        let outputs: Vec<&str> = discovered.outputs().iter().map(String::as_str).collect();
+        let tensor_names = TensorNames::new(&image_input, &outputs);
+        let use_letterbox = cfg!(feature = "letterbox") && scale_factor_input.is_none();
+        Self {
+            session,
+            tensor_names,
+            im_shape_input,
+            scale_factor_input,
+            input_width: input_size.0,
+            input_height: input_size.1,
+            class_filters,
+            use_letterbox,
+            apply_sigmoid: false,
+            max_detections: 300,
+        }
+    }
+
+    /// Creates a new model from an ONNX file with custom tensor names.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn new_from_file_with_names(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Self::from_session_with_names(session, input_size, class_filters, tensor_names)
+    }
+
+    /// Creates a new model with custom session options and tensor names.
+    ///
+    /// The model reads one output, or two for PaddlePaddle exports.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn from_session_with_names(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        tensor_names.validate(&session, tensor_names.outputs().len().clamp(1, 2))?;
+        let mut model = Self::from_session(session, input_size, class_filters);
+        model.tensor_names = tensor_names;
+        Ok(model)
+    }
+
+    /// Enables or disables letterbox preprocessing.
+    ///
+    /// Letterbox preserves aspect ratio by padding with gray.
+    /// Default is `false` (stretch mode).
+    pub fn set_letterbox(&mut self, enabled: bool) {
+        self.use_letterbox = enabled;
+    }
+
+    /// Enables or disables applying sigmoid to the class scores.
+    ///
+    /// Default is `false` (scores are already probabilities, as in Ultralytics exports).
+    pub fn set_apply_sigmoid(&mut self, enabled: bool) {
+        self.apply_sigmoid = enabled;
+    }
+
+    /// Sets the maximum number of detections kept per image (top-k).
+    ///
+    /// Default is 300, the number of queries of the reference models.
+    pub fn set_max_detections(&mut self, max_detections: usize) {
+        self.max_detections = max_detections;
+    }
+
+    /// Returns the input size (width, height).
+    pub fn input_size(&self) -> (u32, u32) {
+        (self.input_width, self.input_height)
+    }
+
+    /// Returns the input and output tensor names.
+    pub fn tensor_names(&self) -> &TensorNames {
+        &self.tensor_names
+    }
+
+    /// Runs inference on an image.
+    ///
+    /// No NMS is performed, the best `max_detections` predictions are kept instead.
+    ///
+    /// # Arguments
+    /// * `image` - Input image buffer
+    /// * `conf_threshold` - Confidence threshold (0.0 - 1.0)
+    ///
+    /// # Returns
+    /// Tuple of (bounding boxes, class IDs, confidence scores)
+    pub fn forward(
+        &mut self,
+        image: &ImageBuffer,
+        conf_threshold: f32,
+    ) -> Result<DetectionVecs, OrtModelError> {
+        let (tensor, meta) = preprocess(
+            image,
+            self.input_width,
+            self.input_height,
+            self.use_letterbox,
+        );
+
+        let input_type = input_element_type(&self.session, self.tensor_names.input());
+        let (im_shape, scale_factor) = Self::paddle_image_info(&meta, (self.input_width, self.input_height));
+        let (im_shape, scale_factor) = (arr2(&[im_shape]), arr2(&[scale_factor]));
+
+        let mut session_inputs = inputs![self.tensor_names.input() => input_value(&tensor, input_type)?];
+        for (name, value) in [(&self.im_shape_input, &im_shape), (&self.scale_factor_input, &scale_factor)] {
+            if let Some(name) = name {
+                let element_type = input_element_type(&self.session, name);
+                session_inputs.push((Cow::from(name.as_str()), input_value(value, element_type)?));
+            }
+        }
+        let outputs = self.session.run(session_inputs)?;
+
+        let arrays = self
+            .tensor_names
+            .outputs()
+            .iter()
+            .map(|name| extract_output(&outputs, name))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let detections = Self::parse_outputs_static(
+            &arrays,
+            conf_threshold,
+            self.apply_sigmoid,
+            (self.input_width, self.input_height),
+            &meta,
+        )?;
+        let filtered = filter_by_class(&detections, &self.class_filters);
+        let final_detections = top_k(filtered, self.max_detections);
+
+        Ok(detections_to_vecs(final_detections))
+    }
+
+    /// Splits the session inputs into the image input and the optional PaddlePaddle
+    /// `im_shape` and `scale_factor` inputs.
+    fn select_inputs(input_names: &[&str]) -> (String, Option<String>, Option<String>) {
+        let find = |name: &str| input_names.iter().find(|n| **n == name).map(|n| n.to_string());
+        let image = input_names
+            .iter()
+            .find(|n| **n != PADDLE_IM_SHAPE && **n != PADDLE_SCALE_FACTOR)
+            .map(|n| n.to_string())
+            .unwrap_or_else(|| "images".to_string());
+        (image, find(PADDLE_IM_SHAPE), find(PADDLE_SCALE_FACTOR))
+    }
+
+    /// Computes the PaddlePaddle `im_shape` (resized image height and width) and
+    /// `scale_factor` (resized / original size, y then x) inputs.
+    fn paddle_image_info(meta: &PreprocessMeta, input_size: (u32, u32)) -> ([f32; 2], [f32; 2]) {
+        match meta {
+            PreprocessMeta::Letterbox(m) => (
+                [
+                    (m.original_height as f32 * m.scale).round(),
+                    (m.original_width as f32 * m.scale).round(),
+                ],
+                [m.scale, m.scale],
+            ),
+            PreprocessMeta::Stretch(m) => (
+                [input_size.1 as f32, input_size.0 as f32],
+                [1.0 / m.scale_y, 1.0 / m.scale_x],
+            ),
+        }
+    }
+
+    /// Parses the model outputs into detections, dispatching on the export flavour.
+    fn parse_outputs_static(
+        outputs: &[ArrayD<f32>],
+        conf_threshold: f32,
+        apply_sigmoid: bool,
+        input_size: (u32, u32),
+        meta: &PreprocessMeta,
+    ) -> Result<Vec<Detection>, OrtModelError> {
+        match outputs {
+            [output] => Self::parse_output_array_static(
+                &output.view(),
+                conf_threshold,
+                apply_sigmoid,
+                input_size,
+                meta,
+            ),
+            // PaddlePaddle post-processed rows and the `bbox_num` count, in either order
+            [first, second] if first.ndim() == 2 || second.ndim() == 2 => {
+                let (rows, bbox_num) = if first.ndim() == 2 { (first, second) } else { (second, first) };
+                Self::parse_paddle_rows_static(&rows.view(), &bbox_num.view(), conf_threshold)
+            }
+            // `exclude_post_process` exports: merge the boxes and the class scores
+            [first, second] => {
+                let (boxes, scores) = if first.shape().last() == Some(&4) { (first, second) } else { (second, first) };
+                let merged = concatenate(Axis(2), &[boxes.view(), scores.view()]).map_err(|_| {
+                    OrtModelError::InvalidOutputShape(format!(
+                        "Expected boxes [1, num_queries, 4] and scores [1, num_queries, num_classes], got {:?} and {:?}",
+                        boxes.shape(),
+                        scores.shape()
+                    ))
+                })?;
+                Self::parse_output_array_static(
+                    &merged.view(),
+                    conf_threshold,
+                    apply_sigmoid,
+                    input_size,
+                    meta,
+                )
+            }
+            _ => Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected 1 or 2 outputs, got {}",
+                outputs.len()
+            ))),
+        }
+    }
+
+    /// Parses PaddlePaddle post-processed `[N, 6]` rows of `(class, score, x1, y1, x2, y2)`.
+    ///
+    /// The model already scales the boxes to the original image using `scale_factor`,
+    /// so no inverse transform is applied. Only the first `bbox_num` rows are read.
+    fn parse_paddle_rows_static(
+        rows: &ArrayViewD<f32>,
+        bbox_num: &ArrayViewD<f32>,
+        conf_threshold: f32,
+    ) -> Result<Vec<Detection>, OrtModelError> {
+        let shape = rows.shape();
+
+        if shape.len() != 2 || shape[1] != 6 {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected shape [N, 6], got {:?}",
+                shape
+            )));
+        }
+
+        let num_rows = bbox_num
+            .iter()
+            .next()
+            .map_or(shape[0], |&n| (n.max(0.0) as usize).min(shape[0]));
+
+        let mut detections = Vec::new();
+
+        for i in 0..num_rows {
+            let class_id = rows[[i, 0]];
+            let score = rows[[i, 1]];
+            // Padding rows carry class -1
+            if class_id < 0.0 || score < conf_threshold {
+                continue;
+            }
+
+            let (x1, y1, x2, y2) = (rows[[i, 2]], rows[[i, 3]], rows[[i, 4]], rows[[i, 5]]);
+            let bbox = BBox::from_center((x1 + x2) / 2.0, (y1 + y2) / 2.0, x2 - x1, y2 - y1);
+            detections.push(Detection::new(bbox, class_id as usize, score));
+        }
+
+        Ok(detections)
+    }
+
+    /// Parses the `[1, num_queries, 4 + num_classes]` model output into detections.
+    fn parse_output_array_static(
+        output: &ArrayViewD<f32>,
+        conf_threshold: f32,
+        apply_sigmoid: bool,
+        input_size: (u32, u32),
+        meta: &PreprocessMeta,
+    ) -> Result<Vec<Detection>, OrtModelError> {
+        let shape = output.shape();
+
+        if shape.len() != 3 || shape[0] != 1 || shape[2] < 5 {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected shape [1, num_queries, 4 + num_classes], got {:?}",
+                shape
+            )));
+        }
+
+        let num_queries = shape[1];
+        let num_features = shape[2];
+        let input_width = input_size.0 as f32;
+        let input_height = input_size.1 as f32;
+
+        let mut detections = Vec::new();
+
+        for i in 0..num_queries {
+            let class_scores: Vec<f32> = (4..num_features)
+                .map(|j| output[[0, i, j]])
+                .collect();
+
+            if let Some((class_idx, raw_score)) = argmax(&class_scores) {
+                // Sigmoid is monotonic, so the argmax does not change
+                let score = if apply_sigmoid { sigmoid(raw_score) } else { raw_score };
+                if score < conf_threshold {
+                    continue;
+                }
+
+                let (cx, cy, w, h) = meta.inverse_transform(
+                    output[[0, i, 0]] * input_width,
+                    output[[0, i, 1]] * input_height,
+                    output[[0, i, 2]] * input_width,
+                    output[[0, i, 3]] * input_height,
+                );
+
+                detections.push(Detection::new(BBox::from_center(cx, cy, w, h), class_idx, score));
+            }
+        }
+
+        Ok(detections)
+    }
+}
+
+impl crate::ObjectDetector for ModelRTDETROrt {
+    type Input = ImageBuffer;
+    type Error = OrtModelError;
+
+    /// Runs detection. `nms_threshold` is ignored since RT-DETR is NMS-free.
+    fn detect(
+        &mut self,
+        input: &Self::Input,
+        conf_threshold: f32,
+        _nms_threshold: f32,
+    ) -> Result<DetectionVecs, Self::Error> {
+        self.forward(input, conf_threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{arr1, Array2, Array3};
+
+    #[test]
+    fn test_select_inputs() {
+        let (image, im_shape, scale_factor) = ModelRTDETROrt::select_inputs(&["images"]);
+        assert_eq!(image, "images");
+        assert_eq!(im_shape, None);
+        assert_eq!(scale_factor, None);
+
+        // PaddlePaddle exports list the auxiliary inputs around the image
+        let (image, im_shape, scale_factor) = ModelRTDETROrt::select_inputs(&["im_shape", "image", "scale_factor"]);
+        assert_eq!(image, "image");
+        assert_eq!(im_shape.as_deref(), Some("im_shape"));
+        assert_eq!(scale_factor.as_deref(), Some("scale_factor"));
+    }
+
+    #[test]
+    fn test_paddle_image_info() {
+        let (im_shape, scale_factor) = ModelRTDETROrt::paddle_image_info(&PreprocessMeta::stretch((1280, 640), (640, 640)), (640, 640));
+        assert_eq!(im_shape, [640.0, 640.0]);
+        assert_eq!(scale_factor, [1.0, 0.5]);
+
+        let letterbox = PreprocessMeta::letterbox((1280, 640), (640, 640));
+        let (im_shape, scale_factor) = ModelRTDETROrt::paddle_image_info(&letterbox, (640, 640));
+        assert_eq!(im_shape, [320.0, 640.0]);
+        assert_eq!(scale_factor, [0.5, 0.5]);
+    }
+
+    #[test]
+    fn test_parse_output_normalized() {
+        // 2 queries, 3 classes
+        let mut output = Array3::<f32>::zeros((1, 2, 7));
+        output[[0, 0, 0]] = 0.25;
+        output[[0, 0, 1]] = 0.5;
+        output[[0, 0, 2]] = 0.125;
+        output[[0, 0, 3]] = 0.25;
+        output[[0, 0, 6]] = 0.8;
+        // Below threshold
+        output[[0, 1, 4]] = 0.1;
+
+        let detections = ModelRTDETROrt::parse_output_array_static(
+            &output.view().into_dyn(),
+            0.25,
+            false,
+            (640, 640),
+            &PreprocessMeta::stretch((1280, 640), (640, 640)),
+        )
+        .unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].class_id, 2);
+        assert!((detections[0].confidence - 0.8).abs() < 0.001);
+        // cx = 160 * 2, cy = 320, w = 80 * 2, h = 160
+        assert_eq!(detections[0].bbox, BBox::new(240, 240, 160, 160));
+    }
+
+    #[test]
+    fn test_parse_output_logits() {
+        let mut output = Array3::<f32>::zeros((1, 2, 6));
+        // sigmoid(2.0) ~ 0.88
+        output[[0, 0, 4]] = 2.0;
+        // sigmoid(-2.0) ~ 0.12
+        output[[0, 1, 5]] = -2.0;
+        output[[0, 1, 4]] = -3.0;
+
+        let detections = ModelRTDETROrt::parse_output_array_static(
+            &output.view().into_dyn(),
+            0.25,
+            true,
+            (640, 640),
+            &PreprocessMeta::stretch((1280, 640), (640, 640)),
+        )
+        .unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].class_id, 0);
+        assert!((detections[0].confidence - 0.8808).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_parse_output_invalid_shape() {
+        let output = Array3::<f32>::zeros((1, 84, 4));
+        let result = ModelRTDETROrt::parse_output_array_static(
+            &output.view().into_dyn(),
+            0.25,
+            false,
+            (640, 640),
+            &PreprocessMeta::stretch((1280, 640), (640, 640)),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_paddle_rows() {
+        let mut rows = Array2::<f32>::zeros((4, 6));
+        rows.row_mut(0).assign(&arr1(&[2.0, 0.9, 100.0, 50.0, 300.0, 250.0]));
+        // Below threshold
+        rows.row_mut(1).assign(&arr1(&[1.0, 0.1, 0.0, 0.0, 10.0, 10.0]));
+        // Padding row
+        rows.row_mut(2).assign(&arr1(&[-1.0, 0.9, 0.0, 0.0, 10.0, 10.0]));
+        // Beyond bbox_num
+        rows.row_mut(3).assign(&arr1(&[0.0, 0.9, 0.0, 0.0, 10.0, 10.0]));
+        let bbox_num = arr1(&[3.0f32]);
+
+        let detections = ModelRTDETROrt::parse_outputs_static(
+            &[rows.into_dyn(), bbox_num.into_dyn()],
+            0.25,
+            false,
+            (640, 640),
+            &PreprocessMeta::stretch((1280, 640), (640, 640)),
+        )
+        .unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].class_id, 2);
+        // Already in original image pixels
+        assert_eq!(detections[0].bbox, BBox::new(100, 50, 200, 200));
+    }
+
+    #[test]
+    fn test_parse_split_boxes_and_logits() {
+        let mut boxes = Array3::<f32>::zeros((1, 2, 4));
+        boxes[[0, 0, 0]] = 0.25;
+        boxes[[0, 0, 1]] = 0.5;
+        boxes[[0, 0, 2]] = 0.125;
+        boxes[[0, 0, 3]] = 0.25;
+        let mut logits = Array3::<f32>::from_elem((1, 2, 3), -5.0);
+        logits[[0, 0, 1]] = 2.0;
+
+        let detections = ModelRTDETROrt::parse_outputs_static(
+            &[logits.into_dyn(), boxes.into_dyn()],
+            0.25,
+            true,
+            (640, 640),
+            &PreprocessMeta::stretch((1280, 640), (640, 640)),
+        )
+        .unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].class_id, 1);
+        assert_eq!(detections[0].bbox, BBox::new(240, 240, 160, 160));
+    }
+
+    #[test]
+    fn test_parse_split_outputs_mismatch() {
+        let boxes = Array3::<f32>::zeros((1, 2, 4));
+        let logits = Array3::<f32>::zeros((1, 3, 80));
+        let result = ModelRTDETROrt::parse_outputs_static(
+            &[boxes.into_dyn(), logits.into_dyn()],
+            0.25,
+            false,
+            (640, 640),
+            &PreprocessMeta::stretch((1280, 640), (640, 640)),
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,485 @@
+//! Ultralytics YOLO instance segmentation models (v8-seg, v11-seg) using ONNX Runtime.
+
+use ort::session::Session;
+use ort::inputs;
+
+use crate::bbox::BBox;
+use crate::model_trait::DetectionVecs;
+use crate::image_buffer::ImageBuffer;
+use crate::mask::Mask;
+use crate::postprocess::{Detection, NmsStrategy, argmax};
+use crate::preprocessing::{preprocess, PreprocessMeta};
+
+use super::OrtModelError;
+use super::tensor_names::TensorNames;
+use super::tensor::{extract_output, input_element_type, input_value};
+
+/// Ultralytics YOLO segmentation model using ONNX Runtime.
+///
+/// `-seg` exports produce two outputs:
+/// - `output0`: `[1, 4 + num_classes + num_masks, N]`, the usual detection rows
+///   followed by `num_masks` (32) mask coefficients per box
+/// - `output1`: `[1, num_masks, mask_height, mask_width]` mask prototypes (160x160 for 640x640 input)
+///
+/// The mask of a detection is `sigmoid(coefficients · prototypes)`, sampled back to the
+/// original image through the preprocessing transform and cropped to the detection box.
+pub struct ModelUltralyticsSegOrt {
+    session: Session,
+    tensor_names: TensorNames,
+    input_width: u32,
+    input_height: u32,
+    class_filters: Vec<usize>,
+    nms_strategy: NmsStrategy,
+    use_letterbox: bool,
+}
+
+impl ModelUltralyticsSegOrt {
+    /// Creates a new model from an ONNX file.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let model = ModelUltralyticsSegOrt::new_from_file(
+    ///     "yolov8n-seg.onnx",
+    ///     (640, 640),
+    ///     vec![],  // detect all classes
+    /// )?;
+    /// ```
+    pub fn new_from_file(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with CUDA acceleration.
+    ///
+    /// Requires the `ort-cuda-backend` feature.
+    #[cfg(feature = "ort-cuda-backend")]
+    pub fn new_from_file_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_cuda(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with TensorRT acceleration.
+    ///
+    /// Requires the `ort-tensorrt-backend` feature.
+    #[cfg(feature = "ort-tensorrt-backend")]
+    pub fn new_from_file_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_tensorrt(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model with custom session options.
+    ///
+    /// # Arguments
+    /// * `session` - Pre-configured ORT session
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect
+    pub fn from_session(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Self {
+        let tensor_names = TensorNames::from_session(&session, 2);
+        Self {
+            session,
+            tensor_names,
+            input_width: input_size.0,
+            input_height: input_size.1,
+            class_filters,
+            nms_strategy: NmsStrategy::default(),
+            #[cfg(feature = "letterbox")]
+            use_letterbox: true,
+            #[cfg(not(feature = "letterbox"))]
+            use_letterbox: false,
+        }
+    }
+
+    /// Creates a new model from an ONNX file with custom tensor names.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn new_from_file_with_names(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Self::from_session_with_names(session, input_size, class_filters, tensor_names)
+    }
+
+    /// Creates a new model with custom session options and tensor names.
+    ///
+    /// The model reads two outputs: detections and mask prototypes.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn from_session_with_names(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        tensor_names.validate(&session, 2)?;
+        let mut model = Self::from_session(session, input_size, class_filters);
+        model.tensor_names = tensor_names;
+        Ok(model)
+    }
+
+    /// Enables or disables letterbox preprocessing.
+    ///
+    /// Letterbox preserves aspect ratio by padding with gray.
+    /// Default is `false` (stretch mode).
+    pub fn set_letterbox(&mut self, enabled: bool) {
+        self.use_letterbox = enabled;
+    }
+
+    /// Sets the NMS algorithm applied to the detections.
+    ///
+    /// Default is [`NmsStrategy::Greedy`].
+    pub fn set_nms_strategy(&mut self, strategy: NmsStrategy) {
+        self.nms_strategy = strategy;
+    }
+
+    /// Returns the input size (width, height).
+    pub fn input_size(&self) -> (u32, u32) {
+        (self.input_width, self.input_height)
+    }
+
+    /// Returns the input and output tensor names.
+    pub fn tensor_names(&self) -> &TensorNames {
+        &self.tensor_names
+    }
+
+    /// Runs inference on an image, returning bounding boxes only.
+    ///
+    /// Mask decoding is skipped, use [`Self::forward_masks`] to get masks.
+    ///
+    /// # Arguments
+    /// * `image` - Input image buffer
+    /// * `conf_threshold` - Confidence threshold (0.0 - 1.0)
+    /// * `nms_threshold` - NMS IoU threshold (0.0 - 1.0)
+    ///
+    /// # Returns
+    /// Tuple of (bounding boxes, class IDs, confidence scores)
+    pub fn forward(
+        &mut self,
+        image: &ImageBuffer,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs, OrtModelError> {
+        let (bboxes, class_ids, confidences, _) = self.run(image, conf_threshold, nms_threshold, false)?;
+        Ok((bboxes, class_ids, confidences))
+    }
+
+    /// Runs inference on an image, returning bounding boxes and instance masks.
+    ///
+    /// # Arguments
+    /// * `image` - Input image buffer
+    /// * `conf_threshold` - Confidence threshold (0.0 - 1.0)
+    /// * `nms_threshold` - NMS IoU threshold (0.0 - 1.0)
+    ///
+    /// # Returns
+    /// Tuple of (bounding boxes, class IDs, confidence scores, masks)
+    #[allow(clippy::type_complexity)]
+    pub fn forward_masks(
+        &mut self,
+        image: &ImageBuffer,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<(Vec<BBox>, Vec<usize>, Vec<f32>, Vec<Mask>), OrtModelError> {
+        self.run(image, conf_threshold, nms_threshold, true)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn run(
+        &mut self,
+        image: &ImageBuffer,
+        conf_threshold: f32,
+        nms_threshold: f32,
+        with_masks: bool,
+    ) -> Result<(Vec<BBox>, Vec<usize>, Vec<f32>, Vec<Mask>), OrtModelError> {
+        let (tensor, meta) = preprocess(
+            image,
+            self.input_width,
+            self.input_height,
+            self.use_letterbox,
+        );
+
+        let input_type = input_element_type(&self.session, self.tensor_names.input());
+        let outputs = self.session.run(
+            inputs![self.tensor_names.input() => input_value(&tensor, input_type)?]
+        )?;
+
+        let output = extract_output(&outputs, self.tensor_names.output(0))?;
+        let protos = extract_output(&outputs, self.tensor_names.output(1))?;
+
+        let proto_shape = protos.shape();
+        if proto_shape.len() != 4 || proto_shape[0] != 1 {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected prototypes shape [1, num_masks, H, W], got {:?}",
+                proto_shape
+            )));
+        }
+        let num_masks = proto_shape[1];
+
+        let (detections, coefficients) =
+            Self::parse_output_array_static(&output.view(), num_masks, conf_threshold, &meta)?;
+
+        // Class filtering and NMS work on indices to keep coefficients aligned
+        let candidates: Vec<usize> = (0..detections.len())
+            .filter(|&i| self.class_filters.is_empty() || self.class_filters.contains(&detections[i].class_id))
+            .collect();
+        let filtered: Vec<Detection> = candidates.iter().map(|&i| detections[i].clone()).collect();
+        let kept = self.nms_strategy.apply_indices(&filtered, nms_threshold, conf_threshold);
+
+        let protos = protos
+            .index_axis(ndarray::Axis(0), 0)
+            .into_dimensionality::<ndarray::Ix3>()
+            .map_err(|e| OrtModelError::InvalidOutputShape(e.to_string()))?;
+
+        let mut bboxes = Vec::with_capacity(kept.len());
+        let mut class_ids = Vec::with_capacity(kept.len());
+        let mut confidences = Vec::with_capacity(kept.len());
+        let mut masks = Vec::new();
+
+        for (idx, confidence) in kept {
+            let original_idx = candidates[idx];
+            let detection = &detections[original_idx];
+            if with_masks {
+                masks.push(Self::decode_mask_static(
+                    &coefficients[original_idx],
+                    &protos,
+                    detection.bbox,
+                    (self.input_width, self.input_height),
+                    &meta,
+                ));
+            }
+            bboxes.push(detection.bbox);
+            class_ids.push(detection.class_id);
+            confidences.push(confidence);
+        }
+
+        Ok((bboxes, class_ids, confidences, masks))
+    }
+
+    /// Parses the `[1, 4 + num_classes + num_masks, N]` output into detections
+    /// and their mask coefficients.
+    fn parse_output_array_static(
+        output: &ndarray::ArrayViewD<f32>,
+        num_masks: usize,
+        conf_threshold: f32,
+        meta: &PreprocessMeta,
+    ) -> Result<(Vec<Detection>, Vec<Vec<f32>>), OrtModelError> {
+        let shape = output.shape();
+
+        if shape.len() != 3 || shape[0] != 1 || shape[1] < 5 + num_masks {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected shape [1, 4 + num_classes + {}, N], got {:?}",
+                num_masks, shape
+            )));
+        }
+
+        let num_features = shape[1];
+        let num_predictions = shape[2];
+        let mask_start = num_features - num_masks;
+
+        let mut detections = Vec::new();
+        let mut coefficients = Vec::new();
+
+        for i in 0..num_predictions {
+            let class_scores: Vec<f32> = (4..mask_start)
+                .map(|j| output[[0, j, i]])
+                .collect();
+
+            if let Some((class_idx, max_score)) = argmax(&class_scores)
+                && max_score >= conf_threshold
+            {
+                let (cx, cy, w, h) = meta.inverse_transform(
+                    output[[0, 0, i]],
+                    output[[0, 1, i]],
+                    output[[0, 2, i]],
+                    output[[0, 3, i]],
+                );
+
+                detections.push(Detection::new(BBox::from_center(cx, cy, w, h), class_idx, max_score));
+                coefficients.push((mask_start..num_features).map(|j| output[[0, j, i]]).collect());
+            }
+        }
+
+        Ok((detections, coefficients))
+    }
+
+    /// Builds the mask of a detection from its coefficients and the prototypes.
+    ///
+    /// Every pixel of `bbox` (clamped to the original image) is mapped to input space
+    /// through `meta`, then bilinearly sampled in the prototype grid.
+    fn decode_mask_static(
+        coefficients: &[f32],
+        protos: &ndarray::ArrayView3<f32>,
+        bbox: BBox,
+        input_size: (u32, u32),
+        meta: &PreprocessMeta,
+    ) -> Mask {
+        let (_, proto_height, proto_width) = protos.dim();
+
+        // Linear combination of prototypes, sigmoid(x) > 0.5 is equivalent to x > 0
+        let mut logits = ndarray::Array2::<f32>::zeros((proto_height, proto_width));
+        for (coefficient, proto) in coefficients.iter().zip(protos.outer_iter()) {
+            logits.scaled_add(*coefficient, &proto);
+        }
+
+        let (image_width, image_height) = meta.original_size();
+        let x1 = bbox.x.clamp(0, image_width);
+        let y1 = bbox.y.clamp(0, image_height);
+        let x2 = (bbox.x + bbox.width).clamp(0, image_width);
+        let y2 = (bbox.y + bbox.height).clamp(0, image_height);
+        // Degenerate boxes (negative size) give an empty mask
+        let clamped = BBox::new(x1, y1, (x2 - x1).max(0), (y2 - y1).max(0));
+
+        let scale_x = proto_width as f32 / input_size.0 as f32;
+        let scale_y = proto_height as f32 / input_size.1 as f32;
+
+        let mut data = ndarray::Array2::from_elem((clamped.height as usize, clamped.width as usize), false);
+        for ((row, col), value) in data.indexed_iter_mut() {
+            let (ix, iy) = meta.transform_point(
+                (x1 + col as i32) as f32 + 0.5,
+                (y1 + row as i32) as f32 + 0.5,
+            );
+            *value = bilinear(&logits, ix * scale_x - 0.5, iy * scale_y - 0.5) > 0.0;
+        }
+
+        Mask::new(clamped, data)
+    }
+}
+
+/// Samples `grid` at fractional coordinates, clamping to its edges.
+fn bilinear(grid: &ndarray::Array2<f32>, x: f32, y: f32) -> f32 {
+    let (height, width) = grid.dim();
+    let x = x.clamp(0.0, (width - 1) as f32);
+    let y = y.clamp(0.0, (height - 1) as f32);
+    let x0 = x.floor() as usize;
+    let y0 = y.floor() as usize;
+    let x1 = (x0 + 1).min(width - 1);
+    let y1 = (y0 + 1).min(height - 1);
+    let fx = x - x0 as f32;
+    let fy = y - y0 as f32;
+
+    let top = grid[[y0, x0]] * (1.0 - fx) + grid[[y0, x1]] * fx;
+    let bottom = grid[[y1, x0]] * (1.0 - fx) + grid[[y1, x1]] * fx;
+    top * (1.0 - fy) + bottom * fy
+}
+
+impl crate::ObjectDetector for ModelUltralyticsSegOrt {
+    type Input = ImageBuffer;
+    type Error = OrtModelError;
+
+    fn detect(
+        &mut self,
+        input: &Self::Input,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs, Self::Error> {
+        self.forward(input, conf_threshold, nms_threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{Array3, Array4};
+
+    #[test]
+    fn test_parse_output_with_coefficients() {
+        // 2 predictions, 2 classes, 2 mask coefficients
+        let mut output = Array3::<f32>::zeros((1, 8, 2));
+        output[[0, 0, 0]] = 4.0;
+        output[[0, 1, 0]] = 4.0;
+        output[[0, 2, 0]] = 4.0;
+        output[[0, 3, 0]] = 4.0;
+        output[[0, 5, 0]] = 0.9;
+        output[[0, 6, 0]] = 1.5;
+        output[[0, 7, 0]] = -0.5;
+        // Below threshold
+        output[[0, 4, 1]] = 0.1;
+
+        let (detections, coefficients) = ModelUltralyticsSegOrt::parse_output_array_static(
+            &output.view().into_dyn(),
+            2,
+            0.25,
+            &PreprocessMeta::stretch((16, 8), (8, 8)),
+        )
+        .unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].class_id, 1);
+        assert_eq!(detections[0].bbox, BBox::new(4, 2, 8, 4));
+        assert_eq!(coefficients[0], vec![1.5, -0.5]);
+    }
+
+    #[test]
+    fn test_decode_mask() {
+        // One 4x4 prototype, positive on its left half only
+        let mut protos = Array4::<f32>::from_elem((1, 1, 4, 4), -1.0);
+        for row in 0..4 {
+            protos[[0, 0, row, 0]] = 1.0;
+            protos[[0, 0, row, 1]] = 1.0;
+        }
+        let protos = protos.index_axis_move(ndarray::Axis(0), 0);
+
+        // Box covering the whole 16x8 original image, partially outside of it
+        let mask = ModelUltralyticsSegOrt::decode_mask_static(
+            &[2.0],
+            &protos.view(),
+            BBox::new(-2, 0, 20, 8),
+            (8, 8),
+            &PreprocessMeta::stretch((16, 8), (8, 8)),
+        );
+
+        assert_eq!(mask.bbox, BBox::new(0, 0, 16, 8));
+        assert!(mask.contains(0, 0));
+        assert!(mask.contains(6, 4));
+        assert!(!mask.contains(9, 4));
+        assert!(!mask.contains(15, 7));
+        assert_eq!(mask.area(), 64);
+
+        // Negative width from a degenerate prediction
+        let mask = ModelUltralyticsSegOrt::decode_mask_static(
+            &[2.0],
+            &protos.view(),
+            BBox::new(4, 0, -3, 8),
+            (8, 8),
+            &PreprocessMeta::stretch((16, 8), (8, 8)),
+        );
+        assert_eq!(mask.bbox.width, 0);
+        assert_eq!(mask.area(), 0);
+    }
+
+    #[test]
+    fn test_parse_output_invalid_shape() {
+        let output = Array3::<f32>::zeros((1, 20, 10));
+        let result = ModelUltralyticsSegOrt::parse_output_array_static(
+            &output.view().into_dyn(),
+            32,
+            0.25,
+            &PreprocessMeta::stretch((16, 8), (8, 8)),
+        );
+        assert!(result.is_err());
+    }
+}
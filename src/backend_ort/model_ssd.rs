@@ -0,0 +1,426 @@
+//! SSD / TensorFlow Object Detection API style models using ONNX Runtime.
+
+use ort::session::Session;
+use ort::inputs;
+use ort::tensor::TensorElementType;
+use ort::value::TensorRef;
+
+use crate::bbox::BBox;
+use crate::model_trait::DetectionVecs;
+use crate::image_buffer::ImageBuffer;
+use crate::postprocess::{Detection, filter_by_class, detections_to_vecs};
+use crate::preprocessing::{resize, to_nchw_tensor, to_nhwc_tensor, to_nhwc_u8_tensor, PreprocessMeta};
+
+use super::OrtModelError;
+use super::tensor::{extract_output, input_element_type, input_value};
+use super::tensor_names::TensorNames;
+
+/// Input tensor layout and element type, read from the session.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SsdInput {
+    /// `[1, H, W, 3]` uint8, the TF Object Detection API default
+    Uint8Nhwc,
+    /// `[1, H, W, 3]` float32 in 0.0 - 1.0
+    Float32Nhwc,
+    /// `[1, 3, H, W]` float32 in 0.0 - 1.0
+    Float32Nchw,
+}
+
+/// SSD-style model (MobileNet-SSD, EfficientDet, ...) using ONNX Runtime.
+///
+/// These models (TF Object Detection API exports converted with tf2onnx, TFLite
+/// detection postprocess) run NMS in the graph and expose four outputs:
+/// - `detection_boxes [1, N, 4]`: normalized `(ymin, xmin, ymax, xmax)`
+/// - `detection_classes [1, N]`: class ids, as float or integer
+/// - `detection_scores [1, N]`: confidences
+/// - `num_detections [1]`: number of valid rows
+///
+/// Outputs are matched by name, or by position (boxes, classes, scores, count)
+/// when names are unknown. Class ids are returned as emitted by the model: TF
+/// label maps are usually 1-based (0 being the background).
+///
+/// The input layout (NHWC or NCHW) and type (uint8 or float32) are taken from the model.
+pub struct ModelSSDOrt {
+    session: Session,
+    tensor_names: TensorNames,
+    input_kind: SsdInput,
+    input_width: u32,
+    input_height: u32,
+    class_filters: Vec<usize>,
+    use_letterbox: bool,
+}
+
+impl ModelSSDOrt {
+    /// Creates a new model from an ONNX file.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let model = ModelSSDOrt::new_from_file(
+    ///     "ssd_mobilenet_v2.onnx",
+    ///     (300, 300),
+    ///     vec![],  // detect all classes
+    /// )?;
+    /// ```
+    pub fn new_from_file(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with CUDA acceleration.
+    ///
+    /// Requires the `ort-cuda-backend` feature.
+    #[cfg(feature = "ort-cuda-backend")]
+    pub fn new_from_file_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_cuda(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with TensorRT acceleration.
+    ///
+    /// Requires the `ort-tensorrt-backend` feature.
+    #[cfg(feature = "ort-tensorrt-backend")]
+    pub fn new_from_file_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_tensorrt(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model with custom session options.
+    ///
+    /// # Arguments
+    /// * `session` - Pre-configured ORT session
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect
+    pub fn from_session(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Self {
+        let tensor_names = TensorNames::from_session(&session, session.outputs.len().max(1));
+        let input_kind = Self::input_kind(&session, tensor_names.input());
+        Self {
+            session,
+            tensor_names,
+            input_kind,
+            input_width: input_size.0,
+            input_height: input_size.1,
+            class_filters,
+            #[cfg(feature = "letterbox")]
+            use_letterbox: true,
+            #[cfg(not(feature = "letterbox"))]
+            use_letterbox: false,
+        }
+    }
+
+    /// Creates a new model from an ONNX file with custom tensor names.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn new_from_file_with_names(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Self::from_session_with_names(session, input_size, class_filters, tensor_names)
+    }
+
+    /// Creates a new model with custom session options and tensor names.
+    ///
+    /// The model reads four outputs: boxes, classes, scores and count, in this order.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn from_session_with_names(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        tensor_names.validate(&session, 4)?;
+        let mut model = Self::from_session(session, input_size, class_filters);
+        model.input_kind = Self::input_kind(&model.session, tensor_names.input());
+        model.tensor_names = tensor_names;
+        Ok(model)
+    }
+
+    /// Reads the layout and element type of the session input named `name`.
+    fn input_kind(session: &Session, name: &str) -> SsdInput {
+        let Some(input) = session.inputs.iter().find(|input| input.name == name) else {
+            return SsdInput::Uint8Nhwc;
+        };
+        let is_u8 = input.input_type.tensor_type() == Some(TensorElementType::Uint8);
+        let is_nhwc = input
+            .input_type
+            .tensor_shape()
+            .is_some_and(|shape| shape.len() == 4 && shape[3] == 3);
+        match (is_u8, is_nhwc) {
+            (true, _) => SsdInput::Uint8Nhwc,
+            (false, true) => SsdInput::Float32Nhwc,
+            (false, false) => SsdInput::Float32Nchw,
+        }
+    }
+
+    /// Enables or disables letterbox preprocessing.
+    ///
+    /// Letterbox preserves aspect ratio by padding with gray.
+    /// Default is `false` (stretch mode).
+    pub fn set_letterbox(&mut self, enabled: bool) {
+        self.use_letterbox = enabled;
+    }
+
+    /// Returns the input size (width, height).
+    pub fn input_size(&self) -> (u32, u32) {
+        (self.input_width, self.input_height)
+    }
+
+    /// Returns the input and output tensor names.
+    pub fn tensor_names(&self) -> &TensorNames {
+        &self.tensor_names
+    }
+
+    /// Runs inference on an image.
+    ///
+    /// No NMS is performed since the model outputs final detections.
+    ///
+    /// # Arguments
+    /// * `image` - Input image buffer
+    /// * `conf_threshold` - Confidence threshold (0.0 - 1.0)
+    ///
+    /// # Returns
+    /// Tuple of (bounding boxes, class IDs, confidence scores)
+    pub fn forward(
+        &mut self,
+        image: &ImageBuffer,
+        conf_threshold: f32,
+    ) -> Result<DetectionVecs, OrtModelError> {
+        let (resized, meta) = resize(image, self.input_width, self.input_height, self.use_letterbox);
+
+        let input_name = self.tensor_names.input();
+        let input_type = input_element_type(&self.session, input_name);
+        let outputs = match self.input_kind {
+            SsdInput::Uint8Nhwc => {
+                let tensor = to_nhwc_u8_tensor(&resized);
+                self.session.run(inputs![input_name => TensorRef::from_array_view(&tensor)?])?
+            }
+            SsdInput::Float32Nhwc => {
+                let tensor = to_nhwc_tensor(&resized);
+                self.session.run(inputs![input_name => input_value(&tensor, input_type)?])?
+            }
+            SsdInput::Float32Nchw => {
+                let tensor = to_nchw_tensor(&resized);
+                self.session.run(inputs![input_name => input_value(&tensor, input_type)?])?
+            }
+        };
+
+        let output_names = self.tensor_names.outputs();
+        let mut arrays = Vec::with_capacity(output_names.len());
+        for name in output_names {
+            arrays.push((name.clone(), extract_output(&outputs, name)?));
+        }
+
+        let [boxes, classes, scores, num_detections] = Self::select_outputs(&arrays)?;
+        let detections = Self::parse_outputs_static(
+            &arrays[boxes].1.view(),
+            &arrays[classes].1.view(),
+            &arrays[scores].1.view(),
+            &arrays[num_detections].1.view(),
+            conf_threshold,
+            (self.input_width, self.input_height),
+            &meta,
+        )?;
+        let filtered = filter_by_class(&detections, &self.class_filters);
+
+        Ok(detections_to_vecs(filtered))
+    }
+
+    /// Finds the indices of the boxes, classes, scores and count outputs.
+    ///
+    /// TF exports may also expose raw (pre-NMS) and multiclass outputs, those are skipped.
+    fn select_outputs(arrays: &[(String, ndarray::ArrayD<f32>)]) -> Result<[usize; 4], OrtModelError> {
+        let find = |pattern: &str| {
+            arrays.iter().position(|(name, _)| {
+                name.contains(pattern) && !name.contains("raw") && !name.contains("multiclass")
+            })
+        };
+
+        if let (Some(boxes), Some(classes), Some(scores), Some(num_detections)) = (
+            find("boxes"),
+            find("classes"),
+            find("scores"),
+            find("num_detections"),
+        ) {
+            return Ok([boxes, classes, scores, num_detections]);
+        }
+
+        // TFLite detection postprocess order
+        if arrays.len() == 4 {
+            return Ok([0, 1, 2, 3]);
+        }
+
+        Err(OrtModelError::InvalidOutputShape(format!(
+            "Expected detection_boxes, detection_classes, detection_scores and num_detections outputs, got {:?}",
+            arrays.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>()
+        )))
+    }
+
+    /// Parses the four SSD outputs into detections.
+    fn parse_outputs_static(
+        boxes: &ndarray::ArrayViewD<f32>,
+        classes: &ndarray::ArrayViewD<f32>,
+        scores: &ndarray::ArrayViewD<f32>,
+        num_detections: &ndarray::ArrayViewD<f32>,
+        conf_threshold: f32,
+        input_size: (u32, u32),
+        meta: &PreprocessMeta,
+    ) -> Result<Vec<Detection>, OrtModelError> {
+        let boxes_shape = boxes.shape();
+
+        if boxes_shape.len() != 3 || boxes_shape[0] != 1 || boxes_shape[2] != 4 {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected detection_boxes shape [1, N, 4], got {:?}",
+                boxes_shape
+            )));
+        }
+
+        let max_dets = boxes_shape[1];
+        if scores.len() != max_dets || classes.len() != max_dets {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected detection_scores and detection_classes with {} elements, got {:?} and {:?}",
+                max_dets,
+                scores.shape(),
+                classes.shape()
+            )));
+        }
+
+        let count = num_detections
+            .iter()
+            .next()
+            .map(|&n| (n.max(0.0) as usize).min(max_dets))
+            .unwrap_or(max_dets);
+
+        let input_width = input_size.0 as f32;
+        let input_height = input_size.1 as f32;
+
+        let mut detections = Vec::with_capacity(count);
+
+        for (i, (&score, &class_id)) in scores.iter().zip(classes.iter()).take(count).enumerate() {
+            if score < conf_threshold {
+                continue;
+            }
+
+            let y1 = boxes[[0, i, 0]] * input_height;
+            let x1 = boxes[[0, i, 1]] * input_width;
+            let y2 = boxes[[0, i, 2]] * input_height;
+            let x2 = boxes[[0, i, 3]] * input_width;
+
+            let (cx, cy, w, h) = meta.inverse_transform(
+                (x1 + x2) / 2.0,
+                (y1 + y2) / 2.0,
+                x2 - x1,
+                y2 - y1,
+            );
+
+            detections.push(Detection::new(
+                BBox::from_center(cx, cy, w, h),
+                class_id.round().max(0.0) as usize,
+                score,
+            ));
+        }
+
+        Ok(detections)
+    }
+}
+
+impl crate::ObjectDetector for ModelSSDOrt {
+    type Input = ImageBuffer;
+    type Error = OrtModelError;
+
+    /// Runs detection. `nms_threshold` is ignored since NMS is part of the model.
+    fn detect(
+        &mut self,
+        input: &Self::Input,
+        conf_threshold: f32,
+        _nms_threshold: f32,
+    ) -> Result<DetectionVecs, Self::Error> {
+        self.forward(input, conf_threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{Array1, Array2, Array3, ArrayD, IxDyn};
+
+    #[test]
+    fn test_parse_outputs() {
+        let mut boxes = Array3::<f32>::zeros((1, 10, 4));
+        // ymin, xmin, ymax, xmax
+        boxes[[0, 0, 0]] = 0.1;
+        boxes[[0, 0, 1]] = 0.2;
+        boxes[[0, 0, 2]] = 0.5;
+        boxes[[0, 0, 3]] = 0.6;
+        let mut classes = Array2::<f32>::zeros((1, 10));
+        classes[[0, 0]] = 18.0;
+        let mut scores = Array2::<f32>::zeros((1, 10));
+        scores[[0, 0]] = 0.9;
+        scores[[0, 1]] = 0.1;
+        // Beyond num_detections
+        scores[[0, 2]] = 0.9;
+        let num_detections = Array1::<f32>::from_elem(1, 2.0);
+
+        let meta = PreprocessMeta::stretch((600, 600), (300, 300));
+
+        let detections = ModelSSDOrt::parse_outputs_static(
+            &boxes.view().into_dyn(),
+            &classes.view().into_dyn(),
+            &scores.view().into_dyn(),
+            &num_detections.view().into_dyn(),
+            0.25,
+            (300, 300),
+            &meta,
+        )
+        .unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].class_id, 18);
+        assert_eq!(detections[0].bbox, BBox::new(120, 60, 240, 240));
+    }
+
+    #[test]
+    fn test_select_outputs() {
+        let arrays = vec![
+            ("detection_boxes".to_string(), ArrayD::<f32>::zeros(IxDyn(&[1, 100, 4]))),
+            ("detection_classes".to_string(), ArrayD::<f32>::zeros(IxDyn(&[1, 100]))),
+            ("detection_multiclass_scores".to_string(), ArrayD::<f32>::zeros(IxDyn(&[1, 100, 91]))),
+            ("detection_scores".to_string(), ArrayD::<f32>::zeros(IxDyn(&[1, 100]))),
+            ("num_detections".to_string(), ArrayD::<f32>::zeros(IxDyn(&[1]))),
+            ("raw_detection_boxes".to_string(), ArrayD::<f32>::zeros(IxDyn(&[1, 1917, 4]))),
+        ];
+        assert_eq!(ModelSSDOrt::select_outputs(&arrays).unwrap(), [0, 1, 3, 4]);
+
+        let unnamed: Vec<_> = (0..4)
+            .map(|i| (format!("StatefulPartitionedCall:{}", i), ArrayD::<f32>::zeros(IxDyn(&[1]))))
+            .collect();
+        assert_eq!(ModelSSDOrt::select_outputs(&unnamed).unwrap(), [0, 1, 2, 3]);
+
+        assert!(ModelSSDOrt::select_outputs(&unnamed[..2]).is_err());
+    }
+}
@@ -1,19 +1,18 @@
 //! Ultralytics YOLO models (v8, v9, v11) using ONNX Runtime.
 
-use ort::session::{Session, builder::GraphOptimizationLevel};
+use ort::session::Session;
+use ndarray::{Axis, Slice};
 use ort::inputs;
-use ort::value::TensorRef;
-
-#[cfg(feature = "ort-cuda-backend")]
-use ort::execution_providers::CUDAExecutionProvider;
-
-#[cfg(feature = "ort-tensorrt-backend")]
-use ort::execution_providers::TensorRTExecutionProvider;
 
 use crate::bbox::BBox;
+use crate::model_trait::DetectionVecs;
 use crate::image_buffer::ImageBuffer;
-use crate::postprocess::{Detection, nms, filter_by_class, detections_to_vecs, argmax};
-use crate::preprocessing::{preprocess, PreprocessMeta};
+use crate::postprocess::{Detection, NmsStrategy, filter_by_class, detections_to_vecs, argmax};
+use crate::preprocessing::{preprocess, preprocess_batch, preprocess_letterbox_auto, PreprocessMeta};
+
+use super::metadata::{UltralyticsMetadata, DEFAULT_INPUT_SIZE};
+use super::tensor_names::TensorNames;
+use super::tensor::{accepts_batch, extract_output, input_element_type, input_value};
 
 /// Error type for ORT model operations.
 #[derive(Debug)]
@@ -24,6 +23,12 @@ pub enum OrtModelError {
     InvalidOutputShape(String),
     /// Preprocessing error
     PreprocessingError(String),
+    /// Invalid or unreadable text embeddings (open-vocabulary models)
+    InvalidEmbeddings(String),
+    /// No decoder matches the model inputs/outputs
+    UnsupportedModel(String),
+    /// Input or output tensor name not found in the model
+    InvalidTensorName(String),
 }
 
 impl std::fmt::Display for OrtModelError {
@@ -32,6 +37,9 @@ impl std::fmt::Display for OrtModelError {
             OrtModelError::Ort(e) => write!(f, "ORT error: {}", e),
             OrtModelError::InvalidOutputShape(s) => write!(f, "Invalid output shape: {}", s),
             OrtModelError::PreprocessingError(s) => write!(f, "Preprocessing error: {}", s),
+            OrtModelError::InvalidEmbeddings(s) => write!(f, "Invalid text embeddings: {}", s),
+            OrtModelError::UnsupportedModel(s) => write!(f, "Unsupported model: {}", s),
+            OrtModelError::InvalidTensorName(s) => write!(f, "Invalid tensor name: {}", s),
         }
     }
 }
@@ -47,12 +55,21 @@ impl From<ort::Error> for OrtModelError {
 /// Ultralytics YOLO model (v8, v9, v11) using ONNX Runtime.
 ///
 /// This model supports YOLOv8, v9, and v11 which share the same output format.
+///
+/// Metadata embedded by Ultralytics exports (class names, image size, task, ...) is
+/// read at load time, see [`Self::metadata`].
 pub struct ModelUltralyticsOrt {
     session: Session,
+    tensor_names: TensorNames,
     input_width: u32,
     input_height: u32,
     class_filters: Vec<usize>,
+    nms_strategy: NmsStrategy,
     use_letterbox: bool,
+    transposed: bool,
+    dynamic_input: bool,
+    multi_label: bool,
+    metadata: UltralyticsMetadata,
 }
 
 impl ModelUltralyticsOrt {
@@ -60,36 +77,26 @@ impl ModelUltralyticsOrt {
     ///
     /// # Arguments
     /// * `model_path` - Path to the ONNX model file
-    /// * `input_size` - Model input size as (width, height)
+    /// * `input_size` - Model input size as (width, height), or `None` to use the
+    ///   `imgsz` metadata of the export (640x640 if absent)
     /// * `class_filters` - List of class indices to detect (empty for all classes)
     ///
     /// # Example
     /// ```ignore
     /// let model = ModelUltralyticsOrt::new_from_file(
     ///     "yolov8n.onnx",
-    ///     (640, 640),
+    ///     None,    // input size from metadata
     ///     vec![],  // detect all classes
     /// )?;
+    /// println!("{:?}", model.class_names());
     /// ```
     pub fn new_from_file(
         model_path: &str,
-        input_size: (u32, u32),
+        input_size: impl Into<Option<(u32, u32)>>,
         class_filters: Vec<usize>,
     ) -> Result<Self, OrtModelError> {
-        let session = Session::builder()?
-            .with_optimization_level(GraphOptimizationLevel::Level3)?
-            .commit_from_file(model_path)?;
-
-        Ok(Self {
-            session,
-            input_width: input_size.0,
-            input_height: input_size.1,
-            class_filters,
-            #[cfg(feature = "letterbox")]
-            use_letterbox: true,
-            #[cfg(not(feature = "letterbox"))]
-            use_letterbox: false,
-        })
+        let session = super::session::session_from_file(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
     }
 
     /// Creates a new model from an ONNX file with CUDA acceleration.
@@ -98,24 +105,11 @@ impl ModelUltralyticsOrt {
     #[cfg(feature = "ort-cuda-backend")]
     pub fn new_from_file_cuda(
         model_path: &str,
-        input_size: (u32, u32),
+        input_size: impl Into<Option<(u32, u32)>>,
         class_filters: Vec<usize>,
     ) -> Result<Self, OrtModelError> {
-        let session = Session::builder()?
-            .with_execution_providers([CUDAExecutionProvider::default().build()])?
-            .with_optimization_level(GraphOptimizationLevel::Level3)?
-            .commit_from_file(model_path)?;
-
-        Ok(Self {
-            session,
-            input_width: input_size.0,
-            input_height: input_size.1,
-            class_filters,
-            #[cfg(feature = "letterbox")]
-            use_letterbox: true,
-            #[cfg(not(feature = "letterbox"))]
-            use_letterbox: false,
-        })
+        let session = super::session::session_from_file_cuda(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
     }
 
     /// Creates a new model from an ONNX file with TensorRT acceleration.
@@ -124,49 +118,78 @@ impl ModelUltralyticsOrt {
     #[cfg(feature = "ort-tensorrt-backend")]
     pub fn new_from_file_tensorrt(
         model_path: &str,
-        input_size: (u32, u32),
+        input_size: impl Into<Option<(u32, u32)>>,
         class_filters: Vec<usize>,
     ) -> Result<Self, OrtModelError> {
-        let session = Session::builder()?
-            .with_execution_providers([TensorRTExecutionProvider::default().build()])?
-            .with_optimization_level(GraphOptimizationLevel::Level3)?
-            .commit_from_file(model_path)?;
-
-        Ok(Self {
-            session,
-            input_width: input_size.0,
-            input_height: input_size.1,
-            class_filters,
-            #[cfg(feature = "letterbox")]
-            use_letterbox: true,
-            #[cfg(not(feature = "letterbox"))]
-            use_letterbox: false,
-        })
+        let session = super::session::session_from_file_tensorrt(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
     }
 
     /// Creates a new model with custom session options.
     ///
     /// # Arguments
     /// * `session` - Pre-configured ORT session
-    /// * `input_size` - Model input size as (width, height)
+    /// * `input_size` - Model input size as (width, height), or `None` to use the metadata
     /// * `class_filters` - List of class indices to detect
     pub fn from_session(
         session: Session,
-        input_size: (u32, u32),
+        input_size: impl Into<Option<(u32, u32)>>,
         class_filters: Vec<usize>,
     ) -> Self {
+        let tensor_names = TensorNames::from_session(&session, 1);
+        let metadata = UltralyticsMetadata::from_session(&session);
+        let input_size: Option<(u32, u32)> = input_size.into();
+        let input_size = input_size.or(metadata.input_size).unwrap_or(DEFAULT_INPUT_SIZE);
         Self {
             session,
+            tensor_names,
             input_width: input_size.0,
             input_height: input_size.1,
             class_filters,
+            nms_strategy: NmsStrategy::default(),
             #[cfg(feature = "letterbox")]
             use_letterbox: true,
             #[cfg(not(feature = "letterbox"))]
             use_letterbox: false,
+            transposed: false,
+            dynamic_input: false,
+            multi_label: false,
+            metadata,
         }
     }
 
+    /// Creates a new model from an ONNX file with custom tensor names.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn new_from_file_with_names(
+        model_path: &str,
+        input_size: impl Into<Option<(u32, u32)>>,
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Self::from_session_with_names(session, input_size, class_filters, tensor_names)
+    }
+
+    /// Creates a new model with custom session options and tensor names.
+    ///
+    /// The model reads one output.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn from_session_with_names(
+        session: Session,
+        input_size: impl Into<Option<(u32, u32)>>,
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        tensor_names.validate(&session, 1)?;
+        let mut model = Self::from_session(session, input_size, class_filters);
+        model.tensor_names = tensor_names;
+        Ok(model)
+    }
+
     /// Enables or disables letterbox preprocessing.
     ///
     /// Letterbox preserves aspect ratio by padding with gray.
@@ -175,11 +198,72 @@ impl ModelUltralyticsOrt {
         self.use_letterbox = enabled;
     }
 
+    /// Sets the NMS algorithm applied to the detections.
+    ///
+    /// Default is [`NmsStrategy::Greedy`].
+    pub fn set_nms_strategy(&mut self, strategy: NmsStrategy) {
+        self.nms_strategy = strategy;
+    }
+
+    /// Enables or disables per-image input sizes for models exported with dynamic axes.
+    ///
+    /// When enabled, each image is letterboxed to the smallest rectangle fitting in the
+    /// input size whose sides are multiples of [`Self::stride`] (e.g. 640x384 instead of
+    /// 640x640 for a 16:9 frame), which saves the compute spent on padding.
+    /// The letterbox setting is ignored in this mode. Default is `false`.
+    pub fn set_dynamic_input(&mut self, enabled: bool) {
+        self.dynamic_input = enabled;
+    }
+
+    /// Enables or disables multi-label output.
+    ///
+    /// When enabled, every class scoring above the confidence threshold produces its own
+    /// detection (e.g. `person` and `worker` for the same box) and NMS is applied per class,
+    /// so overlapping labels of one object are all kept. Default is `false` (best class only).
+    pub fn set_multi_label(&mut self, enabled: bool) {
+        self.multi_label = enabled;
+    }
+
+    /// Sets whether the output is row-major (`[1, N, 4 + num_classes]`).
+    ///
+    /// Some exports transpose the default channel-first `[1, 4 + num_classes, N]` output.
+    /// Default is `false`.
+    pub fn set_transposed(&mut self, transposed: bool) {
+        self.transposed = transposed;
+    }
+
     /// Returns the input size (width, height).
     pub fn input_size(&self) -> (u32, u32) {
         (self.input_width, self.input_height)
     }
 
+    /// Returns the input and output tensor names.
+    pub fn tensor_names(&self) -> &TensorNames {
+        &self.tensor_names
+    }
+
+    /// Returns the metadata embedded in the export.
+    pub fn metadata(&self) -> &UltralyticsMetadata {
+        &self.metadata
+    }
+
+    /// Returns the class names from the export metadata, indexed by class ID.
+    ///
+    /// Empty if the model has no `names` metadata.
+    pub fn class_names(&self) -> &[String] {
+        &self.metadata.class_names
+    }
+
+    /// Returns the maximum stride of the model, from the export metadata (32 if absent).
+    pub fn stride(&self) -> u32 {
+        self.metadata.stride.unwrap_or(32)
+    }
+
+    /// Returns the task from the export metadata (`detect`, `segment`, `pose`, `obb`, `classify`).
+    pub fn task(&self) -> Option<&str> {
+        self.metadata.task.as_deref()
+    }
+
     /// Runs inference on an image.
     ///
     /// # Arguments
@@ -194,46 +278,132 @@ impl ModelUltralyticsOrt {
         image: &ImageBuffer,
         conf_threshold: f32,
         nms_threshold: f32,
-    ) -> Result<(Vec<BBox>, Vec<usize>, Vec<f32>), OrtModelError> {
+    ) -> Result<DetectionVecs, OrtModelError> {
         // Preprocess
-        let (tensor, meta) = preprocess(
-            image,
-            self.input_width,
-            self.input_height,
-            self.use_letterbox,
-        );
-
-        // Run inference using TensorRef (no copy)
+        let (tensor, meta) = if self.dynamic_input {
+            preprocess_letterbox_auto(image, self.input_width, self.input_height, self.stride())
+        } else {
+            preprocess(image, self.input_width, self.input_height, self.use_letterbox)
+        };
+
+        // Run inference (f32 inputs are passed without copy)
+        let input_type = input_element_type(&self.session, self.tensor_names.input());
         let outputs = self.session.run(
-            inputs!["images" => TensorRef::from_array_view(&tensor)?]
+            inputs![self.tensor_names.input() => input_value(&tensor, input_type)?]
         )?;
 
         // Get output tensor by name and extract as owned ndarray
-        let output = outputs["output0"]
-            .try_extract_array::<f32>()?
-            .into_owned();  // Make owned copy to avoid borrow conflict
+        let output = extract_output(&outputs, self.tensor_names.output(0))?;
 
         // Copy class filters to avoid borrow conflict
         let class_filters = self.class_filters.clone();
 
         // Parse output based on shape
         // YOLOv8/v9/v11 output shape: [1, 84, num_predictions] or [1, num_classes+4, num_predictions]
-        let detections = Self::parse_output_array_static(&output.view(), conf_threshold, &meta)?;
+        let mut output = output.view();
+        if self.transposed && output.ndim() == 3 {
+            output.swap_axes(1, 2);
+        }
+        let detections = Self::parse_output_array_static(&output, conf_threshold, &meta, self.multi_label)?;
 
         // Apply class filter
         let filtered = filter_by_class(&detections, &class_filters);
 
         // Apply NMS
-        let final_detections = nms(&filtered, nms_threshold);
+        let final_detections = Self::suppress(&self.nms_strategy, self.multi_label, &filtered, nms_threshold, conf_threshold);
 
         Ok(detections_to_vecs(final_detections))
     }
 
+    /// Runs inference on several images at once.
+    ///
+    /// The images are stacked into one `[B, 3, H, W]` input when the model batch axis is
+    /// dynamic (or matches the number of images). Models with a fixed batch size, and
+    /// the dynamic input mode where every image has its own size, fall back to one
+    /// inference per image.
+    ///
+    /// # Returns
+    /// One tuple of (bounding boxes, class IDs, confidence scores) per image, in input order
+    pub fn forward_batch(
+        &mut self,
+        images: &[ImageBuffer],
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<Vec<DetectionVecs>, OrtModelError> {
+        if images.len() < 2
+            || self.dynamic_input
+            || !accepts_batch(&self.session, self.tensor_names.input(), images.len())
+        {
+            return images
+                .iter()
+                .map(|image| self.forward(image, conf_threshold, nms_threshold))
+                .collect();
+        }
+
+        let (tensor, metas) = preprocess_batch(
+            images,
+            self.input_width,
+            self.input_height,
+            self.use_letterbox,
+        );
+
+        let input_type = input_element_type(&self.session, self.tensor_names.input());
+        let outputs = self.session.run(
+            inputs![self.tensor_names.input() => input_value(&tensor, input_type)?]
+        )?;
+        let output = extract_output(&outputs, self.tensor_names.output(0))?;
+
+        let mut output = output.view();
+        if self.transposed && output.ndim() == 3 {
+            output.swap_axes(1, 2);
+        }
+        if output.ndim() != 3 || output.shape()[0] != images.len() {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected shape [{}, C, N], got {:?}",
+                images.len(),
+                output.shape()
+            )));
+        }
+
+        metas
+            .iter()
+            .enumerate()
+            .map(|(i, meta)| {
+                let single = output.slice_axis(Axis(0), Slice::from(i..i + 1));
+                let detections = Self::parse_output_array_static(&single, conf_threshold, meta, self.multi_label)?;
+                let filtered = filter_by_class(&detections, &self.class_filters);
+                Ok(detections_to_vecs(Self::suppress(&self.nms_strategy, self.multi_label, &filtered, nms_threshold, conf_threshold)))
+            })
+            .collect()
+    }
+
+    /// Applies the NMS strategy: per class in multi-label mode, over all detections otherwise.
+    fn suppress(
+        strategy: &NmsStrategy,
+        multi_label: bool,
+        detections: &[Detection],
+        nms_threshold: f32,
+        conf_threshold: f32,
+    ) -> Vec<Detection> {
+        let kept = if multi_label {
+            strategy.apply_per_class_indices(detections, nms_threshold, conf_threshold)
+        } else {
+            strategy.apply_indices(detections, nms_threshold, conf_threshold)
+        };
+        kept.into_iter()
+            .map(|(idx, confidence)| Detection::new(detections[idx].bbox, detections[idx].class_id, confidence))
+            .collect()
+    }
+
     /// Parses the model output array into detections (static method).
+    ///
+    /// With `multi_label` set, every class above `conf_threshold` yields a detection,
+    /// otherwise only the best class of each prediction does.
     fn parse_output_array_static(
         output: &ndarray::ArrayViewD<f32>,
         conf_threshold: f32,
         meta: &PreprocessMeta,
+        multi_label: bool,
     ) -> Result<Vec<Detection>, OrtModelError> {
         let shape = output.shape();
 
@@ -264,16 +434,32 @@ impl ModelUltralyticsOrt {
                 .map(|j| output[[0, j, i]])
                 .collect();
 
-            // Find best class
-            if let Some((class_idx, max_score)) = argmax(&class_scores) {
-                if max_score >= conf_threshold {
-                    // Transform coordinates back to original image space
+            if multi_label {
+                // Every class above the threshold is a separate detection
+                let bbox = {
                     let (x_orig, y_orig, w_orig, h_orig) = meta.inverse_transform(cx, cy, w, h);
+                    BBox::from_center(x_orig, y_orig, w_orig, h_orig)
+                };
+                detections.extend(
+                    class_scores
+                        .iter()
+                        .enumerate()
+                        .filter(|&(_, &score)| score >= conf_threshold)
+                        .map(|(class_idx, &score)| Detection::new(bbox, class_idx, score)),
+                );
+                continue;
+            }
+
+            // Find best class
+            if let Some((class_idx, max_score)) = argmax(&class_scores)
+                && max_score >= conf_threshold
+            {
+                // Transform coordinates back to original image space
+                let (x_orig, y_orig, w_orig, h_orig) = meta.inverse_transform(cx, cy, w, h);
 
-                    let bbox = BBox::from_center(x_orig, y_orig, w_orig, h_orig);
+                let bbox = BBox::from_center(x_orig, y_orig, w_orig, h_orig);
 
-                    detections.push(Detection::new(bbox, class_idx, max_score));
-                }
+                detections.push(Detection::new(bbox, class_idx, max_score));
             }
         }
 
@@ -290,9 +476,18 @@ impl crate::ObjectDetector for ModelUltralyticsOrt {
         input: &Self::Input,
         conf_threshold: f32,
         nms_threshold: f32,
-    ) -> Result<(Vec<BBox>, Vec<usize>, Vec<f32>), Self::Error> {
+    ) -> Result<DetectionVecs, Self::Error> {
         self.forward(input, conf_threshold, nms_threshold)
     }
+
+    fn detect_batch(
+        &mut self,
+        inputs: &[Self::Input],
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<Vec<DetectionVecs>, Self::Error> {
+        self.forward_batch(inputs, conf_threshold, nms_threshold)
+    }
 }
 
 // OpenCV compatibility: implement ModelTrait for Mat input
@@ -322,18 +517,28 @@ mod opencv_compat_impl {
             image: &Mat,
             conf_threshold: f32,
             nms_threshold: f32,
-        ) -> Result<(Vec<Rect>, Vec<usize>, Vec<f32>), OpenCvError> {
+        ) -> Result<DetectionVecs<Rect>, OpenCvError> {
             // Use optimized preprocessing: OpenCV resize + fused BGR→RGB conversion
-            let (tensor, meta) = crate::opencv_compat::preprocess_mat(
-                image,
-                self.input_width,
-                self.input_height,
-                self.use_letterbox,
-            )?;
+            let (tensor, meta) = if self.dynamic_input {
+                crate::opencv_compat::preprocess_mat_letterbox_auto(
+                    image,
+                    self.input_width,
+                    self.input_height,
+                    self.stride(),
+                )?
+            } else {
+                crate::opencv_compat::preprocess_mat(
+                    image,
+                    self.input_width,
+                    self.input_height,
+                    self.use_letterbox,
+                )?
+            };
 
             // Run inference
+            let input_type = input_element_type(&self.session, self.tensor_names.input());
             let outputs = self.session.run(
-                inputs!["images" => TensorRef::from_array_view(&tensor).map_err(|e| {
+                inputs![self.tensor_names.input() => input_value(&tensor, input_type).map_err(|e| {
                     OpenCvError::new(opencv::core::StsError, format!("ORT error: {}", e))
                 })?]
             ).map_err(|e| {
@@ -341,15 +546,17 @@ mod opencv_compat_impl {
             })?;
 
             // Get output tensor
-            let output = outputs["output0"]
-                .try_extract_array::<f32>()
+            let output = extract_output(&outputs, self.tensor_names.output(0))
                 .map_err(|e| {
                     OpenCvError::new(opencv::core::StsError, format!("Output extraction error: {}", e))
-                })?
-                .into_owned();
+                })?;
 
             // Parse output
-            let detections = Self::parse_output_array_static(&output.view(), conf_threshold, &meta)
+            let mut output = output.view();
+            if self.transposed && output.ndim() == 3 {
+                output.swap_axes(1, 2);
+            }
+            let detections = Self::parse_output_array_static(&output, conf_threshold, &meta, self.multi_label)
                 .map_err(|e| {
                     OpenCvError::new(opencv::core::StsError, format!("Parse error: {}", e))
                 })?;
@@ -359,7 +566,7 @@ mod opencv_compat_impl {
             let filtered = filter_by_class(&detections, &class_filters);
 
             // Apply NMS
-            let final_detections = nms(&filtered, nms_threshold);
+            let final_detections = Self::suppress(&self.nms_strategy, self.multi_label, &filtered, nms_threshold, conf_threshold);
 
             // Convert to OpenCV format
             let (bboxes, class_ids, confidences) = detections_to_vecs(final_detections);
@@ -382,7 +589,7 @@ mod opencv_compat_impl {
             image: &Mat,
             conf_threshold: f32,
             nms_threshold: f32,
-        ) -> Result<(Vec<Rect>, Vec<usize>, Vec<f32>), OpenCvError> {
+        ) -> Result<DetectionVecs<Rect>, OpenCvError> {
             self.forward_mat(image, conf_threshold, nms_threshold)
         }
     }
@@ -391,6 +598,7 @@ mod opencv_compat_impl {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::postprocess::{nms, nms_class_aware};
 
     #[test]
     fn test_model_creation_error() {
@@ -402,4 +610,25 @@ mod tests {
         );
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_parse_output_multi_label() {
+        // One prediction scoring 0.9 for class 0 and 0.6 for class 2, one below threshold
+        let mut output = ndarray::Array3::<f32>::zeros((1, 7, 2));
+        for (j, value) in [100.0, 100.0, 50.0, 50.0, 0.9, 0.1, 0.6].into_iter().enumerate() {
+            output[[0, j, 0]] = value;
+        }
+        output[[0, 4, 1]] = 0.1;
+        let output = output.into_dyn();
+
+        let single = ModelUltralyticsOrt::parse_output_array_static(&output.view(), 0.5, &PreprocessMeta::identity(640, 640), false).unwrap();
+        assert_eq!(single.len(), 1);
+        assert_eq!(single[0].class_id, 0);
+
+        let multi = ModelUltralyticsOrt::parse_output_array_static(&output.view(), 0.5, &PreprocessMeta::identity(640, 640), true).unwrap();
+        let labels: Vec<(usize, f32)> = multi.iter().map(|d| (d.class_id, d.confidence)).collect();
+        assert_eq!(labels, vec![(0, 0.9), (2, 0.6)]);
+        assert_eq!(nms_class_aware(&multi, 0.45).len(), 2);
+        assert_eq!(nms(&multi, 0.45).len(), 1);
+    }
 }
@@ -0,0 +1,331 @@
+//! YOLOv10 (NMS-free) models using ONNX Runtime.
+
+use ort::session::Session;
+use ort::inputs;
+
+use crate::bbox::BBox;
+use crate::model_trait::DetectionVecs;
+use crate::image_buffer::ImageBuffer;
+use crate::postprocess::{Detection, filter_by_class, detections_to_vecs};
+use crate::preprocessing::{preprocess, PreprocessMeta};
+
+use super::OrtModelError;
+use super::metadata::UltralyticsMetadata;
+use super::tensor_names::TensorNames;
+use super::tensor::{extract_output, input_element_type, input_value};
+
+/// YOLOv10 model using ONNX Runtime.
+///
+/// YOLOv10 is NMS-free: the network already selects the final detections and
+/// emits them as `[1, 300, 6]` rows of `(x1, y1, x2, y2, score, class_id)` in
+/// input space. Only the confidence threshold, class filter and inverse
+/// letterbox/stretch transform are applied on the Rust side.
+pub struct ModelYOLOv10Ort {
+    session: Session,
+    tensor_names: TensorNames,
+    metadata: UltralyticsMetadata,
+    input_width: u32,
+    input_height: u32,
+    class_filters: Vec<usize>,
+    use_letterbox: bool,
+}
+
+impl ModelYOLOv10Ort {
+    /// Creates a new model from an ONNX file.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let model = ModelYOLOv10Ort::new_from_file(
+    ///     "yolov10n.onnx",
+    ///     (640, 640),
+    ///     vec![],  // detect all classes
+    /// )?;
+    /// ```
+    pub fn new_from_file(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with CUDA acceleration.
+    ///
+    /// Requires the `ort-cuda-backend` feature.
+    #[cfg(feature = "ort-cuda-backend")]
+    pub fn new_from_file_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_cuda(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with TensorRT acceleration.
+    ///
+    /// Requires the `ort-tensorrt-backend` feature.
+    #[cfg(feature = "ort-tensorrt-backend")]
+    pub fn new_from_file_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_tensorrt(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model with custom session options.
+    ///
+    /// # Arguments
+    /// * `session` - Pre-configured ORT session
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect
+    pub fn from_session(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Self {
+        let tensor_names = TensorNames::from_session(&session, 1);
+        let metadata = UltralyticsMetadata::from_session(&session);
+        Self {
+            session,
+            tensor_names,
+            metadata,
+            input_width: input_size.0,
+            input_height: input_size.1,
+            class_filters,
+            #[cfg(feature = "letterbox")]
+            use_letterbox: true,
+            #[cfg(not(feature = "letterbox"))]
+            use_letterbox: false,
+        }
+    }
+
+    /// Creates a new model from an ONNX file with custom tensor names.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn new_from_file_with_names(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Self::from_session_with_names(session, input_size, class_filters, tensor_names)
+    }
+
+    /// Creates a new model with custom session options and tensor names.
+    ///
+    /// The model reads one output.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn from_session_with_names(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        tensor_names.validate(&session, 1)?;
+        let mut model = Self::from_session(session, input_size, class_filters);
+        model.tensor_names = tensor_names;
+        Ok(model)
+    }
+
+    /// Enables or disables letterbox preprocessing.
+    ///
+    /// Letterbox preserves aspect ratio by padding with gray.
+    /// Default is `false` (stretch mode).
+    pub fn set_letterbox(&mut self, enabled: bool) {
+        self.use_letterbox = enabled;
+    }
+
+    /// Returns the input size (width, height).
+    pub fn input_size(&self) -> (u32, u32) {
+        (self.input_width, self.input_height)
+    }
+
+    /// Returns the input and output tensor names.
+    pub fn tensor_names(&self) -> &TensorNames {
+        &self.tensor_names
+    }
+
+    /// Returns the metadata embedded in the export.
+    pub fn metadata(&self) -> &UltralyticsMetadata {
+        &self.metadata
+    }
+
+    /// Returns the class names from the export metadata, indexed by class ID.
+    ///
+    /// Empty if the model has no `names` metadata.
+    pub fn class_names(&self) -> &[String] {
+        &self.metadata.class_names
+    }
+
+    /// Runs inference on an image.
+    ///
+    /// No NMS is performed since YOLOv10 outputs final detections.
+    ///
+    /// # Arguments
+    /// * `image` - Input image buffer
+    /// * `conf_threshold` - Confidence threshold (0.0 - 1.0)
+    ///
+    /// # Returns
+    /// Tuple of (bounding boxes, class IDs, confidence scores)
+    pub fn forward(
+        &mut self,
+        image: &ImageBuffer,
+        conf_threshold: f32,
+    ) -> Result<DetectionVecs, OrtModelError> {
+        let (tensor, meta) = preprocess(
+            image,
+            self.input_width,
+            self.input_height,
+            self.use_letterbox,
+        );
+
+        let input_type = input_element_type(&self.session, self.tensor_names.input());
+        let outputs = self.session.run(
+            inputs![self.tensor_names.input() => input_value(&tensor, input_type)?]
+        )?;
+
+        let output = extract_output(&outputs, self.tensor_names.output(0))?;
+
+        let detections = Self::parse_output_array_static(&output.view(), conf_threshold, &meta)?;
+        let filtered = filter_by_class(&detections, &self.class_filters);
+
+        Ok(detections_to_vecs(filtered))
+    }
+
+    /// Parses the `[1, N, 6]` model output into detections.
+    fn parse_output_array_static(
+        output: &ndarray::ArrayViewD<f32>,
+        conf_threshold: f32,
+        meta: &PreprocessMeta,
+    ) -> Result<Vec<Detection>, OrtModelError> {
+        let shape = output.shape();
+
+        if shape.len() != 3 || shape[0] != 1 || shape[2] != 6 {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected shape [1, N, 6], got {:?}",
+                shape
+            )));
+        }
+
+        let num_predictions = shape[1];
+        let mut detections = Vec::new();
+
+        for i in 0..num_predictions {
+            let score = output[[0, i, 4]];
+            // Rows are sorted by score, but do not rely on it
+            if score < conf_threshold {
+                continue;
+            }
+
+            let x1 = output[[0, i, 0]];
+            let y1 = output[[0, i, 1]];
+            let x2 = output[[0, i, 2]];
+            let y2 = output[[0, i, 3]];
+            let class_id = output[[0, i, 5]].round().max(0.0) as usize;
+
+            let (cx, cy, w, h) = meta.inverse_transform(
+                (x1 + x2) / 2.0,
+                (y1 + y2) / 2.0,
+                x2 - x1,
+                y2 - y1,
+            );
+
+            detections.push(Detection::new(BBox::from_center(cx, cy, w, h), class_id, score));
+        }
+
+        Ok(detections)
+    }
+}
+
+impl crate::ObjectDetector for ModelYOLOv10Ort {
+    type Input = ImageBuffer;
+    type Error = OrtModelError;
+
+    /// Runs detection. `nms_threshold` is ignored since YOLOv10 is NMS-free.
+    fn detect(
+        &mut self,
+        input: &Self::Input,
+        conf_threshold: f32,
+        _nms_threshold: f32,
+    ) -> Result<DetectionVecs, Self::Error> {
+        self.forward(input, conf_threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::preprocessing::LetterboxMeta;
+    use ndarray::Array3;
+
+    #[test]
+    fn test_parse_output_stretch() {
+        let mut output = Array3::<f32>::zeros((1, 3, 6));
+        // Kept: class 16 with score 0.9
+        output[[0, 0, 0]] = 10.0;
+        output[[0, 0, 1]] = 20.0;
+        output[[0, 0, 2]] = 110.0;
+        output[[0, 0, 3]] = 220.0;
+        output[[0, 0, 4]] = 0.9;
+        output[[0, 0, 5]] = 16.0;
+        // Dropped: below threshold
+        output[[0, 1, 4]] = 0.1;
+
+        let meta = PreprocessMeta::stretch((1280, 640), (640, 640));
+
+        let detections =
+            ModelYOLOv10Ort::parse_output_array_static(&output.view().into_dyn(), 0.25, &meta).unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].class_id, 16);
+        assert_eq!(detections[0].bbox, BBox::new(20, 20, 200, 200));
+    }
+
+    #[test]
+    fn test_parse_output_letterbox() {
+        let mut output = Array3::<f32>::zeros((1, 1, 6));
+        output[[0, 0, 0]] = 10.0;
+        output[[0, 0, 1]] = 120.0;
+        output[[0, 0, 2]] = 60.0;
+        output[[0, 0, 3]] = 170.0;
+        output[[0, 0, 4]] = 0.5;
+
+        let meta = PreprocessMeta::Letterbox(LetterboxMeta {
+            scale: 0.5,
+            pad_left: 10,
+            pad_top: 20,
+            pad_right: 10,
+            pad_bottom: 20,
+            original_width: 640,
+            original_height: 480,
+        });
+
+        let detections =
+            ModelYOLOv10Ort::parse_output_array_static(&output.view().into_dyn(), 0.25, &meta).unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].bbox, BBox::new(0, 200, 100, 100));
+    }
+
+    #[test]
+    fn test_parse_output_invalid_shape() {
+        let output = Array3::<f32>::zeros((1, 84, 8400));
+        let meta = PreprocessMeta::identity(640, 640);
+
+        let result = ModelYOLOv10Ort::parse_output_array_static(&output.view().into_dyn(), 0.25, &meta);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,305 @@
+//! YOLOv5 / YOLOv7 models (objectness-style output) using ONNX Runtime.
+
+use ort::session::Session;
+use ort::inputs;
+
+use crate::bbox::BBox;
+use crate::model_trait::DetectionVecs;
+use crate::image_buffer::ImageBuffer;
+use crate::postprocess::{Detection, NmsStrategy, filter_by_class, detections_to_vecs, argmax};
+use crate::preprocessing::{preprocess, PreprocessMeta};
+
+use super::OrtModelError;
+use super::tensor_names::TensorNames;
+use super::tensor::{extract_output, input_element_type, input_value};
+
+/// YOLOv5 / YOLOv7 model using ONNX Runtime.
+///
+/// These PyTorch exports produce a row-major `[1, N, 5 + num_classes]` output where
+/// every row is `(cx, cy, w, h, objectness, class scores...)` in input space.
+/// The final confidence of a prediction is `objectness * class_score`.
+pub struct ModelYOLOv5Ort {
+    session: Session,
+    tensor_names: TensorNames,
+    input_width: u32,
+    input_height: u32,
+    class_filters: Vec<usize>,
+    nms_strategy: NmsStrategy,
+    use_letterbox: bool,
+}
+
+impl ModelYOLOv5Ort {
+    /// Creates a new model from an ONNX file.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let model = ModelYOLOv5Ort::new_from_file(
+    ///     "yolov5s.onnx",
+    ///     (640, 640),
+    ///     vec![],  // detect all classes
+    /// )?;
+    /// ```
+    pub fn new_from_file(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with CUDA acceleration.
+    ///
+    /// Requires the `ort-cuda-backend` feature.
+    #[cfg(feature = "ort-cuda-backend")]
+    pub fn new_from_file_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_cuda(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with TensorRT acceleration.
+    ///
+    /// Requires the `ort-tensorrt-backend` feature.
+    #[cfg(feature = "ort-tensorrt-backend")]
+    pub fn new_from_file_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_tensorrt(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model with custom session options.
+    ///
+    /// # Arguments
+    /// * `session` - Pre-configured ORT session
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect
+    pub fn from_session(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Self {
+        let tensor_names = TensorNames::from_session(&session, 1);
+        Self {
+            session,
+            tensor_names,
+            input_width: input_size.0,
+            input_height: input_size.1,
+            class_filters,
+            nms_strategy: NmsStrategy::default(),
+            #[cfg(feature = "letterbox")]
+            use_letterbox: true,
+            #[cfg(not(feature = "letterbox"))]
+            use_letterbox: false,
+        }
+    }
+
+    /// Creates a new model from an ONNX file with custom tensor names.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn new_from_file_with_names(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Self::from_session_with_names(session, input_size, class_filters, tensor_names)
+    }
+
+    /// Creates a new model with custom session options and tensor names.
+    ///
+    /// The model reads one output.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn from_session_with_names(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        tensor_names.validate(&session, 1)?;
+        let mut model = Self::from_session(session, input_size, class_filters);
+        model.tensor_names = tensor_names;
+        Ok(model)
+    }
+
+    /// Enables or disables letterbox preprocessing.
+    ///
+    /// Letterbox preserves aspect ratio by padding with gray.
+    /// Default is `false` (stretch mode).
+    pub fn set_letterbox(&mut self, enabled: bool) {
+        self.use_letterbox = enabled;
+    }
+
+    /// Sets the NMS algorithm applied to the detections.
+    ///
+    /// Default is [`NmsStrategy::Greedy`].
+    pub fn set_nms_strategy(&mut self, strategy: NmsStrategy) {
+        self.nms_strategy = strategy;
+    }
+
+    /// Returns the input size (width, height).
+    pub fn input_size(&self) -> (u32, u32) {
+        (self.input_width, self.input_height)
+    }
+
+    /// Returns the input and output tensor names.
+    pub fn tensor_names(&self) -> &TensorNames {
+        &self.tensor_names
+    }
+
+    /// Runs inference on an image.
+    ///
+    /// # Arguments
+    /// * `image` - Input image buffer
+    /// * `conf_threshold` - Confidence threshold (0.0 - 1.0)
+    /// * `nms_threshold` - NMS IoU threshold (0.0 - 1.0)
+    ///
+    /// # Returns
+    /// Tuple of (bounding boxes, class IDs, confidence scores)
+    pub fn forward(
+        &mut self,
+        image: &ImageBuffer,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs, OrtModelError> {
+        let (tensor, meta) = preprocess(
+            image,
+            self.input_width,
+            self.input_height,
+            self.use_letterbox,
+        );
+
+        let input_type = input_element_type(&self.session, self.tensor_names.input());
+        let outputs = self.session.run(
+            inputs![self.tensor_names.input() => input_value(&tensor, input_type)?]
+        )?;
+
+        let output = extract_output(&outputs, self.tensor_names.output(0))?;
+
+        let detections = Self::parse_output_array_static(&output.view(), conf_threshold, &meta)?;
+        let filtered = filter_by_class(&detections, &self.class_filters);
+        let final_detections = self.nms_strategy.apply(&filtered, nms_threshold, conf_threshold);
+
+        Ok(detections_to_vecs(final_detections))
+    }
+
+    /// Parses the `[1, N, 5 + num_classes]` model output into detections.
+    fn parse_output_array_static(
+        output: &ndarray::ArrayViewD<f32>,
+        conf_threshold: f32,
+        meta: &PreprocessMeta,
+    ) -> Result<Vec<Detection>, OrtModelError> {
+        let shape = output.shape();
+
+        if shape.len() != 3 || shape[0] != 1 || shape[2] < 6 {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected shape [1, N, 5 + num_classes], got {:?}",
+                shape
+            )));
+        }
+
+        let num_predictions = shape[1];
+        let num_features = shape[2];
+
+        let mut detections = Vec::new();
+
+        for i in 0..num_predictions {
+            let objectness = output[[0, i, 4]];
+            // obj * cls can never exceed obj, so most rows are rejected here cheaply
+            if objectness < conf_threshold {
+                continue;
+            }
+
+            let class_scores: Vec<f32> = (5..num_features)
+                .map(|j| output[[0, i, j]])
+                .collect();
+
+            if let Some((class_idx, class_score)) = argmax(&class_scores) {
+                let score = objectness * class_score;
+                if score < conf_threshold {
+                    continue;
+                }
+
+                let (cx, cy, w, h) = meta.inverse_transform(
+                    output[[0, i, 0]],
+                    output[[0, i, 1]],
+                    output[[0, i, 2]],
+                    output[[0, i, 3]],
+                );
+
+                detections.push(Detection::new(BBox::from_center(cx, cy, w, h), class_idx, score));
+            }
+        }
+
+        Ok(detections)
+    }
+}
+
+impl crate::ObjectDetector for ModelYOLOv5Ort {
+    type Input = ImageBuffer;
+    type Error = OrtModelError;
+
+    fn detect(
+        &mut self,
+        input: &Self::Input,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs, Self::Error> {
+        self.forward(input, conf_threshold, nms_threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::Array3;
+
+    #[test]
+    fn test_parse_output_objectness() {
+        // 3 predictions, 2 classes
+        let mut output = Array3::<f32>::zeros((1, 3, 7));
+        // High objectness and class score: 0.9 * 0.8 = 0.72
+        output[[0, 0, 0]] = 100.0;
+        output[[0, 0, 1]] = 100.0;
+        output[[0, 0, 2]] = 50.0;
+        output[[0, 0, 3]] = 50.0;
+        output[[0, 0, 4]] = 0.9;
+        output[[0, 0, 6]] = 0.8;
+        // High objectness but low class score: 0.9 * 0.2 = 0.18
+        output[[0, 1, 4]] = 0.9;
+        output[[0, 1, 5]] = 0.2;
+        // Low objectness
+        output[[0, 2, 4]] = 0.1;
+        output[[0, 2, 5]] = 1.0;
+
+        let detections =
+            ModelYOLOv5Ort::parse_output_array_static(&output.view().into_dyn(), 0.25, &PreprocessMeta::identity(640, 640)).unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].class_id, 1);
+        assert!((detections[0].confidence - 0.72).abs() < 0.001);
+        assert_eq!(detections[0].bbox, BBox::new(75, 75, 50, 50));
+    }
+
+    #[test]
+    fn test_parse_output_invalid_shape() {
+        let output = Array3::<f32>::zeros((1, 10, 5));
+        let result = ModelYOLOv5Ort::parse_output_array_static(&output.view().into_dyn(), 0.25, &PreprocessMeta::identity(640, 640));
+        assert!(result.is_err());
+    }
+}
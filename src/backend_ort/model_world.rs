@@ -0,0 +1,555 @@
+//! YOLO-World open-vocabulary models using ONNX Runtime.
+
+use std::path::Path;
+
+use ndarray::Array3;
+use ort::session::Session;
+use ort::inputs;
+
+use crate::bbox::BBox;
+use crate::model_trait::DetectionVecs;
+use crate::image_buffer::ImageBuffer;
+use crate::postprocess::{Detection, NmsStrategy, filter_by_class, detections_to_vecs, argmax};
+use crate::preprocessing::{preprocess, PreprocessMeta};
+
+use super::OrtModelError;
+use super::tensor::{extract_output, input_element_type, input_value};
+use super::tensor_names::TensorNames;
+
+/// Precomputed text embeddings of the classes to detect, as a `[1, K, D]` matrix.
+///
+/// Embeddings are produced offline (e.g. with the CLIP text encoder YOLO-World was
+/// trained with) and loaded from disk, no network access is involved.
+#[derive(Debug, Clone)]
+pub struct TextEmbeddings {
+    data: Array3<f32>,
+    class_names: Vec<String>,
+}
+
+impl TextEmbeddings {
+    /// Creates embeddings from a `[1, K, D]` matrix and the `K` matching class names.
+    pub fn new(data: Array3<f32>, class_names: Vec<String>) -> Result<Self, OrtModelError> {
+        let (batch, num_classes, dim) = data.dim();
+        if batch != 1 || num_classes == 0 || dim == 0 {
+            return Err(OrtModelError::InvalidEmbeddings(format!(
+                "Expected shape [1, K, D], got {:?}",
+                data.shape()
+            )));
+        }
+        if class_names.len() != num_classes {
+            return Err(OrtModelError::InvalidEmbeddings(format!(
+                "{} class names for {} embeddings",
+                class_names.len(),
+                num_classes
+            )));
+        }
+        Ok(Self { data, class_names })
+    }
+
+    /// Loads embeddings from a `.npy` file of little-endian `float32` (or `float64`)
+    /// values, with shape `[K, D]` or `[1, K, D]`.
+    pub fn from_npy<P: AsRef<Path>>(path: P, class_names: Vec<String>) -> Result<Self, OrtModelError> {
+        let bytes = read_file(path.as_ref())?;
+        let (shape, values) = parse_npy(&bytes)?;
+        let data = match shape.as_slice() {
+            &[num_classes, dim] | &[1, num_classes, dim] => Array3::from_shape_vec((1, num_classes, dim), values)
+                .map_err(|e| OrtModelError::InvalidEmbeddings(e.to_string()))?,
+            _ => {
+                return Err(OrtModelError::InvalidEmbeddings(format!(
+                    "Expected .npy shape [K, D] or [1, K, D], got {:?}",
+                    shape
+                )));
+            }
+        };
+        Self::new(data, class_names)
+    }
+
+    /// Loads embeddings from a raw file of `K * D` little-endian `float32` values,
+    /// where `K` is the number of class names.
+    pub fn from_raw_f32<P: AsRef<Path>>(path: P, dim: usize, class_names: Vec<String>) -> Result<Self, OrtModelError> {
+        let bytes = read_file(path.as_ref())?;
+        let expected = class_names.len() * dim * 4;
+        if bytes.len() != expected {
+            return Err(OrtModelError::InvalidEmbeddings(format!(
+                "Expected {} bytes for {} classes of dimension {}, got {}",
+                expected,
+                class_names.len(),
+                dim,
+                bytes.len()
+            )));
+        }
+        let values = bytes
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect();
+        let data = Array3::from_shape_vec((1, class_names.len(), dim), values)
+            .map_err(|e| OrtModelError::InvalidEmbeddings(e.to_string()))?;
+        Self::new(data, class_names)
+    }
+
+    /// Returns the number of classes `K`.
+    pub fn num_classes(&self) -> usize {
+        self.class_names.len()
+    }
+
+    /// Returns the embedding dimension `D`.
+    pub fn dim(&self) -> usize {
+        self.data.dim().2
+    }
+
+    /// Returns the class names, indexed by class ID.
+    pub fn class_names(&self) -> &[String] {
+        &self.class_names
+    }
+
+    /// Returns the `[1, K, D]` embedding matrix.
+    pub fn data(&self) -> &Array3<f32> {
+        &self.data
+    }
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, OrtModelError> {
+    std::fs::read(path).map_err(|e| OrtModelError::InvalidEmbeddings(format!("{}: {}", path.display(), e)))
+}
+
+/// Parses a C-ordered `.npy` buffer of `<f4` or `<f8` values into (shape, values).
+fn parse_npy(bytes: &[u8]) -> Result<(Vec<usize>, Vec<f32>), OrtModelError> {
+    let invalid = |reason: &str| OrtModelError::InvalidEmbeddings(format!("Invalid .npy file: {}", reason));
+
+    if bytes.len() < 10 || &bytes[..6] != b"\x93NUMPY" {
+        return Err(invalid("missing magic string"));
+    }
+    // Version 1.0 stores the header length on 2 bytes, later versions on 4
+    let (header_start, header_len) = match bytes[6] {
+        1 => (10, u16::from_le_bytes([bytes[8], bytes[9]]) as usize),
+        2 | 3 if bytes.len() >= 12 => (12, u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize),
+        _ => return Err(invalid("unsupported version")),
+    };
+    let data_start = header_start + header_len;
+    if bytes.len() < data_start {
+        return Err(invalid("truncated header"));
+    }
+    let header = std::str::from_utf8(&bytes[header_start..data_start]).map_err(|_| invalid("header is not UTF-8"))?;
+
+    let field = |key: &str| {
+        header
+            .find(key)
+            .map(|pos| header[pos + key.len()..].trim_start_matches([' ', ':']))
+    };
+
+    if field("'fortran_order'").is_some_and(|v| v.starts_with("True")) {
+        return Err(invalid("Fortran order is not supported"));
+    }
+
+    let descr = field("'descr'")
+        .and_then(|v| v.strip_prefix('\''))
+        .and_then(|v| v.split('\'').next())
+        .ok_or_else(|| invalid("missing descr"))?;
+
+    let shape: Vec<usize> = field("'shape'")
+        .and_then(|v| v.strip_prefix('('))
+        .and_then(|v| v.split(')').next())
+        .ok_or_else(|| invalid("missing shape"))?
+        .split(',')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(|v| v.parse::<usize>().map_err(|_| invalid("bad shape")))
+        .collect::<Result<_, _>>()?;
+
+    let data = &bytes[data_start..];
+    let count: usize = shape.iter().product();
+    let values: Vec<f32> = match descr {
+        "<f4" if data.len() == count * 4 => data
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect(),
+        "<f8" if data.len() == count * 8 => data
+            .chunks_exact(8)
+            .map(|b| f64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]) as f32)
+            .collect(),
+        "<f4" | "<f8" => return Err(invalid("data size does not match shape")),
+        _ => return Err(invalid(&format!("unsupported dtype {}", descr))),
+    };
+
+    Ok((shape, values))
+}
+
+/// YOLO-World model using ONNX Runtime.
+///
+/// The ONNX export takes two inputs: the `[1, 3, H, W]` image and a `[1, K, D]`
+/// text embedding matrix (usually named `txt_feats`). The output is the usual
+/// Ultralytics `[1, 4 + K, N]` layout, where class `i` is the `i`-th embedding.
+pub struct ModelYOLOWorldOrt {
+    session: Session,
+    tensor_names: TensorNames,
+    text_input_name: String,
+    input_width: u32,
+    input_height: u32,
+    embeddings: TextEmbeddings,
+    class_filters: Vec<usize>,
+    nms_strategy: NmsStrategy,
+    use_letterbox: bool,
+}
+
+impl ModelYOLOWorldOrt {
+    /// Creates a new model from an ONNX file.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `embeddings` - Text embeddings of the classes to detect
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let embeddings = TextEmbeddings::from_npy("classes.npy", vec!["helmet".into(), "vest".into()])?;
+    /// let model = ModelYOLOWorldOrt::new_from_file(
+    ///     "yolov8s-worldv2.onnx",
+    ///     (640, 640),
+    ///     embeddings,
+    ///     vec![],  // detect all classes
+    /// )?;
+    /// ```
+    pub fn new_from_file(
+        model_path: &str,
+        input_size: (u32, u32),
+        embeddings: TextEmbeddings,
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Ok(Self::from_session(session, input_size, embeddings, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with CUDA acceleration.
+    ///
+    /// Requires the `ort-cuda-backend` feature.
+    #[cfg(feature = "ort-cuda-backend")]
+    pub fn new_from_file_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+        embeddings: TextEmbeddings,
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_cuda(model_path)?;
+        Ok(Self::from_session(session, input_size, embeddings, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with TensorRT acceleration.
+    ///
+    /// Requires the `ort-tensorrt-backend` feature.
+    #[cfg(feature = "ort-tensorrt-backend")]
+    pub fn new_from_file_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+        embeddings: TextEmbeddings,
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_tensorrt(model_path)?;
+        Ok(Self::from_session(session, input_size, embeddings, class_filters))
+    }
+
+    /// Creates a new model with custom session options.
+    ///
+    /// # Arguments
+    /// * `session` - Pre-configured ORT session
+    /// * `input_size` - Model input size as (width, height)
+    /// * `embeddings` - Text embeddings of the classes to detect
+    /// * `class_filters` - List of class indices to detect
+    pub fn from_session(
+        session: Session,
+        input_size: (u32, u32),
+        embeddings: TextEmbeddings,
+        class_filters: Vec<usize>,
+    ) -> Self {
+        // The image is the 4-D input, the text embeddings the 3-D one
+        let find_input = |rank: usize, default: &str| {
+            session
+                .inputs
+                .iter()
+                .find(|input| input.input_type.tensor_shape().is_some_and(|shape| shape.len() == rank))
+                .map(|input| input.name.clone())
+                .unwrap_or_else(|| default.to_string())
+        };
+        let output_name = session
+            .outputs
+            .first()
+            .map(|output| output.name.clone())
+            .unwrap_or_else(|| "output0".to_string());
+        let tensor_names = TensorNames::new(&find_input(4, "images"), &[output_name.as_str()]);
+        let text_input_name = find_input(3, "txt_feats");
+        Self {
+            session,
+            tensor_names,
+            text_input_name,
+            input_width: input_size.0,
+            input_height: input_size.1,
+            embeddings,
+            class_filters,
+            nms_strategy: NmsStrategy::default(),
+            #[cfg(feature = "letterbox")]
+            use_letterbox: true,
+            #[cfg(not(feature = "letterbox"))]
+            use_letterbox: false,
+        }
+    }
+
+    /// Creates a new model from an ONNX file with custom tensor names.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn new_from_file_with_names(
+        model_path: &str,
+        input_size: (u32, u32),
+        embeddings: TextEmbeddings,
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+        text_input_name: &str,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Self::from_session_with_names(session, input_size, embeddings, class_filters, tensor_names, text_input_name)
+    }
+
+    /// Creates a new model with custom session options and tensor names.
+    ///
+    /// `tensor_names` holds the image input and the single output, `text_input_name`
+    /// the text embeddings input.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn from_session_with_names(
+        session: Session,
+        input_size: (u32, u32),
+        embeddings: TextEmbeddings,
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+        text_input_name: &str,
+    ) -> Result<Self, OrtModelError> {
+        tensor_names.validate(&session, 1)?;
+        if !session.inputs.iter().any(|input| input.name == text_input_name) {
+            return Err(OrtModelError::InvalidTensorName(format!(
+                "input `{}` not found, model inputs: {:?}",
+                text_input_name,
+                session.inputs.iter().map(|input| input.name.as_str()).collect::<Vec<_>>()
+            )));
+        }
+        let mut model = Self::from_session(session, input_size, embeddings, class_filters);
+        model.tensor_names = tensor_names;
+        model.text_input_name = text_input_name.to_string();
+        Ok(model)
+    }
+
+    /// Replaces the text embeddings, i.e. the set of classes to detect.
+    ///
+    /// Class filters refer to embedding indices and are kept as is.
+    pub fn set_embeddings(&mut self, embeddings: TextEmbeddings) {
+        self.embeddings = embeddings;
+    }
+
+    /// Returns the text embeddings.
+    pub fn embeddings(&self) -> &TextEmbeddings {
+        &self.embeddings
+    }
+
+    /// Returns the class names, indexed by class ID.
+    pub fn class_names(&self) -> &[String] {
+        self.embeddings.class_names()
+    }
+
+    /// Enables or disables letterbox preprocessing.
+    ///
+    /// Letterbox preserves aspect ratio by padding with gray.
+    /// Default is `false` (stretch mode).
+    pub fn set_letterbox(&mut self, enabled: bool) {
+        self.use_letterbox = enabled;
+    }
+
+    /// Sets the NMS algorithm applied to the detections.
+    ///
+    /// Default is [`NmsStrategy::Greedy`].
+    pub fn set_nms_strategy(&mut self, strategy: NmsStrategy) {
+        self.nms_strategy = strategy;
+    }
+
+    /// Returns the input size (width, height).
+    pub fn input_size(&self) -> (u32, u32) {
+        (self.input_width, self.input_height)
+    }
+
+    /// Returns the image input and output tensor names.
+    pub fn tensor_names(&self) -> &TensorNames {
+        &self.tensor_names
+    }
+
+    /// Returns the name of the text embeddings input.
+    pub fn text_input_name(&self) -> &str {
+        &self.text_input_name
+    }
+
+    /// Runs inference on an image.
+    ///
+    /// # Arguments
+    /// * `image` - Input image buffer
+    /// * `conf_threshold` - Confidence threshold (0.0 - 1.0)
+    /// * `nms_threshold` - NMS IoU threshold (0.0 - 1.0)
+    ///
+    /// # Returns
+    /// Tuple of (bounding boxes, class IDs, confidence scores)
+    pub fn forward(
+        &mut self,
+        image: &ImageBuffer,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs, OrtModelError> {
+        let (tensor, meta) = preprocess(
+            image,
+            self.input_width,
+            self.input_height,
+            self.use_letterbox,
+        );
+
+        let image_type = input_element_type(&self.session, self.tensor_names.input());
+        let text_type = input_element_type(&self.session, self.text_input_name.as_str());
+        let outputs = self.session.run(inputs![
+            self.tensor_names.input() => input_value(&tensor, image_type)?,
+            self.text_input_name.as_str() => input_value(self.embeddings.data(), text_type)?,
+        ])?;
+
+        let output = extract_output(&outputs, self.tensor_names.output(0))?;
+
+        let detections = Self::parse_output_array_static(
+            &output.view(),
+            self.embeddings.num_classes(),
+            conf_threshold,
+            &meta,
+        )?;
+        let filtered = filter_by_class(&detections, &self.class_filters);
+        let nms_result = self.nms_strategy.apply(&filtered, nms_threshold, conf_threshold);
+
+        Ok(detections_to_vecs(nms_result))
+    }
+
+    /// Parses the `[1, 4 + K, N]` output.
+    fn parse_output_array_static(
+        output: &ndarray::ArrayViewD<f32>,
+        num_classes: usize,
+        conf_threshold: f32,
+        meta: &PreprocessMeta,
+    ) -> Result<Vec<Detection>, OrtModelError> {
+        let shape = output.shape();
+
+        if shape.len() != 3 || shape[0] != 1 || shape[1] != 4 + num_classes {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected shape [1, {}, N] for {} classes, got {:?}",
+                4 + num_classes,
+                num_classes,
+                shape
+            )));
+        }
+
+        let num_predictions = shape[2];
+        let mut detections = Vec::new();
+
+        for i in 0..num_predictions {
+            let class_scores: Vec<f32> = (4..4 + num_classes)
+                .map(|j| output[[0, j, i]])
+                .collect();
+
+            if let Some((class_idx, max_score)) = argmax(&class_scores)
+                && max_score >= conf_threshold
+            {
+                let (cx, cy, w, h) = meta.inverse_transform(
+                    output[[0, 0, i]],
+                    output[[0, 1, i]],
+                    output[[0, 2, i]],
+                    output[[0, 3, i]],
+                );
+
+                detections.push(Detection::new(BBox::from_center(cx, cy, w, h), class_idx, max_score));
+            }
+        }
+
+        Ok(detections)
+    }
+}
+
+impl crate::ObjectDetector for ModelYOLOWorldOrt {
+    type Input = ImageBuffer;
+    type Error = OrtModelError;
+
+    fn detect(
+        &mut self,
+        input: &Self::Input,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs, Self::Error> {
+        self.forward(input, conf_threshold, nms_threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn npy_bytes(descr: &str, shape: &str, payload: &[u8]) -> Vec<u8> {
+        let mut header = format!("{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}", descr, shape);
+        // Header is padded with spaces and ends with a newline, total aligned to 64 bytes
+        while (10 + header.len() + 1) % 64 != 0 {
+            header.push(' ');
+        }
+        header.push('\n');
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    #[test]
+    fn test_parse_npy() {
+        let payload: Vec<u8> = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0].iter().flat_map(|v| v.to_le_bytes()).collect();
+        let (shape, values) = parse_npy(&npy_bytes("<f4", "(2, 3)", &payload)).unwrap();
+        assert_eq!(shape, vec![2, 3]);
+        assert_eq!(values, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let payload: Vec<u8> = [0.5f64, -1.5].iter().flat_map(|v| v.to_le_bytes()).collect();
+        let (shape, values) = parse_npy(&npy_bytes("<f8", "(1, 1, 2)", &payload)).unwrap();
+        assert_eq!(shape, vec![1, 1, 2]);
+        assert_eq!(values, vec![0.5, -1.5]);
+
+        assert!(parse_npy(&npy_bytes("<i4", "(2,)", &[0; 8])).is_err());
+        assert!(parse_npy(&npy_bytes("<f4", "(2, 3)", &[0; 8])).is_err());
+        assert!(parse_npy(b"not a npy file").is_err());
+    }
+
+    #[test]
+    fn test_text_embeddings_validation() {
+        let names = vec!["helmet".to_string(), "vest".to_string()];
+        let embeddings = TextEmbeddings::new(Array3::zeros((1, 2, 512)), names.clone()).unwrap();
+        assert_eq!(embeddings.num_classes(), 2);
+        assert_eq!(embeddings.dim(), 512);
+
+        assert!(TextEmbeddings::new(Array3::zeros((1, 3, 512)), names.clone()).is_err());
+        assert!(TextEmbeddings::new(Array3::zeros((2, 2, 512)), names).is_err());
+    }
+
+    #[test]
+    fn test_parse_output_k_classes() {
+        let meta = PreprocessMeta::identity(640, 640);
+
+        let mut output = Array3::<f32>::zeros((1, 7, 2));
+        output[[0, 0, 1]] = 100.0;
+        output[[0, 1, 1]] = 100.0;
+        output[[0, 2, 1]] = 20.0;
+        output[[0, 3, 1]] = 40.0;
+        output[[0, 6, 1]] = 0.7;
+
+        let detections =
+            ModelYOLOWorldOrt::parse_output_array_static(&output.view().into_dyn(), 3, 0.25, &meta).unwrap();
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].class_id, 2);
+        assert_eq!(detections[0].bbox, BBox::new(90, 80, 20, 40));
+
+        // Output does not match the number of embeddings
+        assert!(ModelYOLOWorldOrt::parse_output_array_static(&output.view().into_dyn(), 80, 0.25, &meta).is_err());
+    }
+}
@@ -0,0 +1,355 @@
+//! YOLOX models using ONNX Runtime.
+
+use ort::session::Session;
+use ort::inputs;
+
+use crate::bbox::BBox;
+use crate::model_trait::DetectionVecs;
+use crate::image_buffer::ImageBuffer;
+use crate::postprocess::{Detection, NmsStrategy, filter_by_class, detections_to_vecs, argmax};
+use crate::preprocessing::{preprocess_bgr_unnormalized, PreprocessMeta};
+
+use super::OrtModelError;
+use super::tensor::{extract_output, input_element_type, input_value_unnormalized};
+use super::tensor_names::TensorNames;
+
+/// Strides of the YOLOX P3/P4/P5 heads.
+const YOLOX_STRIDES: [u32; 3] = [8, 16, 32];
+
+/// YOLOX model using ONNX Runtime.
+///
+/// YOLOX exports (without `--decode_in_inference`) emit `[1, N, 5 + num_classes]` rows of
+/// `(tx, ty, tw, th, objectness, class scores...)` where the box is relative to its
+/// anchor point. Boxes are decoded against grids generated for strides 8/16/32:
+/// `cx = (tx + grid_x) * stride`, `w = exp(tw) * stride`.
+///
+/// Unlike the other models, the input is BGR in the 0-255 range (no `/255`).
+pub struct ModelYOLOXOrt {
+    session: Session,
+    tensor_names: TensorNames,
+    input_width: u32,
+    input_height: u32,
+    class_filters: Vec<usize>,
+    nms_strategy: NmsStrategy,
+    use_letterbox: bool,
+    grids: Vec<(f32, f32, f32)>,
+}
+
+impl ModelYOLOXOrt {
+    /// Creates a new model from an ONNX file.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let model = ModelYOLOXOrt::new_from_file(
+    ///     "yolox_s.onnx",
+    ///     (640, 640),
+    ///     vec![],  // detect all classes
+    /// )?;
+    /// ```
+    pub fn new_from_file(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with CUDA acceleration.
+    ///
+    /// Requires the `ort-cuda-backend` feature.
+    #[cfg(feature = "ort-cuda-backend")]
+    pub fn new_from_file_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_cuda(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model from an ONNX file with TensorRT acceleration.
+    ///
+    /// Requires the `ort-tensorrt-backend` feature.
+    #[cfg(feature = "ort-tensorrt-backend")]
+    pub fn new_from_file_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file_tensorrt(model_path)?;
+        Ok(Self::from_session(session, input_size, class_filters))
+    }
+
+    /// Creates a new model with custom session options.
+    ///
+    /// # Arguments
+    /// * `session` - Pre-configured ORT session
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect
+    pub fn from_session(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Self {
+        let tensor_names = TensorNames::from_session(&session, 1);
+        Self {
+            session,
+            tensor_names,
+            input_width: input_size.0,
+            input_height: input_size.1,
+            class_filters,
+            nms_strategy: NmsStrategy::default(),
+            use_letterbox: true,
+            grids: generate_grids(input_size, &YOLOX_STRIDES),
+        }
+    }
+
+    /// Creates a new model from an ONNX file with custom tensor names.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn new_from_file_with_names(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        let session = super::session::session_from_file(model_path)?;
+        Self::from_session_with_names(session, input_size, class_filters, tensor_names)
+    }
+
+    /// Creates a new model with custom session options and tensor names.
+    ///
+    /// The model reads one output.
+    ///
+    /// # Errors
+    /// [`OrtModelError::InvalidTensorName`] if a name is not found in the model.
+    pub fn from_session_with_names(
+        session: Session,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+        tensor_names: TensorNames,
+    ) -> Result<Self, OrtModelError> {
+        tensor_names.validate(&session, 1)?;
+        let mut model = Self::from_session(session, input_size, class_filters);
+        model.tensor_names = tensor_names;
+        Ok(model)
+    }
+
+    /// Enables or disables letterbox preprocessing.
+    ///
+    /// Default is `true`: YOLOX is trained on padded inputs, stretching
+    /// noticeably degrades its accuracy.
+    pub fn set_letterbox(&mut self, enabled: bool) {
+        self.use_letterbox = enabled;
+    }
+
+    /// Sets the NMS algorithm applied to the detections.
+    ///
+    /// Default is [`NmsStrategy::Greedy`].
+    pub fn set_nms_strategy(&mut self, strategy: NmsStrategy) {
+        self.nms_strategy = strategy;
+    }
+
+    /// Returns the input size (width, height).
+    pub fn input_size(&self) -> (u32, u32) {
+        (self.input_width, self.input_height)
+    }
+
+    /// Returns the input and output tensor names.
+    pub fn tensor_names(&self) -> &TensorNames {
+        &self.tensor_names
+    }
+
+    /// Runs inference on an image.
+    ///
+    /// # Arguments
+    /// * `image` - Input image buffer
+    /// * `conf_threshold` - Confidence threshold (0.0 - 1.0)
+    /// * `nms_threshold` - NMS IoU threshold (0.0 - 1.0)
+    ///
+    /// # Returns
+    /// Tuple of (bounding boxes, class IDs, confidence scores)
+    pub fn forward(
+        &mut self,
+        image: &ImageBuffer,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs, OrtModelError> {
+        let (tensor, meta) = preprocess_bgr_unnormalized(
+            image,
+            self.input_width,
+            self.input_height,
+            self.use_letterbox,
+        );
+
+        // Pixel values are 0 - 255, so uint8 inputs take them unscaled
+        let input_type = input_element_type(&self.session, self.tensor_names.input());
+        let outputs = self.session.run(
+            inputs![self.tensor_names.input() => input_value_unnormalized(&tensor, input_type)?]
+        )?;
+
+        let output = extract_output(&outputs, self.tensor_names.output(0))?;
+
+        let detections = Self::parse_output_array_static(&output.view(), conf_threshold, &self.grids, &meta)?;
+        let filtered = filter_by_class(&detections, &self.class_filters);
+        let final_detections = self.nms_strategy.apply(&filtered, nms_threshold, conf_threshold);
+
+        Ok(detections_to_vecs(final_detections))
+    }
+
+    /// Parses the `[1, N, 5 + num_classes]` model output into detections.
+    ///
+    /// `grids` holds one `(grid_x, grid_y, stride)` entry per prediction.
+    fn parse_output_array_static(
+        output: &ndarray::ArrayViewD<f32>,
+        conf_threshold: f32,
+        grids: &[(f32, f32, f32)],
+        meta: &PreprocessMeta,
+    ) -> Result<Vec<Detection>, OrtModelError> {
+        let shape = output.shape();
+
+        if shape.len() != 3 || shape[0] != 1 || shape[2] < 6 {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected shape [1, N, 5 + num_classes], got {:?}",
+                shape
+            )));
+        }
+
+        let num_predictions = shape[1];
+        let num_features = shape[2];
+
+        if num_predictions != grids.len() {
+            return Err(OrtModelError::InvalidOutputShape(format!(
+                "Expected {} predictions for the configured input size, got {}",
+                grids.len(),
+                num_predictions
+            )));
+        }
+
+        let mut detections = Vec::new();
+
+        for (i, &(grid_x, grid_y, stride)) in grids.iter().enumerate() {
+            let objectness = output[[0, i, 4]];
+            if objectness < conf_threshold {
+                continue;
+            }
+
+            let class_scores: Vec<f32> = (5..num_features)
+                .map(|j| output[[0, i, j]])
+                .collect();
+
+            if let Some((class_idx, class_score)) = argmax(&class_scores) {
+                let score = objectness * class_score;
+                if score < conf_threshold {
+                    continue;
+                }
+
+                let (cx, cy, w, h) = meta.inverse_transform(
+                    (output[[0, i, 0]] + grid_x) * stride,
+                    (output[[0, i, 1]] + grid_y) * stride,
+                    output[[0, i, 2]].exp() * stride,
+                    output[[0, i, 3]].exp() * stride,
+                );
+
+                detections.push(Detection::new(BBox::from_center(cx, cy, w, h), class_idx, score));
+            }
+        }
+
+        Ok(detections)
+    }
+}
+
+/// Generates `(grid_x, grid_y, stride)` anchor points for every head, in the
+/// order YOLOX flattens its predictions (stride, then row, then column).
+fn generate_grids(input_size: (u32, u32), strides: &[u32]) -> Vec<(f32, f32, f32)> {
+    let mut grids = Vec::new();
+    for &stride in strides {
+        let grid_w = input_size.0 / stride;
+        let grid_h = input_size.1 / stride;
+        for y in 0..grid_h {
+            for x in 0..grid_w {
+                grids.push((x as f32, y as f32, stride as f32));
+            }
+        }
+    }
+    grids
+}
+
+impl crate::ObjectDetector for ModelYOLOXOrt {
+    type Input = ImageBuffer;
+    type Error = OrtModelError;
+
+    fn detect(
+        &mut self,
+        input: &Self::Input,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs, Self::Error> {
+        self.forward(input, conf_threshold, nms_threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::Array3;
+
+    #[test]
+    fn test_generate_grids() {
+        let grids = generate_grids((640, 640), &YOLOX_STRIDES);
+        assert_eq!(grids.len(), 8400);
+        assert_eq!(grids[1], (1.0, 0.0, 8.0));
+        assert_eq!(grids[80], (0.0, 1.0, 8.0));
+        assert_eq!(grids[6400], (0.0, 0.0, 16.0));
+        assert_eq!(grids[8399], (19.0, 19.0, 32.0));
+    }
+
+    #[test]
+    fn test_parse_output_grid_decoding() {
+        // 64x64 input: 64 + 16 + 4 = 84 predictions, 2 classes
+        let grids = generate_grids((64, 64), &YOLOX_STRIDES);
+        let mut output = Array3::<f32>::zeros((1, grids.len(), 7));
+        // Stride 16 head, cell (1, 2): index 64 + 2 * 4 + 1
+        let i = 73;
+        output[[0, i, 0]] = 0.5;
+        output[[0, i, 1]] = 0.5;
+        output[[0, i, 2]] = 0.0;
+        output[[0, i, 3]] = 2.0_f32.ln();
+        output[[0, i, 4]] = 0.9;
+        output[[0, i, 6]] = 0.8;
+
+        let detections = ModelYOLOXOrt::parse_output_array_static(
+            &output.view().into_dyn(),
+            0.25,
+            &grids,
+            &PreprocessMeta::identity(64, 64),
+        )
+        .unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].class_id, 1);
+        assert!((detections[0].confidence - 0.72).abs() < 0.001);
+        // cx = 1.5 * 16 = 24, cy = 2.5 * 16 = 40, w = 16, h = 32
+        assert_eq!(detections[0].bbox, BBox::new(16, 24, 16, 32));
+    }
+
+    #[test]
+    fn test_parse_output_grid_mismatch() {
+        let grids = generate_grids((64, 64), &YOLOX_STRIDES);
+        let output = Array3::<f32>::zeros((1, 8400, 85));
+        let result = ModelYOLOXOrt::parse_output_array_static(
+            &output.view().into_dyn(),
+            0.25,
+            &grids,
+            &PreprocessMeta::identity(64, 64),
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,39 @@
+//! Session construction helpers shared by the ORT models.
+
+use ort::session::{Session, builder::GraphOptimizationLevel};
+
+#[cfg(feature = "ort-cuda-backend")]
+use ort::execution_providers::CUDAExecutionProvider;
+
+#[cfg(feature = "ort-tensorrt-backend")]
+use ort::execution_providers::TensorRTExecutionProvider;
+
+use super::OrtModelError;
+
+/// Creates a CPU session from an ONNX file.
+pub(crate) fn session_from_file(model_path: &str) -> Result<Session, OrtModelError> {
+    let session = Session::builder()?
+        .with_optimization_level(GraphOptimizationLevel::Level3)?
+        .commit_from_file(model_path)?;
+    Ok(session)
+}
+
+/// Creates a session with the CUDA execution provider from an ONNX file.
+#[cfg(feature = "ort-cuda-backend")]
+pub(crate) fn session_from_file_cuda(model_path: &str) -> Result<Session, OrtModelError> {
+    let session = Session::builder()?
+        .with_execution_providers([CUDAExecutionProvider::default().build()])?
+        .with_optimization_level(GraphOptimizationLevel::Level3)?
+        .commit_from_file(model_path)?;
+    Ok(session)
+}
+
+/// Creates a session with the TensorRT execution provider from an ONNX file.
+#[cfg(feature = "ort-tensorrt-backend")]
+pub(crate) fn session_from_file_tensorrt(model_path: &str) -> Result<Session, OrtModelError> {
+    let session = Session::builder()?
+        .with_execution_providers([TensorRTExecutionProvider::default().build()])?
+        .with_optimization_level(GraphOptimizationLevel::Level3)?
+        .commit_from_file(model_path)?;
+    Ok(session)
+}
@@ -0,0 +1,115 @@
+//! Tensor conversion helpers shared by the ORT models.
+//!
+//! Half-precision exports (`half=True`) take and return `float16` tensors and some
+//! quantized models take `uint8` images, while preprocessing and postprocessing work
+//! on `f32`. These helpers convert at the session boundary.
+
+use half::f16;
+use ndarray::{Array, Dimension};
+use ort::session::{Session, SessionInputValue, SessionOutputs};
+use ort::tensor::TensorElementType;
+use ort::value::{DynValue, Tensor, TensorRef};
+
+use super::OrtModelError;
+
+/// Extracts a float or integer tensor as an owned f32 array.
+///
+/// Detection counts and class ids are often exported as `int32`/`int64`, half-precision
+/// models return `float16`.
+pub(crate) fn extract_as_f32(value: &DynValue) -> Result<ndarray::ArrayD<f32>, OrtModelError> {
+    let array = match value.dtype().tensor_type() {
+        Some(TensorElementType::Float16) => value.try_extract_array::<f16>()?.mapv(f16::to_f32),
+        Some(TensorElementType::Float64) => value.try_extract_array::<f64>()?.mapv(|v| v as f32),
+        Some(TensorElementType::Uint8) => value.try_extract_array::<u8>()?.mapv(|v| v as f32),
+        Some(TensorElementType::Int8) => value.try_extract_array::<i8>()?.mapv(|v| v as f32),
+        Some(TensorElementType::Int32) => value.try_extract_array::<i32>()?.mapv(|v| v as f32),
+        Some(TensorElementType::Int64) => value.try_extract_array::<i64>()?.mapv(|v| v as f32),
+        _ => value.try_extract_array::<f32>()?.into_owned(),
+    };
+    Ok(array)
+}
+
+/// Extracts the output named `name` as an owned f32 array.
+///
+/// Unlike indexing the outputs, a missing output is an error instead of a panic.
+pub(crate) fn extract_output(outputs: &SessionOutputs, name: &str) -> Result<ndarray::ArrayD<f32>, OrtModelError> {
+    let value = outputs
+        .get(name)
+        .ok_or_else(|| OrtModelError::InvalidOutputShape(format!("Output '{}' not found", name)))?;
+    extract_as_f32(value)
+}
+
+/// Returns the element type of the session input named `name`.
+pub(crate) fn input_element_type(session: &Session, name: &str) -> Option<TensorElementType> {
+    session
+        .inputs
+        .iter()
+        .find(|input| input.name == name)
+        .and_then(|input| input.input_type.tensor_type())
+}
+
+/// Returns whether the session input named `name` accepts a batch of `batch_size` images:
+/// its batch axis is dynamic or has that exact size.
+pub(crate) fn accepts_batch(session: &Session, name: &str, batch_size: usize) -> bool {
+    session
+        .inputs
+        .iter()
+        .find(|input| input.name == name)
+        .and_then(|input| input.input_type.tensor_shape())
+        .and_then(|shape| shape.first().copied())
+        .is_some_and(|batch| batch <= 0 || batch as usize == batch_size)
+}
+
+/// Converts a preprocessed image tensor (0.0 - 1.0) to the element type of the model input.
+///
+/// `float32` inputs are passed without copy, `float16` inputs are converted and `uint8`
+/// inputs get the 0 - 255 pixel values back.
+pub(crate) fn input_value<D: Dimension + 'static>(
+    tensor: &Array<f32, D>,
+    element_type: Option<TensorElementType>,
+) -> Result<SessionInputValue<'_>, OrtModelError> {
+    convert_input(tensor, element_type, 255.0)
+}
+
+/// Same as [`input_value`] for tensors that already hold 0 - 255 pixel values
+/// (e.g. YOLOX), which are passed to `uint8` inputs unscaled.
+pub(crate) fn input_value_unnormalized<D: Dimension + 'static>(
+    tensor: &Array<f32, D>,
+    element_type: Option<TensorElementType>,
+) -> Result<SessionInputValue<'_>, OrtModelError> {
+    convert_input(tensor, element_type, 1.0)
+}
+
+fn convert_input<D: Dimension + 'static>(
+    tensor: &Array<f32, D>,
+    element_type: Option<TensorElementType>,
+    u8_scale: f32,
+) -> Result<SessionInputValue<'_>, OrtModelError> {
+    let value = match element_type {
+        Some(TensorElementType::Float16) => Tensor::from_array(tensor.mapv(f16::from_f32))?.into(),
+        Some(TensorElementType::Uint8) => Tensor::from_array(to_u8_pixels(tensor, u8_scale))?.into(),
+        _ => TensorRef::from_array_view(tensor)?.into(),
+    };
+    Ok(value)
+}
+
+/// Maps a tensor to 0 - 255 pixel values, multiplying by `scale` first.
+fn to_u8_pixels<D: Dimension + 'static>(tensor: &Array<f32, D>, scale: f32) -> Array<u8, D> {
+    tensor.mapv(|v| (v * scale).round().clamp(0.0, 255.0) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::Array4;
+
+    #[test]
+    fn test_to_u8_pixels() {
+        let tensor = Array4::from_shape_vec((1, 1, 1, 4), vec![0.0, 0.5, 1.0, 1.2]).unwrap();
+        assert_eq!(to_u8_pixels(&tensor, 255.0).into_raw_vec_and_offset().0, vec![0, 128, 255, 255]);
+
+        // Unnormalized tensors (0 - 255) are only rounded and clamped
+        let tensor = Array4::from_shape_vec((1, 1, 1, 4), vec![0.0, 127.6, 255.0, 300.0]).unwrap();
+        assert_eq!(to_u8_pixels(&tensor, 1.0).into_raw_vec_and_offset().0, vec![0, 128, 255, 255]);
+    }
+}
@@ -0,0 +1,123 @@
+//! Input and output tensor names of the ORT models.
+
+use ort::session::Session;
+
+use super::OrtModelError;
+
+/// Names of the input tensor and of the output tensors read by a model.
+///
+/// By default the names are discovered from the session: its first input and its
+/// first outputs, in graph order. Models exported by tools that do not follow the
+/// Ultralytics naming (`images`, `output0`) can override them with the
+/// `*_with_names` constructors, which check the names against the session.
+///
+/// # Example
+/// ```ignore
+/// let names = TensorNames::new("data", &["output"]);
+/// let model = ModelYOLOv5Ort::new_from_file_with_names("yolov5s.onnx", (640, 640), vec![], names)?;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TensorNames {
+    input: String,
+    outputs: Vec<String>,
+}
+
+impl TensorNames {
+    /// Creates tensor names from the input name and the output names, in the order the model reads them.
+    pub fn new(input: &str, outputs: &[&str]) -> Self {
+        Self {
+            input: input.to_string(),
+            outputs: outputs.iter().map(|name| name.to_string()).collect(),
+        }
+    }
+
+    /// Returns the input tensor name.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns the output tensor names.
+    pub fn outputs(&self) -> &[String] {
+        &self.outputs
+    }
+
+    /// Returns the name of the `index`-th output read by the model.
+    pub(crate) fn output(&self, index: usize) -> &str {
+        &self.outputs[index]
+    }
+
+    /// Discovers the names from the session: its first input and first `num_outputs` outputs.
+    ///
+    /// Falls back to the Ultralytics names when the session has fewer tensors: the
+    /// missing outputs are then reported as [`OrtModelError::InvalidOutputShape`] by `forward`.
+    pub(crate) fn from_session(session: &Session, num_outputs: usize) -> Self {
+        let input = session
+            .inputs
+            .first()
+            .map(|input| input.name.clone())
+            .unwrap_or_else(|| "images".to_string());
+        let outputs = (0..num_outputs)
+            .map(|i| {
+                session
+                    .outputs
+                    .get(i)
+                    .map(|output| output.name.clone())
+                    .unwrap_or_else(|| format!("output{}", i))
+            })
+            .collect();
+        Self { input, outputs }
+    }
+
+    /// Checks that the names exist in the session and that `num_outputs` outputs are named.
+    pub(crate) fn validate(&self, session: &Session, num_outputs: usize) -> Result<(), OrtModelError> {
+        let inputs: Vec<&str> = session.inputs.iter().map(|input| input.name.as_str()).collect();
+        let outputs: Vec<&str> = session.outputs.iter().map(|output| output.name.as_str()).collect();
+        self.validate_names(&inputs, &outputs, num_outputs)
+    }
+
+    fn validate_names(
+        &self,
+        inputs: &[&str],
+        outputs: &[&str],
+        num_outputs: usize,
+    ) -> Result<(), OrtModelError> {
+        if !inputs.contains(&self.input.as_str()) {
+            return Err(OrtModelError::InvalidTensorName(format!(
+                "input `{}` not found, model inputs: {:?}",
+                self.input, inputs
+            )));
+        }
+        if self.outputs.len() != num_outputs {
+            return Err(OrtModelError::InvalidTensorName(format!(
+                "expected {} output names, got {:?}",
+                num_outputs, self.outputs
+            )));
+        }
+        if let Some(name) = self.outputs.iter().find(|name| !outputs.contains(&name.as_str())) {
+            return Err(OrtModelError::InvalidTensorName(format!(
+                "output `{}` not found, model outputs: {:?}",
+                name, outputs
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_names() {
+        let names = TensorNames::new("data", &["output"]);
+        assert!(names.validate_names(&["data"], &["output", "aux"], 1).is_ok());
+
+        let err = names.validate_names(&["images"], &["output"], 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid tensor name: input `data` not found, model inputs: [\"images\"]"
+        );
+        assert!(names.validate_names(&["data"], &["output0"], 1).is_err());
+        assert!(names.validate_names(&["data"], &["output"], 2).is_err());
+    }
+}
@@ -0,0 +1,472 @@
+//! Darknet `.cfg` parser.
+//!
+//! This module reads the `[net]` section and the `[yolo]` / `[region]` heads of a
+//! Darknet configuration to discover the input size, class count, anchors, masks,
+//! strides and output layer names of "classic" YOLO models (v2, v3, v4, v7).
+
+use std::path::Path;
+
+/// Error type for Darknet configuration parsing.
+#[derive(Debug)]
+pub enum DarknetCfgError {
+    /// Configuration file could not be read
+    Io(std::io::Error),
+    /// Syntax error at the given (1-based) line
+    Parse { line: usize, message: String },
+    /// Configuration is syntactically valid but inconsistent or incomplete
+    Invalid(String),
+}
+
+impl std::fmt::Display for DarknetCfgError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DarknetCfgError::Io(e) => write!(f, "Can't read Darknet cfg: {}", e),
+            DarknetCfgError::Parse { line, message } => write!(f, "Darknet cfg line {}: {}", line, message),
+            DarknetCfgError::Invalid(s) => write!(f, "Invalid Darknet cfg: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for DarknetCfgError {}
+
+impl From<std::io::Error> for DarknetCfgError {
+    fn from(e: std::io::Error) -> Self {
+        DarknetCfgError::Io(e)
+    }
+}
+
+/// Kind of a Darknet detection head.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DarknetHeadKind {
+    /// `[yolo]` head (YOLOv3 and later), anchors in input pixels
+    Yolo,
+    /// `[region]` head (YOLOv2), anchors in grid cells
+    Region,
+}
+
+/// A `[yolo]` or `[region]` section.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DarknetHead {
+    /// Head kind
+    pub kind: DarknetHeadKind,
+    /// Index of the layer in the network (the `[net]` section excluded)
+    pub layer_index: usize,
+    /// All anchors of the head as (width, height)
+    pub anchors: Vec<(f32, f32)>,
+    /// Indices into `anchors` used by this head (all anchors for `[region]`)
+    pub mask: Vec<usize>,
+    /// Number of classes
+    pub num_classes: usize,
+    /// Downsampling factor of the head relative to the network input
+    pub stride: u32,
+    /// Grid sensitivity factor (`scale_x_y`), `1.0` when absent
+    pub scale_x_y: f32,
+    /// Scaled-YOLOv4 / YOLOv7 box parametrization (`new_coords=1`)
+    pub new_coords: bool,
+}
+
+/// Model configuration discovered from a Darknet `.cfg` file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DarknetConfig {
+    /// Network input width (`[net]` width)
+    pub width: i32,
+    /// Network input height (`[net]` height)
+    pub height: i32,
+    /// Number of input channels (`[net]` channels)
+    pub channels: i32,
+    /// Number of classes, shared by all heads
+    pub num_classes: usize,
+    /// Detection heads, in network order
+    pub heads: Vec<DarknetHead>,
+}
+
+/// A parsed `[section]` with its key/value pairs and the line it starts on.
+struct Section {
+    name: String,
+    line: usize,
+    options: Vec<(String, String)>,
+}
+
+impl Section {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.options.iter().rev().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    fn parse<T: std::str::FromStr>(&self, key: &str, default: T) -> Result<T, DarknetCfgError> {
+        match self.get(key) {
+            Some(value) => value.parse().map_err(|_| DarknetCfgError::Parse {
+                line: self.line,
+                message: format!("[{}] has invalid {}={}", self.name, key, value),
+            }),
+            None => Ok(default),
+        }
+    }
+
+    fn parse_list<T: std::str::FromStr>(&self, key: &str) -> Result<Vec<T>, DarknetCfgError> {
+        let Some(value) = self.get(key) else {
+            return Ok(Vec::new());
+        };
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(|v| {
+                v.parse().map_err(|_| DarknetCfgError::Parse {
+                    line: self.line,
+                    message: format!("[{}] has invalid {}={}", self.name, key, value),
+                })
+            })
+            .collect()
+    }
+}
+
+impl DarknetConfig {
+    /// Reads and parses a Darknet `.cfg` file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, DarknetCfgError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Parses the content of a Darknet `.cfg` file.
+    pub fn parse(content: &str) -> Result<Self, DarknetCfgError> {
+        let sections = parse_sections(content)?;
+
+        let Some((net, layers)) = sections.split_first().filter(|(net, _)| net.name == "net" || net.name == "network") else {
+            return Err(DarknetCfgError::Invalid("first section must be [net]".to_string()));
+        };
+
+        let width = net.parse("width", 0)?;
+        let height = net.parse("height", 0)?;
+        let channels = net.parse("channels", 3)?;
+        if width <= 0 || height <= 0 {
+            return Err(DarknetCfgError::Invalid("[net] width and height must be set".to_string()));
+        }
+
+        // Track the downsampling factor of every layer to find the strides of the heads
+        let mut strides: Vec<f32> = Vec::with_capacity(layers.len());
+        let mut heads = Vec::new();
+
+        for (index, layer) in layers.iter().enumerate() {
+            let previous = strides.last().copied().unwrap_or(1.0);
+            let stride = match layer.name.as_str() {
+                "convolutional" | "maxpool" | "local" | "reorg" | "reorg3d" => previous * layer.parse("stride", 1.0f32)?,
+                "upsample" => previous / layer.parse("stride", 2.0f32)?,
+                "route" => {
+                    let sources: Vec<i64> = layer.parse_list("layers")?;
+                    let first = *sources.first().ok_or_else(|| DarknetCfgError::Parse {
+                        line: layer.line,
+                        message: "[route] without layers".to_string(),
+                    })?;
+                    let source = if first < 0 { index as i64 + first } else { first };
+                    if source < 0 || source as usize >= index {
+                        return Err(DarknetCfgError::Parse {
+                            line: layer.line,
+                            message: format!("[route] refers to missing layer {}", first),
+                        });
+                    }
+                    strides[source as usize]
+                }
+                _ => previous,
+            };
+            strides.push(stride);
+
+            let kind = match layer.name.as_str() {
+                "yolo" => DarknetHeadKind::Yolo,
+                "region" => DarknetHeadKind::Region,
+                _ => continue,
+            };
+
+            let values: Vec<f32> = layer.parse_list("anchors")?;
+            if !values.len().is_multiple_of(2) {
+                return Err(DarknetCfgError::Parse {
+                    line: layer.line,
+                    message: format!("[{}] has an odd number of anchor values", layer.name),
+                });
+            }
+            let anchors: Vec<(f32, f32)> = values.chunks_exact(2).map(|a| (a[0], a[1])).collect();
+            let mask: Vec<usize> = match kind {
+                DarknetHeadKind::Yolo if layer.get("mask").is_some() => layer.parse_list("mask")?,
+                _ => (0..anchors.len()).collect(),
+            };
+            if let Some(&bad) = mask.iter().find(|&&m| m >= anchors.len()) {
+                return Err(DarknetCfgError::Parse {
+                    line: layer.line,
+                    message: format!("[{}] mask {} is out of {} anchors", layer.name, bad, anchors.len()),
+                });
+            }
+
+            heads.push(DarknetHead {
+                kind,
+                layer_index: index,
+                anchors,
+                mask,
+                num_classes: layer.parse("classes", 0)?,
+                stride: stride.round().max(1.0) as u32,
+                scale_x_y: layer.parse("scale_x_y", 1.0)?,
+                new_coords: layer.parse("new_coords", 0u8)? != 0,
+            });
+        }
+
+        let Some(first_head) = heads.first() else {
+            return Err(DarknetCfgError::Invalid("no [yolo] or [region] section".to_string()));
+        };
+        let num_classes = first_head.num_classes;
+        if num_classes == 0 || heads.iter().any(|head| head.num_classes != num_classes) {
+            return Err(DarknetCfgError::Invalid(format!(
+                "heads must share a non-zero class count, got {:?}",
+                heads.iter().map(|head| head.num_classes).collect::<Vec<_>>()
+            )));
+        }
+
+        Ok(Self {
+            width,
+            height,
+            channels,
+            num_classes,
+            heads,
+        })
+    }
+
+    /// Returns the network input size as (width, height).
+    pub fn input_size(&self) -> (i32, i32) {
+        (self.width, self.height)
+    }
+
+    /// Returns the names OpenCV's Darknet importer gives to the output layers.
+    pub fn output_layer_names(&self) -> Vec<String> {
+        self.heads
+            .iter()
+            .map(|head| match head.kind {
+                DarknetHeadKind::Yolo => format!("yolo_{}", head.layer_index),
+                DarknetHeadKind::Region => "detection_out".to_string(),
+            })
+            .collect()
+    }
+
+    /// Checks user-supplied settings against the configuration.
+    ///
+    /// # Arguments
+    /// * `net_size` - Network size as (width, height), must match `[net]`
+    /// * `filter_classes` - Class indices to detect, must be lower than the class count
+    pub fn validate(&self, net_size: (i32, i32), filter_classes: &[usize]) -> Result<(), DarknetCfgError> {
+        if net_size != self.input_size() {
+            return Err(DarknetCfgError::Invalid(format!(
+                "network size {:?} does not match [net] width/height {:?}",
+                net_size,
+                self.input_size()
+            )));
+        }
+        if let Some(&class_id) = filter_classes.iter().find(|&&c| c >= self.num_classes) {
+            return Err(DarknetCfgError::Invalid(format!(
+                "class filter {} is out of {} classes",
+                class_id, self.num_classes
+            )));
+        }
+        Ok(())
+    }
+
+    /// Converts the `[yolo]` heads into layer configurations for decoding raw outputs
+    /// with [`ModelYOLOClassicOrt::set_yolo_layers`](crate::backend_ort::ModelYOLOClassicOrt::set_yolo_layers).
+    ///
+    /// `[region]` heads are skipped.
+    #[cfg(feature = "ort-backend")]
+    pub fn yolo_layers(&self) -> Vec<crate::postprocess::YoloLayer> {
+        self.heads
+            .iter()
+            .filter(|head| head.kind == DarknetHeadKind::Yolo)
+            .map(|head| {
+                crate::postprocess::YoloLayer::new(head.anchors.clone(), head.mask.clone(), head.stride, head.num_classes)
+                    .with_scale_x_y(head.scale_x_y)
+                    .with_new_coords(head.new_coords)
+            })
+            .collect()
+    }
+}
+
+/// Splits the configuration into sections, skipping comments and blank lines.
+fn parse_sections(content: &str) -> Result<Vec<Section>, DarknetCfgError> {
+    let mut sections: Vec<Section> = Vec::new();
+
+    for (number, raw) in content.lines().enumerate() {
+        let line = raw.split(['#', ';']).next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[') {
+            let name = name.strip_suffix(']').ok_or_else(|| DarknetCfgError::Parse {
+                line: number + 1,
+                message: format!("unterminated section header '{}'", line),
+            })?;
+            sections.push(Section {
+                name: name.trim().to_string(),
+                line: number + 1,
+                options: Vec::new(),
+            });
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(DarknetCfgError::Parse {
+                line: number + 1,
+                message: format!("expected key=value, got '{}'", line),
+            });
+        };
+        let Some(section) = sections.last_mut() else {
+            return Err(DarknetCfgError::Parse {
+                line: number + 1,
+                message: "option outside of a section".to_string(),
+            });
+        };
+        section.options.push((key.trim().to_string(), value.trim().to_string()));
+    }
+
+    Ok(sections)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TINY_CFG: &str = "
+[net]
+# Testing
+batch=1
+width=416
+height=288
+channels=3
+
+[convolutional]
+filters=16
+size=3
+stride=1
+
+[maxpool]
+size=2
+stride=2
+
+[convolutional]
+filters=32
+size=3
+stride=2
+
+[maxpool]
+size=2
+stride=2
+
+[convolutional]
+size=3
+stride=1
+
+[maxpool]
+size=2
+stride=2
+
+[convolutional]
+size=3
+stride=1
+
+[maxpool]
+size=2
+stride=2
+
+[convolutional]
+size=1
+filters=255
+
+[yolo]
+mask = 3,4,5
+anchors = 10,14,  23,27,  37,58,  81,82,  135,169,  344,319
+classes=80
+num=6
+scale_x_y = 1.05
+
+[route]
+layers = -2
+
+[upsample]
+stride=2
+
+[route]
+layers = -1, 6
+
+[convolutional]
+size=1
+filters=255
+
+[yolo]
+mask = 0,1,2
+anchors = 10,14,  23,27,  37,58,  81,82,  135,169,  344,319
+classes=80
+num=6
+";
+
+    #[test]
+    fn test_parse_cfg() {
+        let cfg = DarknetConfig::parse(TINY_CFG).unwrap();
+
+        assert_eq!(cfg.input_size(), (416, 288));
+        assert_eq!(cfg.channels, 3);
+        assert_eq!(cfg.num_classes, 80);
+        assert_eq!(cfg.heads.len(), 2);
+
+        assert_eq!(cfg.heads[0].kind, DarknetHeadKind::Yolo);
+        assert_eq!(cfg.heads[0].stride, 32);
+        assert_eq!(cfg.heads[0].mask, vec![3, 4, 5]);
+        assert_eq!(cfg.heads[0].anchors.len(), 6);
+        assert_eq!(cfg.heads[0].anchors[5], (344.0, 319.0));
+        assert!((cfg.heads[0].scale_x_y - 1.05).abs() < 1e-6);
+
+        assert_eq!(cfg.heads[1].stride, 16);
+        assert_eq!(cfg.heads[1].scale_x_y, 1.0);
+        assert!(!cfg.heads[1].new_coords);
+
+        assert_eq!(cfg.output_layer_names(), vec!["yolo_9", "yolo_14"]);
+    }
+
+    #[test]
+    fn test_validate() {
+        let cfg = DarknetConfig::parse(TINY_CFG).unwrap();
+        assert!(cfg.validate((416, 288), &[0, 79]).is_ok());
+        assert!(cfg.validate((416, 416), &[]).is_err());
+        assert!(cfg.validate((416, 288), &[80]).is_err());
+    }
+
+    #[test]
+    fn test_region_head() {
+        let cfg = DarknetConfig::parse(
+            "[net]\nwidth=416\nheight=416\n[convolutional]\nstride=32\n[region]\nanchors=0.57,0.67, 1.87,2.06\nclasses=20\nnum=2\n",
+        )
+        .unwrap();
+        assert_eq!(cfg.heads[0].kind, DarknetHeadKind::Region);
+        assert_eq!(cfg.heads[0].mask, vec![0, 1]);
+        assert_eq!(cfg.heads[0].stride, 32);
+        assert_eq!(cfg.output_layer_names(), vec!["detection_out"]);
+    }
+
+    #[test]
+    fn test_invalid_cfg() {
+        assert!(matches!(
+            DarknetConfig::parse("[convolutional]\nstride=1\n"),
+            Err(DarknetCfgError::Invalid(_))
+        ));
+        assert!(matches!(
+            DarknetConfig::parse("[net]\nwidth=416\nheight=416\nbroken line\n"),
+            Err(DarknetCfgError::Parse { line: 4, .. })
+        ));
+        assert!(matches!(
+            DarknetConfig::parse("[net]\nwidth=416\nheight=416\n[yolo]\nmask=7\nanchors=1,2\nclasses=1\n"),
+            Err(DarknetCfgError::Parse { line: 4, .. })
+        ));
+        assert!(DarknetConfig::parse("[net]\nwidth=416\nheight=416\n[convolutional]\n").is_err());
+    }
+
+    #[cfg(feature = "ort-backend")]
+    #[test]
+    fn test_yolo_layers() {
+        let layers = DarknetConfig::parse(TINY_CFG).unwrap().yolo_layers();
+        assert_eq!(layers.len(), 2);
+        assert_eq!(layers[0].stride, 32);
+        assert_eq!(layers[1].mask, vec![0, 1, 2]);
+        assert_eq!(layers[0].num_classes, 80);
+    }
+}
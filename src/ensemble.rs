@@ -0,0 +1,204 @@
+//! Running several detectors on the same image and fusing their outputs.
+//!
+//! This module provides [`EnsembleDetector`], which combines the detections of several
+//! [`ObjectDetector`]s with Weighted Boxes Fusion (see [`weighted_boxes_fusion`]).
+
+use crate::model_trait::{DetectionVecs, ObjectDetector};
+use crate::postprocess::{Detection, FusionConfidence, detections_to_vecs, weighted_boxes_fusion};
+
+/// Boxed detector taking part in an ensemble.
+pub type EnsembleMember<I, E> = Box<dyn ObjectDetector<Input = I, Error = E>>;
+
+/// Default IoU threshold for fusing boxes of different models.
+pub const DEFAULT_FUSION_IOU_THRESHOLD: f32 = 0.55;
+
+/// Default confidence below which model detections are ignored by the fusion.
+pub const DEFAULT_SKIP_THRESHOLD: f32 = 0.05;
+
+/// Runs several detectors over the same image and fuses their outputs with Weighted Boxes Fusion.
+///
+/// Every model is run with the lower of the requested confidence threshold and the skip
+/// threshold, so that boxes confirmed by several models may end above the threshold once fused.
+/// The fused detections are then filtered by the requested confidence threshold.
+/// The NMS threshold passed to [`ObjectDetector::detect`] is used by each model's own NMS.
+///
+/// All models must share the same class indices.
+///
+/// # Example
+///
+/// ```ignore
+/// use od_opencv::{ensemble::EnsembleDetector, Model, ObjectDetector};
+///
+/// let mut ensemble = EnsembleDetector::new()
+///     .with_model(Model::auto("pretrained/yolov8s.onnx")?, 2.0)
+///     .with_model(Model::auto("pretrained/yolov10s.onnx")?, 1.0);
+/// let (bboxes, class_ids, confidences) = ensemble.detect(&img_buffer, 0.25, 0.45)?;
+/// ```
+pub struct EnsembleDetector<I, E> {
+    models: Vec<EnsembleMember<I, E>>,
+    weights: Vec<f32>,
+    iou_threshold: f32,
+    skip_threshold: f32,
+    conf_type: FusionConfidence,
+}
+
+impl<I, E> EnsembleDetector<I, E> {
+    /// Creates an empty ensemble with default fusion settings.
+    pub fn new() -> Self {
+        Self {
+            models: Vec::new(),
+            weights: Vec::new(),
+            iou_threshold: DEFAULT_FUSION_IOU_THRESHOLD,
+            skip_threshold: DEFAULT_SKIP_THRESHOLD,
+            conf_type: FusionConfidence::default(),
+        }
+    }
+
+    /// Adds a model with the given weight (1.0 for an equal vote).
+    pub fn with_model(mut self, model: EnsembleMember<I, E>, weight: f32) -> Self {
+        self.add_model(model, weight);
+        self
+    }
+
+    /// Adds a model with the given weight (1.0 for an equal vote).
+    pub fn add_model(&mut self, model: EnsembleMember<I, E>, weight: f32) {
+        self.models.push(model);
+        self.weights.push(weight);
+    }
+
+    /// Sets the IoU above which boxes of different models are fused.
+    ///
+    /// Default is [`DEFAULT_FUSION_IOU_THRESHOLD`].
+    pub fn with_iou_threshold(mut self, iou_threshold: f32) -> Self {
+        self.iou_threshold = iou_threshold;
+        self
+    }
+
+    /// Sets the confidence below which model detections are ignored.
+    ///
+    /// Default is [`DEFAULT_SKIP_THRESHOLD`].
+    pub fn with_skip_threshold(mut self, skip_threshold: f32) -> Self {
+        self.skip_threshold = skip_threshold;
+        self
+    }
+
+    /// Sets how the confidence of fused boxes is computed.
+    ///
+    /// Default is [`FusionConfidence::Avg`].
+    pub fn with_conf_type(mut self, conf_type: FusionConfidence) -> Self {
+        self.conf_type = conf_type;
+        self
+    }
+
+    /// Returns the number of models in the ensemble.
+    pub fn len(&self) -> usize {
+        self.models.len()
+    }
+
+    /// Returns `true` if the ensemble has no models.
+    pub fn is_empty(&self) -> bool {
+        self.models.is_empty()
+    }
+
+    /// Returns the model weights, in the order the models were added.
+    pub fn weights(&self) -> &[f32] {
+        &self.weights
+    }
+}
+
+impl<I, E> Default for EnsembleDetector<I, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, E> ObjectDetector for EnsembleDetector<I, E> {
+    type Input = I;
+    type Error = E;
+
+    fn detect(
+        &mut self,
+        input: &Self::Input,
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<DetectionVecs, Self::Error> {
+        let model_threshold = conf_threshold.min(self.skip_threshold);
+        let mut per_model = Vec::with_capacity(self.models.len());
+        for model in self.models.iter_mut() {
+            let (bboxes, class_ids, confidences) = model.detect(input, model_threshold, nms_threshold)?;
+            let detections: Vec<Detection> = bboxes
+                .into_iter()
+                .zip(class_ids)
+                .zip(confidences)
+                .map(|((bbox, class_id), confidence)| Detection::new(bbox, class_id, confidence))
+                .collect();
+            per_model.push(detections);
+        }
+
+        let fused = weighted_boxes_fusion(
+            &per_model,
+            &self.weights,
+            self.iou_threshold,
+            self.skip_threshold,
+            self.conf_type,
+        );
+        Ok(detections_to_vecs(
+            fused.into_iter().filter(|d| d.confidence >= conf_threshold).collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BBox;
+
+    /// Detector returning fixed detections.
+    struct FixedDetector {
+        bboxes: Vec<BBox>,
+        confidences: Vec<f32>,
+    }
+
+    impl ObjectDetector for FixedDetector {
+        type Input = ();
+        type Error = String;
+
+        fn detect(
+            &mut self,
+            _input: &(),
+            conf_threshold: f32,
+            _nms_threshold: f32,
+        ) -> Result<DetectionVecs, String> {
+            let kept: Vec<usize> = (0..self.bboxes.len())
+                .filter(|&i| self.confidences[i] >= conf_threshold)
+                .collect();
+            Ok((
+                kept.iter().map(|&i| self.bboxes[i]).collect(),
+                vec![0; kept.len()],
+                kept.iter().map(|&i| self.confidences[i]).collect(),
+            ))
+        }
+    }
+
+    #[test]
+    fn test_ensemble_detect() {
+        let first = FixedDetector {
+            bboxes: vec![BBox::new(0, 0, 10, 10), BBox::new(100, 100, 10, 10)],
+            confidences: vec![0.8, 0.3],
+        };
+        let second = FixedDetector {
+            bboxes: vec![BBox::new(1, 1, 10, 10)],
+            confidences: vec![0.6],
+        };
+        let mut ensemble = EnsembleDetector::new()
+            .with_model(Box::new(first), 1.0)
+            .with_model(Box::new(second), 1.0);
+        assert_eq!(ensemble.len(), 2);
+
+        // The box found by both models is kept, the one found by one model only drops to 0.15
+        let (bboxes, class_ids, confidences) = ensemble.detect(&(), 0.25, 0.45).unwrap();
+        assert_eq!(bboxes.len(), 1);
+        assert_eq!(class_ids, vec![0]);
+        assert!((confidences[0] - 0.7).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,26 @@
+//! Pose estimation keypoint type.
+
+/// A single keypoint in original image coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Keypoint {
+    /// X coordinate
+    pub x: f32,
+    /// Y coordinate
+    pub y: f32,
+    /// Visibility score (0.0 - 1.0)
+    pub confidence: f32,
+}
+
+impl Keypoint {
+    /// Creates a new keypoint.
+    #[inline]
+    pub fn new(x: f32, y: f32, confidence: f32) -> Self {
+        Self { x, y, confidence }
+    }
+
+    /// Returns whether the keypoint visibility reaches `threshold`.
+    #[inline]
+    pub fn is_visible(&self, threshold: f32) -> bool {
+        self.confidence >= threshold
+    }
+}
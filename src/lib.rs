@@ -9,27 +9,59 @@
 //!
 //! ## Supported Models
 //!
-//! - Traditional YOLO (v3, v4, v7) - Darknet format, opencv-backend only
+//! - Traditional YOLO (v3, v4, v7) - Darknet format (opencv-backend) or decoded ONNX exports (both backends)
 //! - Ultralytics YOLO (v8, v9, v11) - ONNX format, both backends
+//! - YOLOv5 / YOLOv7 (objectness-style output) - ONNX format, ort-backend only
+//! - YOLOv10 (NMS-free) - ONNX format, ort-backend only
+//! - YOLOX - ONNX format, ort-backend only
+//! - YOLO-NAS (two-output exports) - ONNX format, ort-backend only
+//! - RT-DETR (NMS-free transformer) - ONNX format, ort-backend only
+//! - YOLO exports with embedded NMS (EfficientNMS / NonMaxSuppression) - ONNX format, ort-backend only
+//! - SSD / TF Object Detection API style models (MobileNet-SSD, EfficientDet) - both backends
+//! - YOLOv8/v11 instance segmentation (`-seg` exports) with per-detection masks and contours - ORT backend
+//! - YOLOv8/v11 pose estimation (`-pose` exports) with keypoints - ORT backend
+//! - YOLOv8/v11 oriented bounding boxes (`-obb` exports) with rotated NMS - ORT backend
+//! - Image classification (YOLOv8-cls, ImageNet-style classifiers) via `ImageClassifier` - ORT backend
+//! - Face detection with five landmarks (SCRFD, YOLOv8-face) - ORT backend
+//! - YOLO-World open-vocabulary detection from precomputed text embeddings - ORT backend
 
 // Common types (always available)
 pub mod bbox;
+pub mod rotated_bbox;
 pub mod image_buffer;
 pub mod model_trait;
 pub mod model_factory;
+pub mod mask;
+pub mod keypoint;
+pub mod darknet_cfg;
 
 pub use bbox::BBox;
+pub use rotated_bbox::RotatedBBox;
 pub use image_buffer::{ChannelOrder, ImageBuffer};
-pub use model_trait::ObjectDetector;
+pub use model_trait::{DetectionVecs, ImageClassifier, ObjectDetector};
 pub use model_factory::Model;
+pub use mask::Mask;
+pub use keypoint::Keypoint;
+pub use darknet_cfg::DarknetConfig;
 
-// Pure Rust preprocessing/postprocessing (for ort-backend)
-#[cfg(feature = "ort-backend")]
+// Pure Rust preprocessing/postprocessing (for ort-backend).
+// Preprocessing metadata (`LetterboxMeta`, `PreprocessMeta`) and NMS strategies are shared with opencv-backend.
+#[cfg(any(feature = "ort-backend", feature = "opencv-backend"))]
 pub mod preprocessing;
 
-#[cfg(feature = "ort-backend")]
+#[cfg(any(feature = "ort-backend", feature = "opencv-backend"))]
 pub mod postprocess;
 
+#[cfg(any(feature = "ort-backend", feature = "opencv-backend"))]
+pub use postprocess::{FusionConfidence, NmsStrategy};
+
+// Multi-model ensembles with Weighted Boxes Fusion
+#[cfg(any(feature = "ort-backend", feature = "opencv-backend"))]
+pub mod ensemble;
+
+#[cfg(any(feature = "ort-backend", feature = "opencv-backend"))]
+pub use ensemble::EnsembleDetector;
+
 // OpenCV DNN backend - requires opencv/dnn feature
 #[cfg(feature = "opencv-backend")]
 pub mod backend_opencv;
@@ -60,6 +92,9 @@ pub use backend_opencv::model_classic;
 #[cfg(feature = "opencv-backend")]
 pub use backend_opencv::model_ultralytics;
 
+#[cfg(feature = "opencv-backend")]
+pub use backend_opencv::model_ssd;
+
 // model module is needed for ModelTrait (opencv-backend only - depends on dnn)
 #[cfg(feature = "opencv-backend")]
 pub use backend_opencv::model;
@@ -68,9 +103,60 @@ pub use backend_opencv::model;
 #[cfg(feature = "ort-backend")]
 pub use backend_ort::ModelUltralyticsOrt;
 
+#[cfg(feature = "ort-backend")]
+pub use backend_ort::ModelYOLOClassicOrt;
+
+#[cfg(feature = "ort-backend")]
+pub use backend_ort::ModelYOLOv5Ort;
+
+#[cfg(feature = "ort-backend")]
+pub use backend_ort::ModelYOLOv10Ort;
+
+#[cfg(feature = "ort-backend")]
+pub use backend_ort::ModelYOLOXOrt;
+
+#[cfg(feature = "ort-backend")]
+pub use backend_ort::ModelRTDETROrt;
+
+#[cfg(feature = "ort-backend")]
+pub use backend_ort::ModelYOLONASOrt;
+
+#[cfg(feature = "ort-backend")]
+pub use backend_ort::ModelEnd2EndOrt;
+
+#[cfg(feature = "ort-backend")]
+pub use backend_ort::ModelSSDOrt;
+
+#[cfg(feature = "ort-backend")]
+pub use backend_ort::ModelUltralyticsSegOrt;
+
+#[cfg(feature = "ort-backend")]
+pub use backend_ort::ModelUltralyticsPoseOrt;
+
+#[cfg(feature = "ort-backend")]
+pub use backend_ort::ModelUltralyticsObbOrt;
+
+#[cfg(feature = "ort-backend")]
+pub use backend_ort::ModelClassifierOrt;
+
+#[cfg(feature = "ort-backend")]
+pub use backend_ort::{FaceModelFormat, ModelFaceOrt};
+
+#[cfg(feature = "ort-backend")]
+pub use backend_ort::{ModelYOLOWorldOrt, TextEmbeddings};
+
+#[cfg(feature = "ort-backend")]
+pub use backend_ort::{BoxedDetector, OutputLayout};
+
 #[cfg(feature = "ort-backend")]
 pub use backend_ort::OrtModelError;
 
+#[cfg(feature = "ort-backend")]
+pub use backend_ort::UltralyticsMetadata;
+
+#[cfg(feature = "ort-backend")]
+pub use backend_ort::TensorNames;
+
 // OpenCV compatibility layer for ORT backend
 // Allows using ORT inference with OpenCV Mat input
 // Provides ModelTrait that does NOT depend on opencv/dnn
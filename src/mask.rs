@@ -0,0 +1,235 @@
+//! Instance segmentation mask type.
+//!
+//! This module provides a backend-agnostic binary mask, stored cropped to the
+//! bounding box of its detection, plus contour (polygon) extraction.
+
+use ndarray::Array2;
+
+use crate::bbox::BBox;
+
+/// Neighbor offsets in clockwise order (y axis pointing down), starting east.
+const MOORE_NEIGHBORS: [(i32, i32); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+
+/// A binary instance mask in original image coordinates.
+///
+/// Only the region covered by `bbox` is stored: `data[[row, col]]` is the
+/// pixel at `(bbox.x + col, bbox.y + row)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mask {
+    /// Region of the image covered by the mask
+    pub bbox: BBox,
+    /// Binary mask of `bbox.height` rows and `bbox.width` columns
+    pub data: Array2<bool>,
+}
+
+impl Mask {
+    /// Creates a new mask.
+    ///
+    /// # Panics
+    /// Panics if `data` dimensions do not match `bbox`.
+    pub fn new(bbox: BBox, data: Array2<bool>) -> Self {
+        assert_eq!(
+            data.dim(),
+            (bbox.height.max(0) as usize, bbox.width.max(0) as usize),
+            "Mask data does not match its bounding box"
+        );
+        Self { bbox, data }
+    }
+
+    /// Returns whether the pixel at image coordinates `(x, y)` belongs to the mask.
+    #[inline]
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        let col = x - self.bbox.x;
+        let row = y - self.bbox.y;
+        if col < 0 || row < 0 || col >= self.bbox.width || row >= self.bbox.height {
+            return false;
+        }
+        self.data[[row as usize, col as usize]]
+    }
+
+    /// Returns the number of pixels in the mask.
+    pub fn area(&self) -> usize {
+        self.data.iter().filter(|&&v| v).count()
+    }
+
+    /// Expands the mask to a full `(height, width)` image mask.
+    pub fn to_image_mask(&self, width: usize, height: usize) -> Array2<bool> {
+        let mut full = Array2::from_elem((height, width), false);
+        for ((row, col), &value) in self.data.indexed_iter() {
+            let x = self.bbox.x + col as i32;
+            let y = self.bbox.y + row as i32;
+            if value && x >= 0 && y >= 0 && (x as usize) < width && (y as usize) < height {
+                full[[y as usize, x as usize]] = true;
+            }
+        }
+        full
+    }
+
+    /// Extracts the outer contour of every connected region of the mask.
+    ///
+    /// Regions are 8-connected and traced clockwise with Moore-neighbor tracing.
+    /// Points are in image coordinates. Holes are not reported.
+    pub fn contours(&self) -> Vec<Vec<(i32, i32)>> {
+        let (height, width) = self.data.dim();
+        let mut visited = Array2::from_elem((height, width), false);
+        let mut contours = Vec::new();
+
+        for row in 0..height {
+            for col in 0..width {
+                if !self.data[[row, col]] || visited[[row, col]] {
+                    continue;
+                }
+
+                self.mark_region(&mut visited, row, col);
+                let contour = self
+                    .trace_contour(col as i32, row as i32)
+                    .into_iter()
+                    .map(|(x, y)| (self.bbox.x + x, self.bbox.y + y))
+                    .collect();
+                contours.push(contour);
+            }
+        }
+
+        contours
+    }
+
+    /// Returns the mask value at local coordinates, `false` outside of the mask.
+    #[inline]
+    fn local(&self, x: i32, y: i32) -> bool {
+        let (height, width) = self.data.dim();
+        x >= 0 && y >= 0 && (x as usize) < width && (y as usize) < height && self.data[[y as usize, x as usize]]
+    }
+
+    /// Marks the 8-connected region containing `(row, col)` as visited.
+    fn mark_region(&self, visited: &mut Array2<bool>, row: usize, col: usize) {
+        let mut stack = vec![(col as i32, row as i32)];
+        visited[[row, col]] = true;
+        while let Some((x, y)) = stack.pop() {
+            for (dx, dy) in MOORE_NEIGHBORS {
+                let (nx, ny) = (x + dx, y + dy);
+                if self.local(nx, ny) && !visited[[ny as usize, nx as usize]] {
+                    visited[[ny as usize, nx as usize]] = true;
+                    stack.push((nx, ny));
+                }
+            }
+        }
+    }
+
+    /// Traces the outer boundary of the region whose first pixel (raster order) is `start`.
+    fn trace_contour(&self, start_x: i32, start_y: i32) -> Vec<(i32, i32)> {
+        let start = (start_x, start_y);
+        // The west neighbor of the first pixel in raster order is always background
+        let mut backtrack = 4;
+
+        let mut contour = vec![start];
+        let mut current = start;
+        let mut second = None;
+        // Each boundary pixel is entered at most 4 times
+        let max_steps = 4 * self.data.len() + 8;
+
+        for _ in 0..max_steps {
+            let next = (1..8).map(|k| (backtrack + k) % 8).find(|&dir| {
+                let (dx, dy) = MOORE_NEIGHBORS[dir];
+                self.local(current.0 + dx, current.1 + dy)
+            });
+
+            let Some(dir) = next else {
+                // Isolated pixel
+                break;
+            };
+
+            let (dx, dy) = MOORE_NEIGHBORS[dir];
+            let previous = current;
+            current = (current.0 + dx, current.1 + dy);
+
+            // Stop once the first boundary step is about to be repeated
+            if previous == start {
+                match second {
+                    None => second = Some(current),
+                    Some(first_step) if first_step == current => break,
+                    Some(_) => {}
+                }
+            }
+
+            // The last background neighbor checked becomes the new backtrack point
+            let (bx, by) = MOORE_NEIGHBORS[(dir + 7) % 8];
+            let background = (previous.0 + bx, previous.1 + by);
+            backtrack = MOORE_NEIGHBORS
+                .iter()
+                .position(|&(ox, oy)| (current.0 + ox, current.1 + oy) == background)
+                .unwrap_or((dir + 4) % 8);
+
+            contour.push(current);
+        }
+
+        // The trace ends by coming back to the start pixel
+        if contour.len() > 1 && contour.last() == Some(&start) {
+            contour.pop();
+        }
+
+        contour
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mask_contains_and_area() {
+        let mut data = Array2::from_elem((2, 3), false);
+        data[[1, 2]] = true;
+        let mask = Mask::new(BBox::new(10, 20, 3, 2), data);
+
+        assert!(mask.contains(12, 21));
+        assert!(!mask.contains(10, 20));
+        assert!(!mask.contains(100, 100));
+        assert_eq!(mask.area(), 1);
+
+        let full = mask.to_image_mask(16, 24);
+        assert!(full[[21, 12]]);
+        assert_eq!(full.iter().filter(|&&v| v).count(), 1);
+    }
+
+    #[test]
+    fn test_contour_rectangle() {
+        let mut data = Array2::from_elem((4, 5), false);
+        for row in 1..3 {
+            for col in 1..4 {
+                data[[row, col]] = true;
+            }
+        }
+        let mask = Mask::new(BBox::new(100, 200, 5, 4), data);
+
+        let contours = mask.contours();
+        assert_eq!(contours.len(), 1);
+        assert_eq!(
+            contours[0],
+            vec![(101, 201), (102, 201), (103, 201), (103, 202), (102, 202), (101, 202)]
+        );
+    }
+
+    #[test]
+    fn test_contour_multiple_regions() {
+        let mut data = Array2::from_elem((3, 6), false);
+        data[[0, 0]] = true;
+        data[[1, 3]] = true;
+        data[[1, 4]] = true;
+        data[[2, 4]] = true;
+        let mask = Mask::new(BBox::new(0, 0, 6, 3), data);
+
+        let contours = mask.contours();
+        assert_eq!(contours.len(), 2);
+        assert_eq!(contours[0], vec![(0, 0)]);
+        assert_eq!(contours[1], vec![(3, 1), (4, 1), (4, 2)]);
+    }
+}
@@ -15,6 +15,9 @@
 //! // ORT backend with CUDA
 //! let model = Model::ort_cuda("yolov8n.onnx", (640, 640))?;
 //!
+//! // ORT backend for NMS-free YOLOv10
+//! let model = Model::ort_v10("yolov10n.onnx", (640, 640))?;
+//!
 //! // OpenCV backend for Ultralytics models (CUDA)
 //! let model = Model::opencv("yolov8n.onnx", (640, 640), DnnBackend::Cuda, DnnTarget::Cuda)?;
 //!
@@ -22,76 +25,756 @@
 //! let model = Model::darknet("yolov4.cfg", "yolov4.weights", (416, 416), DnnBackend::Cuda, DnnTarget::Cuda)?;
 //! ```
 
-/// Factory for creating object detection models.
-///
-/// This is a zero-sized type that serves as a namespace for model constructors.
-/// Each method returns the concrete model type, enabling full compiler optimization.
-pub struct Model;
+/// Factory for creating object detection models.
+///
+/// This is a zero-sized type that serves as a namespace for model constructors.
+/// Each method returns the concrete model type, enabling full compiler optimization.
+pub struct Model;
+
+// ============================================================================
+// ORT Backend
+// ============================================================================
+
+#[cfg(feature = "ort-backend")]
+impl Model {
+    /// Creates a new Ultralytics YOLO model (v8/v9/v11) using ONNX Runtime (CPU).
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height), or `None` to read it
+    ///   from the Ultralytics export metadata
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut model = Model::ort("yolov8n.onnx", (640, 640))?;
+    /// let mut model = Model::ort("yolov8n.onnx", None)?;
+    /// ```
+    pub fn ort(
+        model_path: &str,
+        input_size: impl Into<Option<(u32, u32)>>,
+    ) -> Result<crate::backend_ort::ModelUltralyticsOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsOrt::new_from_file(model_path, input_size, vec![])
+    }
+
+    /// Creates a new Ultralytics YOLO model with class filtering using ONNX Runtime (CPU).
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    pub fn ort_filtered(
+        model_path: &str,
+        input_size: impl Into<Option<(u32, u32)>>,
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelUltralyticsOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsOrt::new_from_file(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new YOLOv10 (NMS-free) model using ONNX Runtime (CPU).
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut model = Model::ort_v10("yolov10n.onnx", (640, 640))?;
+    /// ```
+    pub fn ort_v10(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelYOLOv10Ort, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOv10Ort::new_from_file(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLOv10 model with class filtering using ONNX Runtime (CPU).
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    pub fn ort_v10_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelYOLOv10Ort, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOv10Ort::new_from_file(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new YOLOv5/YOLOv7 model (objectness-style output) using ONNX Runtime (CPU).
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut model = Model::ort_v5("yolov5s.onnx", (640, 640))?;
+    /// ```
+    pub fn ort_v5(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelYOLOv5Ort, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOv5Ort::new_from_file(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLOv5/YOLOv7 model with class filtering using ONNX Runtime (CPU).
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    pub fn ort_v5_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelYOLOv5Ort, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOv5Ort::new_from_file(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new classic YOLO model (v3/v4/v7) from an ONNX export using ONNX Runtime (CPU).
+    ///
+    /// This is the ORT counterpart of `Model::classic_onnx` and does not require OpenCV DNN.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut model = Model::ort_classic("yolov4-tiny.onnx", (416, 416))?;
+    /// ```
+    pub fn ort_classic(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelYOLOClassicOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOClassicOrt::new_from_file(model_path, input_size, vec![])
+    }
+
+    /// Creates a new classic YOLO model with class filtering using ONNX Runtime (CPU).
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    pub fn ort_classic_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelYOLOClassicOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOClassicOrt::new_from_file(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new YOLOX model using ONNX Runtime (CPU).
+    ///
+    /// Inputs are letterboxed BGR in the 0-255 range, as YOLOX expects.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut model = Model::ort_yolox("yolox_s.onnx", (640, 640))?;
+    /// ```
+    pub fn ort_yolox(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelYOLOXOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOXOrt::new_from_file(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLOX model with class filtering using ONNX Runtime (CPU).
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    pub fn ort_yolox_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelYOLOXOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOXOrt::new_from_file(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new RT-DETR model using ONNX Runtime (CPU).
+    ///
+    /// RT-DETR is NMS-free: `forward` only takes a confidence threshold.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut model = Model::ort_rtdetr("rtdetr-l.onnx", (640, 640))?;
+    /// ```
+    pub fn ort_rtdetr(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelRTDETROrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelRTDETROrt::new_from_file(model_path, input_size, vec![])
+    }
+
+    /// Creates a new RT-DETR model with class filtering using ONNX Runtime (CPU).
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    pub fn ort_rtdetr_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelRTDETROrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelRTDETROrt::new_from_file(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new YOLO-NAS model using ONNX Runtime (CPU).
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut model = Model::ort_nas("yolo_nas_s.onnx", (640, 640))?;
+    /// ```
+    pub fn ort_nas(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelYOLONASOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLONASOrt::new_from_file(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLO-NAS model with class filtering using ONNX Runtime (CPU).
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    pub fn ort_nas_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelYOLONASOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLONASOrt::new_from_file(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new end-to-end (embedded NMS) model using ONNX Runtime (CPU).
+    ///
+    /// Supports EfficientNMS and ONNX `NonMaxSuppression` outputs, NMS is not run by the crate.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut model = Model::ort_end2end("yolov7-tiny-nms.onnx", (640, 640))?;
+    /// ```
+    pub fn ort_end2end(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelEnd2EndOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelEnd2EndOrt::new_from_file(model_path, input_size, vec![])
+    }
+
+    /// Creates a new end-to-end (embedded NMS) model with class filtering using ONNX Runtime (CPU).
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    pub fn ort_end2end_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelEnd2EndOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelEnd2EndOrt::new_from_file(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new SSD-style (TF Object Detection API) model using ONNX Runtime (CPU).
+    ///
+    /// Input layout (NHWC/NCHW) and type (uint8/float32) are read from the model, NMS is part of the graph.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut model = Model::ort_ssd("ssd_mobilenet_v2.onnx", (300, 300))?;
+    /// ```
+    pub fn ort_ssd(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelSSDOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelSSDOrt::new_from_file(model_path, input_size, vec![])
+    }
+
+    /// Creates a new SSD-style (TF Object Detection API) model with class filtering using ONNX Runtime (CPU).
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    pub fn ort_ssd_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelSSDOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelSSDOrt::new_from_file(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new YOLOv8/v11 segmentation model using ONNX Runtime (CPU).
+    ///
+    /// Use `forward_masks` on the returned model to get per-detection masks.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut model = Model::ort_seg("yolov8n-seg.onnx", (640, 640))?;
+    /// ```
+    pub fn ort_seg(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelUltralyticsSegOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsSegOrt::new_from_file(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLOv8/v11 segmentation model with class filtering using ONNX Runtime (CPU).
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    pub fn ort_seg_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelUltralyticsSegOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsSegOrt::new_from_file(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new YOLOv8/v11 pose estimation model using ONNX Runtime (CPU).
+    ///
+    /// Use `forward_keypoints` on the returned model to get keypoints.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut model = Model::ort_pose("yolov8n-pose.onnx", (640, 640))?;
+    /// ```
+    pub fn ort_pose(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelUltralyticsPoseOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsPoseOrt::new_from_file(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLOv8/v11 pose estimation model with class filtering using ONNX Runtime (CPU).
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    pub fn ort_pose_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelUltralyticsPoseOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsPoseOrt::new_from_file(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new YOLOv8/v11 oriented bounding box model using ONNX Runtime (CPU).
+    ///
+    /// The returned model's `forward` yields `RotatedBBox` results.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut model = Model::ort_obb("yolov8n-obb.onnx", (1024, 1024))?;
+    /// ```
+    pub fn ort_obb(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelUltralyticsObbOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsObbOrt::new_from_file(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLOv8/v11 oriented bounding box model with class filtering using ONNX Runtime (CPU).
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `class_filters` - List of class indices to detect (empty for all classes)
+    pub fn ort_obb_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelUltralyticsObbOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsObbOrt::new_from_file(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new image classification model using ONNX Runtime (CPU).
+    ///
+    /// Defaults match Ultralytics `-cls` exports, see `ModelClassifierOrt` for other classifiers.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut model = Model::ort_classifier("yolov8n-cls.onnx", (224, 224))?;
+    /// let top5 = model.forward(&image, 5)?;
+    /// ```
+    pub fn ort_classifier(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelClassifierOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelClassifierOrt::new_from_file(model_path, input_size)
+    }
+
+    /// Creates a new face detection model (SCRFD or YOLOv8-face) using ONNX Runtime (CPU).
+    ///
+    /// The output format is detected from the model. Use `forward_landmarks` on the
+    /// returned model to get the five facial landmarks.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut model = Model::ort_face("scrfd_2.5g_kps.onnx", (640, 640))?;
+    /// ```
+    pub fn ort_face(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelFaceOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelFaceOrt::new_from_file(model_path, input_size)
+    }
+
+    /// Creates a new YOLO-World open-vocabulary model using ONNX Runtime (CPU).
+    ///
+    /// The classes to detect are given by precomputed text embeddings.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `embeddings` - Text embeddings of the classes to detect
+    ///
+    /// # Example
+    /// ```ignore
+    /// let embeddings = TextEmbeddings::from_npy("classes.npy", vec!["helmet".into(), "vest".into()])?;
+    /// let mut model = Model::ort_world("yolov8s-worldv2.onnx", (640, 640), embeddings)?;
+    /// ```
+    pub fn ort_world(
+        model_path: &str,
+        input_size: (u32, u32),
+        embeddings: crate::backend_ort::TextEmbeddings,
+    ) -> Result<crate::backend_ort::ModelYOLOWorldOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOWorldOrt::new_from_file(model_path, input_size, embeddings, vec![])
+    }
+
+    /// Creates a new YOLO-World model with class filtering using ONNX Runtime (CPU).
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    /// * `embeddings` - Text embeddings of the classes to detect
+    /// * `class_filters` - List of embedding indices to detect (empty for all classes)
+    pub fn ort_world_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        embeddings: crate::backend_ort::TextEmbeddings,
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelYOLOWorldOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOWorldOrt::new_from_file(model_path, input_size, embeddings, class_filters)
+    }
+
+    /// Loads an ONNX detection model with ONNX Runtime (CPU), picking the decoder from the model.
+    ///
+    /// Input/output names and shapes and the Ultralytics metadata select the output layout
+    /// (channel-first or row-major, with or without objectness, NMS-free or not), see
+    /// [`crate::backend_ort::OutputLayout`]. The input size is taken from the metadata or the
+    /// input shape (640x640 if both are unknown).
+    ///
+    /// # Errors
+    /// [`crate::backend_ort::OrtModelError::UnsupportedModel`] listing the detected inputs,
+    /// outputs and metadata when no decoder matches.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut model = Model::auto("yolov8n.onnx")?;
+    /// let (bboxes, class_ids, confidences) = model.detect(&image, 0.25, 0.45)?;
+    /// ```
+    pub fn auto(
+        model_path: &str,
+    ) -> Result<crate::backend_ort::BoxedDetector, crate::backend_ort::OrtModelError> {
+        let session = crate::backend_ort::session::session_from_file(model_path)?;
+        crate::backend_ort::OutputLayout::load(session, None)
+    }
+}
+
+#[cfg(feature = "ort-cuda-backend")]
+impl Model {
+    /// Creates a new Ultralytics YOLO model using ONNX Runtime with CUDA acceleration.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file
+    /// * `input_size` - Model input size as (width, height)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut model = Model::ort_cuda("yolov8n.onnx", (640, 640))?;
+    /// ```
+    pub fn ort_cuda(
+        model_path: &str,
+        input_size: impl Into<Option<(u32, u32)>>,
+    ) -> Result<crate::backend_ort::ModelUltralyticsOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsOrt::new_from_file_cuda(model_path, input_size, vec![])
+    }
+
+    /// Creates a new Ultralytics YOLO model with class filtering using ONNX Runtime with CUDA.
+    pub fn ort_cuda_filtered(
+        model_path: &str,
+        input_size: impl Into<Option<(u32, u32)>>,
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelUltralyticsOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsOrt::new_from_file_cuda(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new YOLOv10 model using ONNX Runtime with CUDA acceleration.
+    pub fn ort_v10_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelYOLOv10Ort, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOv10Ort::new_from_file_cuda(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLOv10 model with class filtering using ONNX Runtime with CUDA.
+    pub fn ort_v10_cuda_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelYOLOv10Ort, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOv10Ort::new_from_file_cuda(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new YOLOv5/YOLOv7 model using ONNX Runtime with CUDA acceleration.
+    pub fn ort_v5_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelYOLOv5Ort, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOv5Ort::new_from_file_cuda(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLOv5/YOLOv7 model with class filtering using ONNX Runtime with CUDA.
+    pub fn ort_v5_cuda_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelYOLOv5Ort, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOv5Ort::new_from_file_cuda(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new classic YOLO model (v3/v4/v7) using ONNX Runtime with CUDA acceleration.
+    pub fn ort_classic_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelYOLOClassicOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOClassicOrt::new_from_file_cuda(model_path, input_size, vec![])
+    }
+
+    /// Creates a new classic YOLO model with class filtering using ONNX Runtime with CUDA.
+    pub fn ort_classic_cuda_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelYOLOClassicOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOClassicOrt::new_from_file_cuda(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new YOLOX model using ONNX Runtime with CUDA acceleration.
+    pub fn ort_yolox_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelYOLOXOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOXOrt::new_from_file_cuda(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLOX model with class filtering using ONNX Runtime with CUDA.
+    pub fn ort_yolox_cuda_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelYOLOXOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOXOrt::new_from_file_cuda(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new RT-DETR model using ONNX Runtime with CUDA acceleration.
+    pub fn ort_rtdetr_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelRTDETROrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelRTDETROrt::new_from_file_cuda(model_path, input_size, vec![])
+    }
+
+    /// Creates a new RT-DETR model with class filtering using ONNX Runtime with CUDA.
+    pub fn ort_rtdetr_cuda_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelRTDETROrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelRTDETROrt::new_from_file_cuda(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new YOLO-NAS model using ONNX Runtime with CUDA acceleration.
+    pub fn ort_nas_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelYOLONASOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLONASOrt::new_from_file_cuda(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLO-NAS model with class filtering using ONNX Runtime with CUDA.
+    pub fn ort_nas_cuda_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelYOLONASOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLONASOrt::new_from_file_cuda(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new end-to-end (embedded NMS) model using ONNX Runtime with CUDA acceleration.
+    pub fn ort_end2end_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelEnd2EndOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelEnd2EndOrt::new_from_file_cuda(model_path, input_size, vec![])
+    }
+
+    /// Creates a new end-to-end (embedded NMS) model with class filtering using ONNX Runtime with CUDA.
+    pub fn ort_end2end_cuda_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelEnd2EndOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelEnd2EndOrt::new_from_file_cuda(model_path, input_size, class_filters)
+    }
 
-// ============================================================================
-// ORT Backend
-// ============================================================================
+    /// Creates a new SSD-style (TF Object Detection API) model using ONNX Runtime with CUDA acceleration.
+    pub fn ort_ssd_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelSSDOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelSSDOrt::new_from_file_cuda(model_path, input_size, vec![])
+    }
 
-#[cfg(feature = "ort-backend")]
-impl Model {
-    /// Creates a new Ultralytics YOLO model (v8/v9/v11) using ONNX Runtime (CPU).
-    ///
-    /// # Arguments
-    /// * `model_path` - Path to the ONNX model file
-    /// * `input_size` - Model input size as (width, height)
-    ///
-    /// # Example
-    /// ```ignore
-    /// let mut model = Model::ort("yolov8n.onnx", (640, 640))?;
-    /// ```
-    pub fn ort(
+    /// Creates a new SSD-style (TF Object Detection API) model with class filtering using ONNX Runtime with CUDA.
+    pub fn ort_ssd_cuda_filtered(
         model_path: &str,
         input_size: (u32, u32),
-    ) -> Result<crate::backend_ort::ModelUltralyticsOrt, crate::backend_ort::OrtModelError> {
-        crate::backend_ort::ModelUltralyticsOrt::new_from_file(model_path, input_size, vec![])
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelSSDOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelSSDOrt::new_from_file_cuda(model_path, input_size, class_filters)
     }
 
-    /// Creates a new Ultralytics YOLO model with class filtering using ONNX Runtime (CPU).
-    ///
-    /// # Arguments
-    /// * `model_path` - Path to the ONNX model file
-    /// * `input_size` - Model input size as (width, height)
-    /// * `class_filters` - List of class indices to detect (empty for all classes)
-    pub fn ort_filtered(
+    /// Creates a new YOLOv8/v11 segmentation model using ONNX Runtime with CUDA acceleration.
+    pub fn ort_seg_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelUltralyticsSegOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsSegOrt::new_from_file_cuda(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLOv8/v11 segmentation model with class filtering using ONNX Runtime with CUDA.
+    pub fn ort_seg_cuda_filtered(
         model_path: &str,
         input_size: (u32, u32),
         class_filters: Vec<usize>,
-    ) -> Result<crate::backend_ort::ModelUltralyticsOrt, crate::backend_ort::OrtModelError> {
-        crate::backend_ort::ModelUltralyticsOrt::new_from_file(model_path, input_size, class_filters)
+    ) -> Result<crate::backend_ort::ModelUltralyticsSegOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsSegOrt::new_from_file_cuda(model_path, input_size, class_filters)
     }
-}
 
-#[cfg(feature = "ort-cuda-backend")]
-impl Model {
-    /// Creates a new Ultralytics YOLO model using ONNX Runtime with CUDA acceleration.
-    ///
-    /// # Arguments
-    /// * `model_path` - Path to the ONNX model file
-    /// * `input_size` - Model input size as (width, height)
-    ///
-    /// # Example
-    /// ```ignore
-    /// let mut model = Model::ort_cuda("yolov8n.onnx", (640, 640))?;
-    /// ```
-    pub fn ort_cuda(
+    /// Creates a new YOLOv8/v11 pose estimation model using ONNX Runtime with CUDA acceleration.
+    pub fn ort_pose_cuda(
         model_path: &str,
         input_size: (u32, u32),
-    ) -> Result<crate::backend_ort::ModelUltralyticsOrt, crate::backend_ort::OrtModelError> {
-        crate::backend_ort::ModelUltralyticsOrt::new_from_file_cuda(model_path, input_size, vec![])
+    ) -> Result<crate::backend_ort::ModelUltralyticsPoseOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsPoseOrt::new_from_file_cuda(model_path, input_size, vec![])
     }
 
-    /// Creates a new Ultralytics YOLO model with class filtering using ONNX Runtime with CUDA.
-    pub fn ort_cuda_filtered(
+    /// Creates a new YOLOv8/v11 pose estimation model with class filtering using ONNX Runtime with CUDA.
+    pub fn ort_pose_cuda_filtered(
         model_path: &str,
         input_size: (u32, u32),
         class_filters: Vec<usize>,
-    ) -> Result<crate::backend_ort::ModelUltralyticsOrt, crate::backend_ort::OrtModelError> {
-        crate::backend_ort::ModelUltralyticsOrt::new_from_file_cuda(model_path, input_size, class_filters)
+    ) -> Result<crate::backend_ort::ModelUltralyticsPoseOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsPoseOrt::new_from_file_cuda(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new YOLOv8/v11 oriented bounding box model using ONNX Runtime with CUDA acceleration.
+    pub fn ort_obb_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelUltralyticsObbOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsObbOrt::new_from_file_cuda(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLOv8/v11 oriented bounding box model with class filtering using ONNX Runtime with CUDA.
+    pub fn ort_obb_cuda_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelUltralyticsObbOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsObbOrt::new_from_file_cuda(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new image classification model using ONNX Runtime with CUDA acceleration.
+    pub fn ort_classifier_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelClassifierOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelClassifierOrt::new_from_file_cuda(model_path, input_size)
+    }
+
+    /// Creates a new face detection model using ONNX Runtime with CUDA acceleration.
+    pub fn ort_face_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelFaceOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelFaceOrt::new_from_file_cuda(model_path, input_size)
+    }
+
+    /// Creates a new YOLO-World model using ONNX Runtime with CUDA acceleration.
+    pub fn ort_world_cuda(
+        model_path: &str,
+        input_size: (u32, u32),
+        embeddings: crate::backend_ort::TextEmbeddings,
+    ) -> Result<crate::backend_ort::ModelYOLOWorldOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOWorldOrt::new_from_file_cuda(model_path, input_size, embeddings, vec![])
+    }
+
+    /// Loads an ONNX detection model with ONNX Runtime and CUDA acceleration, picking the decoder from the model.
+    pub fn auto_cuda(
+        model_path: &str,
+    ) -> Result<crate::backend_ort::BoxedDetector, crate::backend_ort::OrtModelError> {
+        let session = crate::backend_ort::session::session_from_file_cuda(model_path)?;
+        crate::backend_ort::OutputLayout::load(session, None)
     }
 }
 
@@ -109,7 +792,7 @@ impl Model {
     /// ```
     pub fn ort_tensorrt(
         model_path: &str,
-        input_size: (u32, u32),
+        input_size: impl Into<Option<(u32, u32)>>,
     ) -> Result<crate::backend_ort::ModelUltralyticsOrt, crate::backend_ort::OrtModelError> {
         crate::backend_ort::ModelUltralyticsOrt::new_from_file_tensorrt(model_path, input_size, vec![])
     }
@@ -117,11 +800,231 @@ impl Model {
     /// Creates a new Ultralytics YOLO model with class filtering using TensorRT.
     pub fn ort_tensorrt_filtered(
         model_path: &str,
-        input_size: (u32, u32),
+        input_size: impl Into<Option<(u32, u32)>>,
         class_filters: Vec<usize>,
     ) -> Result<crate::backend_ort::ModelUltralyticsOrt, crate::backend_ort::OrtModelError> {
         crate::backend_ort::ModelUltralyticsOrt::new_from_file_tensorrt(model_path, input_size, class_filters)
     }
+
+    /// Creates a new YOLOv10 model using ONNX Runtime with TensorRT acceleration.
+    pub fn ort_v10_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelYOLOv10Ort, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOv10Ort::new_from_file_tensorrt(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLOv10 model with class filtering using TensorRT.
+    pub fn ort_v10_tensorrt_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelYOLOv10Ort, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOv10Ort::new_from_file_tensorrt(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new YOLOv5/YOLOv7 model using ONNX Runtime with TensorRT acceleration.
+    pub fn ort_v5_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelYOLOv5Ort, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOv5Ort::new_from_file_tensorrt(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLOv5/YOLOv7 model with class filtering using TensorRT.
+    pub fn ort_v5_tensorrt_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelYOLOv5Ort, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOv5Ort::new_from_file_tensorrt(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new classic YOLO model (v3/v4/v7) using ONNX Runtime with TensorRT acceleration.
+    pub fn ort_classic_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelYOLOClassicOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOClassicOrt::new_from_file_tensorrt(model_path, input_size, vec![])
+    }
+
+    /// Creates a new classic YOLO model with class filtering using TensorRT.
+    pub fn ort_classic_tensorrt_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelYOLOClassicOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOClassicOrt::new_from_file_tensorrt(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new YOLOX model using ONNX Runtime with TensorRT acceleration.
+    pub fn ort_yolox_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelYOLOXOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOXOrt::new_from_file_tensorrt(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLOX model with class filtering using TensorRT.
+    pub fn ort_yolox_tensorrt_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelYOLOXOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOXOrt::new_from_file_tensorrt(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new RT-DETR model using ONNX Runtime with TensorRT acceleration.
+    pub fn ort_rtdetr_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelRTDETROrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelRTDETROrt::new_from_file_tensorrt(model_path, input_size, vec![])
+    }
+
+    /// Creates a new RT-DETR model with class filtering using TensorRT.
+    pub fn ort_rtdetr_tensorrt_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelRTDETROrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelRTDETROrt::new_from_file_tensorrt(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new YOLO-NAS model using ONNX Runtime with TensorRT acceleration.
+    pub fn ort_nas_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelYOLONASOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLONASOrt::new_from_file_tensorrt(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLO-NAS model with class filtering using TensorRT.
+    pub fn ort_nas_tensorrt_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelYOLONASOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLONASOrt::new_from_file_tensorrt(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new end-to-end (embedded NMS) model using ONNX Runtime with TensorRT acceleration.
+    pub fn ort_end2end_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelEnd2EndOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelEnd2EndOrt::new_from_file_tensorrt(model_path, input_size, vec![])
+    }
+
+    /// Creates a new end-to-end (embedded NMS) model with class filtering using TensorRT.
+    pub fn ort_end2end_tensorrt_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelEnd2EndOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelEnd2EndOrt::new_from_file_tensorrt(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new SSD-style (TF Object Detection API) model using ONNX Runtime with TensorRT acceleration.
+    pub fn ort_ssd_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelSSDOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelSSDOrt::new_from_file_tensorrt(model_path, input_size, vec![])
+    }
+
+    /// Creates a new SSD-style (TF Object Detection API) model with class filtering using TensorRT.
+    pub fn ort_ssd_tensorrt_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelSSDOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelSSDOrt::new_from_file_tensorrt(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new YOLOv8/v11 segmentation model using ONNX Runtime with TensorRT acceleration.
+    pub fn ort_seg_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelUltralyticsSegOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsSegOrt::new_from_file_tensorrt(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLOv8/v11 segmentation model with class filtering using TensorRT.
+    pub fn ort_seg_tensorrt_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelUltralyticsSegOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsSegOrt::new_from_file_tensorrt(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new YOLOv8/v11 pose estimation model using ONNX Runtime with TensorRT acceleration.
+    pub fn ort_pose_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelUltralyticsPoseOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsPoseOrt::new_from_file_tensorrt(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLOv8/v11 pose estimation model with class filtering using TensorRT.
+    pub fn ort_pose_tensorrt_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelUltralyticsPoseOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsPoseOrt::new_from_file_tensorrt(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new YOLOv8/v11 oriented bounding box model using ONNX Runtime with TensorRT acceleration.
+    pub fn ort_obb_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelUltralyticsObbOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsObbOrt::new_from_file_tensorrt(model_path, input_size, vec![])
+    }
+
+    /// Creates a new YOLOv8/v11 oriented bounding box model with class filtering using TensorRT.
+    pub fn ort_obb_tensorrt_filtered(
+        model_path: &str,
+        input_size: (u32, u32),
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_ort::ModelUltralyticsObbOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelUltralyticsObbOrt::new_from_file_tensorrt(model_path, input_size, class_filters)
+    }
+
+    /// Creates a new image classification model using ONNX Runtime with TensorRT acceleration.
+    pub fn ort_classifier_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelClassifierOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelClassifierOrt::new_from_file_tensorrt(model_path, input_size)
+    }
+
+    /// Creates a new face detection model using ONNX Runtime with TensorRT acceleration.
+    pub fn ort_face_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+    ) -> Result<crate::backend_ort::ModelFaceOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelFaceOrt::new_from_file_tensorrt(model_path, input_size)
+    }
+
+    /// Creates a new YOLO-World model using ONNX Runtime with TensorRT acceleration.
+    pub fn ort_world_tensorrt(
+        model_path: &str,
+        input_size: (u32, u32),
+        embeddings: crate::backend_ort::TextEmbeddings,
+    ) -> Result<crate::backend_ort::ModelYOLOWorldOrt, crate::backend_ort::OrtModelError> {
+        crate::backend_ort::ModelYOLOWorldOrt::new_from_file_tensorrt(model_path, input_size, embeddings, vec![])
+    }
+
+    /// Loads an ONNX detection model with ONNX Runtime and TensorRT acceleration, picking the decoder from the model.
+    pub fn auto_tensorrt(
+        model_path: &str,
+    ) -> Result<crate::backend_ort::BoxedDetector, crate::backend_ort::OrtModelError> {
+        let session = crate::backend_ort::session::session_from_file_tensorrt(model_path)?;
+        crate::backend_ort::OutputLayout::load(session, None)
+    }
 }
 
 // ============================================================================
@@ -189,10 +1092,13 @@ impl Model {
 
     /// Creates a new classic YOLO model (v3/v4/v7) from Darknet files using OpenCV DNN.
     ///
+    /// The .cfg file is parsed to validate `input_size` against its `[net]` section,
+    /// pass `None` to take the input size from the cfg.
+    ///
     /// # Arguments
     /// * `cfg_path` - Path to the Darknet .cfg file
     /// * `weights_path` - Path to the Darknet .weights file
-    /// * `input_size` - Model input size as (width, height)
+    /// * `input_size` - Model input size as (width, height), or `None` to read it from the cfg
     /// * `backend` - DNN backend
     /// * `target` - DNN target device
     ///
@@ -203,7 +1109,7 @@ impl Model {
     /// let mut model = Model::darknet(
     ///     "yolov4.cfg",
     ///     "yolov4.weights",
-    ///     (416, 416),
+    ///     None,
     ///     DnnBackend::Cuda,
     ///     DnnTarget::Cuda
     /// )?;
@@ -211,7 +1117,7 @@ impl Model {
     pub fn darknet(
         cfg_path: &str,
         weights_path: &str,
-        input_size: (i32, i32),
+        input_size: impl Into<Option<(i32, i32)>>,
         backend: crate::dnn_backend::DnnBackend,
         target: crate::dnn_backend::DnnTarget,
     ) -> Result<crate::backend_opencv::model_classic::ModelYOLOClassic, opencv::Error> {
@@ -230,14 +1136,14 @@ impl Model {
     /// # Arguments
     /// * `cfg_path` - Path to the Darknet .cfg file
     /// * `weights_path` - Path to the Darknet .weights file
-    /// * `input_size` - Model input size as (width, height)
+    /// * `input_size` - Model input size as (width, height), or `None` to read it from the cfg
     /// * `backend` - DNN backend
     /// * `target` - DNN target device
     /// * `class_filters` - List of class indices to detect (empty for all)
     pub fn darknet_filtered(
         cfg_path: &str,
         weights_path: &str,
-        input_size: (i32, i32),
+        input_size: impl Into<Option<(i32, i32)>>,
         backend: crate::dnn_backend::DnnBackend,
         target: crate::dnn_backend::DnnTarget,
         class_filters: Vec<usize>,
@@ -309,4 +1215,69 @@ impl Model {
             class_filters,
         )
     }
+
+    /// Creates a new SSD-style model (MobileNet-SSD, TF Object Detection API) using OpenCV DNN.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the model file (.pb, .caffemodel, .onnx)
+    /// * `config_path` - Optional path to the text graph (.pbtxt, .prototxt)
+    /// * `input_size` - Model input size as (width, height)
+    /// * `backend` - DNN backend
+    /// * `target` - DNN target device
+    ///
+    /// # Example
+    /// ```ignore
+    /// use od_opencv::{Model, DnnBackend, DnnTarget};
+    ///
+    /// let mut model = Model::ssd(
+    ///     "frozen_inference_graph.pb",
+    ///     Some("ssd_mobilenet_v2_coco.pbtxt"),
+    ///     (300, 300),
+    ///     DnnBackend::OpenCV,
+    ///     DnnTarget::Cpu
+    /// )?;
+    /// ```
+    pub fn ssd(
+        model_path: &str,
+        config_path: Option<&str>,
+        input_size: (i32, i32),
+        backend: crate::dnn_backend::DnnBackend,
+        target: crate::dnn_backend::DnnTarget,
+    ) -> Result<crate::backend_opencv::model_ssd::ModelSSD, opencv::Error> {
+        crate::backend_opencv::model_ssd::ModelSSD::new_from_file(
+            model_path,
+            config_path,
+            input_size,
+            backend.into(),
+            target.into(),
+            vec![],
+        )
+    }
+
+    /// Creates a new SSD-style model with class filtering using OpenCV DNN.
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the model file (.pb, .caffemodel, .onnx)
+    /// * `config_path` - Optional path to the text graph (.pbtxt, .prototxt)
+    /// * `input_size` - Model input size as (width, height)
+    /// * `backend` - DNN backend
+    /// * `target` - DNN target device
+    /// * `class_filters` - List of class indices to detect (empty for all)
+    pub fn ssd_filtered(
+        model_path: &str,
+        config_path: Option<&str>,
+        input_size: (i32, i32),
+        backend: crate::dnn_backend::DnnBackend,
+        target: crate::dnn_backend::DnnTarget,
+        class_filters: Vec<usize>,
+    ) -> Result<crate::backend_opencv::model_ssd::ModelSSD, opencv::Error> {
+        crate::backend_opencv::model_ssd::ModelSSD::new_from_file(
+            model_path,
+            config_path,
+            input_size,
+            backend.into(),
+            target.into(),
+            class_filters,
+        )
+    }
 }
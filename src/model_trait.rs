@@ -1,10 +1,16 @@
 //! Backend-agnostic object detection trait.
 //!
 //! This module defines the `ObjectDetector` trait that provides a common interface
-//! for object detection across different inference backends (OpenCV DNN, ONNX Runtime, etc.).
+//! for object detection across different inference backends (OpenCV DNN, ONNX Runtime, etc.),
+//! and the parallel `ImageClassifier` trait for classification models.
 
 use crate::BBox;
 
+/// Detection results: bounding boxes, class IDs and confidence scores, one entry per detection.
+///
+/// The box type defaults to [`BBox`]; the OpenCV APIs use `DetectionVecs<Rect>`.
+pub type DetectionVecs<B = BBox> = (Vec<B>, Vec<usize>, Vec<f32>);
+
 /// A trait for object detection models.
 ///
 /// This trait provides a backend-agnostic interface for running object detection.
@@ -52,5 +58,62 @@ pub trait ObjectDetector {
         input: &Self::Input,
         conf_threshold: f32,
         nms_threshold: f32,
-    ) -> Result<(Vec<BBox>, Vec<usize>, Vec<f32>), Self::Error>;
+    ) -> Result<DetectionVecs, Self::Error>;
+
+    /// Runs object detection on several images.
+    ///
+    /// Detectors able to run a whole batch in one inference override this method.
+    /// The default implementation calls [`Self::detect`] for every image.
+    ///
+    /// # Returns
+    /// One `(bboxes, class_ids, confidences)` tuple per input image, in input order.
+    fn detect_batch(
+        &mut self,
+        inputs: &[Self::Input],
+        conf_threshold: f32,
+        nms_threshold: f32,
+    ) -> Result<Vec<DetectionVecs>, Self::Error> {
+        inputs
+            .iter()
+            .map(|input| self.detect(input, conf_threshold, nms_threshold))
+            .collect()
+    }
+}
+
+/// A trait for image classification models.
+///
+/// This is the classification counterpart of [`ObjectDetector`].
+///
+/// # Example
+///
+/// ```ignore
+/// use od_opencv::ImageClassifier;
+///
+/// fn best_class<C: ImageClassifier>(
+///     classifier: &mut C,
+///     input: &C::Input,
+/// ) -> Result<Option<usize>, C::Error> {
+///     Ok(classifier.classify(input, 1)?.first().map(|&(class_id, _)| class_id))
+/// }
+/// ```
+pub trait ImageClassifier {
+    /// The input image type for this classifier.
+    type Input;
+
+    /// The error type for this classifier.
+    type Error;
+
+    /// Classifies the input image.
+    ///
+    /// # Arguments
+    /// * `input` - The input image
+    /// * `top_k` - Maximum number of classes to return
+    ///
+    /// # Returns
+    /// Up to `top_k` pairs of (class ID, probability), sorted by descending probability
+    fn classify(
+        &mut self,
+        input: &Self::Input,
+        top_k: usize,
+    ) -> Result<Vec<(usize, f32)>, Self::Error>;
 }
@@ -19,6 +19,7 @@ use opencv::{
 };
 
 use crate::preprocessing::{LetterboxMeta, StretchMeta, PreprocessMeta};
+use crate::model_trait::DetectionVecs;
 
 /// A trait for object detection models that work with OpenCV Mat.
 ///
@@ -41,7 +42,7 @@ pub trait ModelTrait {
         image: &Mat,
         conf_threshold: f32,
         nms_threshold: f32,
-    ) -> Result<(Vec<Rect>, Vec<usize>, Vec<f32>), OpenCvError>;
+    ) -> Result<DetectionVecs<Rect>, OpenCvError>;
 }
 
 /// Converts an OpenCV Mat to an ndarray ArrayView3 (zero-copy when possible).
@@ -183,7 +184,9 @@ pub fn resize_mat_stretch(
 
 /// Resizes a BGR Mat with letterbox padding, returns metadata for inverse transform.
 ///
-/// Preserves aspect ratio by scaling uniformly and padding with gray (114, 114, 114).
+/// Preserves aspect ratio by scaling uniformly and padding with gray (114, 114, 114)
+/// to the full target size. See [`resize_mat_letterbox_auto`] for the stride-multiple
+/// variant used with dynamic axes, kept separate so that this signature stays unchanged.
 ///
 /// # Arguments
 /// * `mat` - Input BGR Mat
@@ -197,27 +200,33 @@ pub fn resize_mat_letterbox(
     target_width: i32,
     target_height: i32,
 ) -> Result<(Mat, LetterboxMeta), OpenCvError> {
-    let orig_width = mat.cols();
-    let orig_height = mat.rows();
-
-    let scale = f32::min(
-        target_width as f32 / orig_width as f32,
-        target_height as f32 / orig_height as f32,
-    );
-
-    let new_width = (orig_width as f32 * scale).round() as i32;
-    let new_height = (orig_height as f32 * scale).round() as i32;
+    let meta = LetterboxMeta::new(mat.cols(), mat.rows(), target_width, target_height, None);
+    let padded = apply_mat_letterbox(mat, &meta)?;
+    Ok((padded, meta))
+}
 
-    let pad_left = (target_width - new_width) / 2;
-    let pad_top = (target_height - new_height) / 2;
+/// Resizes a BGR Mat with letterbox padding to the smallest rectangle whose sides
+/// are multiples of `stride` (Ultralytics `auto=True`).
+///
+/// The result fits in `target_width` x `target_height` but is usually smaller, so it
+/// is meant for models exported with dynamic input axes.
+///
+/// # Returns
+/// Tuple of (letterboxed Mat, LetterboxMeta)
+pub fn resize_mat_letterbox_auto(
+    mat: &Mat,
+    target_width: i32,
+    target_height: i32,
+    stride: i32,
+) -> Result<(Mat, LetterboxMeta), OpenCvError> {
+    let meta = LetterboxMeta::new(mat.cols(), mat.rows(), target_width, target_height, Some(stride));
+    let padded = apply_mat_letterbox(mat, &meta)?;
+    Ok((padded, meta))
+}
 
-    let meta = LetterboxMeta {
-        scale,
-        pad_left,
-        pad_top,
-        original_width: orig_width,
-        original_height: orig_height,
-    };
+/// Scales and pads a BGR Mat as described by the letterbox metadata.
+fn apply_mat_letterbox(mat: &Mat, meta: &LetterboxMeta) -> Result<Mat, OpenCvError> {
+    let (new_width, new_height) = meta.resized_size();
 
     // Resize to new dimensions
     let mut resized = Mat::default();
@@ -230,24 +239,20 @@ pub fn resize_mat_letterbox(
         imgproc::INTER_LINEAR,
     )?;
 
-    // Calculate padding for right and bottom
-    let pad_right = target_width - new_width - pad_left;
-    let pad_bottom = target_height - new_height - pad_top;
-
     // Add border padding with gray color (BGR: 114, 114, 114)
     let mut padded = Mat::default();
     opencv::core::copy_make_border(
         &resized,
         &mut padded,
-        pad_top,
-        pad_bottom,
-        pad_left,
-        pad_right,
+        meta.pad_top,
+        meta.pad_bottom,
+        meta.pad_left,
+        meta.pad_right,
         BORDER_CONSTANT,
         Scalar::new(114.0, 114.0, 114.0, 0.0),
     )?;
 
-    Ok((padded, meta))
+    Ok(padded)
 }
 
 /// Full preprocessing pipeline for BGR Mat: resize + convert to tensor.
@@ -287,6 +292,26 @@ pub fn preprocess_mat(
     Ok((tensor, meta))
 }
 
+/// Preprocessing pipeline for BGR Mat and models with dynamic input axes:
+/// minimal-rectangle letterbox (see [`resize_mat_letterbox_auto`]) + convert to tensor.
+///
+/// # Returns
+/// Tuple of (NCHW f32 tensor in RGB, PreprocessMeta)
+pub fn preprocess_mat_letterbox_auto(
+    mat: &Mat,
+    target_width: u32,
+    target_height: u32,
+    stride: u32,
+) -> Result<(Array4<f32>, PreprocessMeta), OpenCvError> {
+    let (resized, meta) =
+        resize_mat_letterbox_auto(mat, target_width as i32, target_height as i32, stride as i32)?;
+
+    let bgr_view = mat_to_array_view(&resized)?;
+    let tensor = crate::preprocessing::bgr_hwc_to_rgb_nchw_tensor(&bgr_view);
+
+    Ok((tensor, PreprocessMeta::Letterbox(meta)))
+}
+
 // Tests are ignored due to OpenCV library loading issues in test environment.
 // These functions are tested through integration tests in the actual application.
 #[cfg(test)]
@@ -353,6 +378,25 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[ignore = "OpenCV tests require proper library loading - test in application"]
+    fn test_resize_mat_letterbox_auto() -> Result<(), OpenCvError> {
+        let mat = Mat::new_rows_cols_with_default(
+            720,
+            1280,
+            opencv::core::CV_8UC3,
+            Scalar::new(128.0, 128.0, 128.0, 0.0),
+        )?;
+
+        let (resized, meta) = resize_mat_letterbox_auto(&mat, 640, 640, 32)?;
+
+        assert_eq!(resized.cols(), 640);
+        assert_eq!(resized.rows(), 384);
+        assert_eq!((meta.pad_top, meta.pad_bottom), (12, 12));
+
+        Ok(())
+    }
+
     #[test]
     #[ignore = "OpenCV tests require proper library loading - test in application"]
     fn test_preprocess_mat() -> Result<(), OpenCvError> {
@@ -4,6 +4,9 @@
 //! Non-Maximum Suppression (NMS) and output parsing.
 
 use crate::bbox::BBox;
+use crate::model_trait::DetectionVecs;
+use crate::preprocessing::PreprocessMeta;
+use crate::rotated_bbox::RotatedBBox;
 
 /// A single detection before NMS filtering.
 #[derive(Debug, Clone)]
@@ -40,6 +43,24 @@ impl Detection {
 /// # Returns
 /// Filtered set of detections
 pub fn nms(detections: &[Detection], iou_threshold: f32) -> Vec<Detection> {
+    nms_indices(detections, iou_threshold)
+        .into_iter()
+        .map(|idx| detections[idx].clone())
+        .collect()
+}
+
+/// Performs Non-Maximum Suppression and returns the indices of the kept detections.
+///
+/// Same as [`nms`], useful when detections carry extra per-box data (e.g. mask
+/// coefficients or keypoints) stored alongside them.
+///
+/// # Arguments
+/// * `detections` - array of detections to filter
+/// * `iou_threshold` - IoU threshold for considering boxes as overlapping (0.0 - 1.0)
+///
+/// # Returns
+/// Indices into `detections`, sorted by descending confidence
+pub fn nms_indices(detections: &[Detection], iou_threshold: f32) -> Vec<usize> {
     if detections.is_empty() {
         return Vec::new();
     }
@@ -60,7 +81,7 @@ pub fn nms(detections: &[Detection], iou_threshold: f32) -> Vec<Detection> {
             continue;
         }
 
-        keep.push((*detection).clone());
+        keep.push(*orig_idx);
 
         // Suppress all detections with high IoU
         for (other_orig_idx, other) in sorted.iter() {
@@ -117,6 +138,517 @@ pub fn nms_class_aware(detections: &[Detection], iou_threshold: f32) -> Vec<Dete
     result
 }
 
+/// A single oriented detection before NMS filtering.
+#[derive(Debug, Clone)]
+pub struct RotatedDetection {
+    /// Rotated bounding box
+    pub rbox: RotatedBBox,
+    /// Class index
+    pub class_id: usize,
+    /// Confidence score
+    pub confidence: f32,
+}
+
+impl RotatedDetection {
+    /// Creates a new oriented detection.
+    #[inline]
+    pub fn new(rbox: RotatedBBox, class_id: usize, confidence: f32) -> Self {
+        Self {
+            rbox,
+            class_id,
+            confidence,
+        }
+    }
+}
+
+/// Performs Non-Maximum Suppression on oriented detections using rotated IoU.
+///
+/// # Arguments
+/// * `detections` - array of detections to filter
+/// * `iou_threshold` - IoU threshold for considering boxes as overlapping (0.0 - 1.0)
+///
+/// # Returns
+/// Filtered set of detections, sorted by descending confidence
+pub fn nms_rotated(detections: &[RotatedDetection], iou_threshold: f32) -> Vec<RotatedDetection> {
+    // Hard NMS ignores the score threshold
+    NmsStrategy::Greedy.apply_rotated(detections, iou_threshold, 0.0)
+}
+
+/// Non-Maximum Suppression algorithm used to remove duplicate detections.
+///
+/// The default is [`NmsStrategy::Greedy`], the classic hard NMS used by [`nms`].
+/// Soft and Matrix variants decay the confidence of overlapping boxes instead of
+/// removing them, which keeps more true positives in crowded scenes; boxes whose
+/// decayed confidence falls below the score threshold are dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum NmsStrategy {
+    /// Hard NMS across all classes: boxes with IoU above the threshold are removed
+    #[default]
+    Greedy,
+    /// Hard NMS, a box only suppresses boxes of the same class
+    ClassAware,
+    /// Soft-NMS, confidences of boxes with IoU above the threshold are multiplied by `1 - IoU`
+    SoftLinear,
+    /// Soft-NMS, every overlapping box confidence is multiplied by `exp(-IoU² / sigma)`.
+    /// The IoU threshold is not used. `sigma = 0.5` is the usual choice
+    SoftGaussian {
+        /// Width of the gaussian penalty
+        sigma: f32,
+    },
+    /// Hard NMS on Distance-IoU: the IoU is reduced by the normalized distance between
+    /// box centers, so close but distinct objects are kept
+    DIoU,
+    /// Matrix NMS (SOLOv2): all confidences are decayed in parallel with a gaussian
+    /// kernel, `exp(-sigma * (IoU² - compensation²))`. The IoU threshold is not used.
+    /// `sigma = 2.0` is the usual choice
+    Matrix {
+        /// Strength of the gaussian decay
+        sigma: f32,
+    },
+}
+
+impl NmsStrategy {
+    /// Applies the strategy to detections.
+    ///
+    /// # Arguments
+    /// * `detections` - array of detections to filter
+    /// * `iou_threshold` - IoU threshold for considering boxes as overlapping (0.0 - 1.0)
+    /// * `score_threshold` - Minimum confidence after decay (Soft-NMS and Matrix NMS)
+    ///
+    /// # Returns
+    /// Kept detections with their (possibly decayed) confidences, sorted by descending confidence
+    pub fn apply(
+        &self,
+        detections: &[Detection],
+        iou_threshold: f32,
+        score_threshold: f32,
+    ) -> Vec<Detection> {
+        self.apply_indices(detections, iou_threshold, score_threshold)
+            .into_iter()
+            .map(|(idx, confidence)| Detection::new(detections[idx].bbox, detections[idx].class_id, confidence))
+            .collect()
+    }
+
+    /// Applies the strategy and returns the indices of the kept detections with their confidences.
+    ///
+    /// Same as [`NmsStrategy::apply`], useful when detections carry extra per-box data
+    /// (e.g. mask coefficients or keypoints) stored alongside them.
+    ///
+    /// # Returns
+    /// (index into `detections`, confidence) pairs, sorted by descending confidence
+    pub fn apply_indices(
+        &self,
+        detections: &[Detection],
+        iou_threshold: f32,
+        score_threshold: f32,
+    ) -> Vec<(usize, f32)> {
+        suppress(self, detections, iou_threshold, score_threshold)
+    }
+
+    /// Applies the strategy separately to the detections of each class.
+    ///
+    /// Used by multi-label outputs, where one object may carry several labels
+    /// that must not suppress each other.
+    ///
+    /// # Returns
+    /// Kept detections grouped by ascending class ID, each group sorted by descending confidence
+    pub fn apply_per_class(
+        &self,
+        detections: &[Detection],
+        iou_threshold: f32,
+        score_threshold: f32,
+    ) -> Vec<Detection> {
+        self.apply_per_class_indices(detections, iou_threshold, score_threshold)
+            .into_iter()
+            .map(|(idx, confidence)| Detection::new(detections[idx].bbox, detections[idx].class_id, confidence))
+            .collect()
+    }
+
+    /// Applies the strategy separately to each class and returns the indices of the kept
+    /// detections with their confidences.
+    ///
+    /// Same as [`NmsStrategy::apply_per_class`], the indices refer to `detections`.
+    ///
+    /// # Returns
+    /// (index, confidence) pairs grouped by ascending class ID, each group sorted by descending confidence
+    pub fn apply_per_class_indices(
+        &self,
+        detections: &[Detection],
+        iou_threshold: f32,
+        score_threshold: f32,
+    ) -> Vec<(usize, f32)> {
+        let max_class = detections.iter().map(|d| d.class_id).max().unwrap_or(0);
+        let mut by_class: Vec<Vec<usize>> = vec![Vec::new(); max_class + 1];
+        for (idx, detection) in detections.iter().enumerate() {
+            by_class[detection.class_id].push(idx);
+        }
+
+        by_class
+            .into_iter()
+            .filter(|members| !members.is_empty())
+            .flat_map(|members| {
+                let group: Vec<Detection> = members.iter().map(|&idx| detections[idx].clone()).collect();
+                self.apply_indices(&group, iou_threshold, score_threshold)
+                    .into_iter()
+                    .map(|(idx, confidence)| (members[idx], confidence))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Applies the strategy to oriented detections using rotated IoU.
+    ///
+    /// For [`NmsStrategy::DIoU`] the center distance is normalized by the axis-aligned box
+    /// enclosing both rotated boxes.
+    ///
+    /// # Returns
+    /// Kept detections with their (possibly decayed) confidences, sorted by descending confidence
+    pub fn apply_rotated(
+        &self,
+        detections: &[RotatedDetection],
+        iou_threshold: f32,
+        score_threshold: f32,
+    ) -> Vec<RotatedDetection> {
+        suppress(self, detections, iou_threshold, score_threshold)
+            .into_iter()
+            .map(|(idx, confidence)| RotatedDetection::new(detections[idx].rbox, detections[idx].class_id, confidence))
+            .collect()
+    }
+}
+
+/// Box geometry needed by the NMS strategies.
+trait NmsCandidate {
+    fn confidence(&self) -> f32;
+    fn class_id(&self) -> usize;
+    fn iou(&self, other: &Self) -> f32;
+    /// Squared center distance divided by the squared diagonal of the enclosing box
+    fn center_distance_penalty(&self, other: &Self) -> f32;
+}
+
+/// Squared center distance over the squared diagonal of the box enclosing `a` and `b`.
+fn normalized_center_distance(a: &BBox, b: &BBox, center_a: (f32, f32), center_b: (f32, f32)) -> f32 {
+    let enclosing_width = ((a.x + a.width).max(b.x + b.width) - a.x.min(b.x)) as f32;
+    let enclosing_height = ((a.y + a.height).max(b.y + b.height) - a.y.min(b.y)) as f32;
+    let diagonal = enclosing_width * enclosing_width + enclosing_height * enclosing_height;
+    if diagonal <= 0.0 {
+        return 0.0;
+    }
+    let dx = center_a.0 - center_b.0;
+    let dy = center_a.1 - center_b.1;
+    (dx * dx + dy * dy) / diagonal
+}
+
+impl NmsCandidate for Detection {
+    fn confidence(&self) -> f32 {
+        self.confidence
+    }
+
+    fn class_id(&self) -> usize {
+        self.class_id
+    }
+
+    fn iou(&self, other: &Self) -> f32 {
+        self.bbox.iou(&other.bbox)
+    }
+
+    fn center_distance_penalty(&self, other: &Self) -> f32 {
+        normalized_center_distance(&self.bbox, &other.bbox, self.bbox.center(), other.bbox.center())
+    }
+}
+
+impl NmsCandidate for RotatedDetection {
+    fn confidence(&self) -> f32 {
+        self.confidence
+    }
+
+    fn class_id(&self) -> usize {
+        self.class_id
+    }
+
+    fn iou(&self, other: &Self) -> f32 {
+        // Cheap axis-aligned rejection before the polygon clipping
+        if self.rbox.bounding_box().iou(&other.rbox.bounding_box()) <= 0.0 {
+            return 0.0;
+        }
+        self.rbox.iou(&other.rbox)
+    }
+
+    fn center_distance_penalty(&self, other: &Self) -> f32 {
+        normalized_center_distance(
+            &self.rbox.bounding_box(),
+            &other.rbox.bounding_box(),
+            (self.rbox.cx, self.rbox.cy),
+            (other.rbox.cx, other.rbox.cy),
+        )
+    }
+}
+
+/// Runs `strategy` over `candidates`, returns (index, confidence) of the kept ones by descending confidence.
+fn suppress<T: NmsCandidate>(
+    strategy: &NmsStrategy,
+    candidates: &[T],
+    iou_threshold: f32,
+    score_threshold: f32,
+) -> Vec<(usize, f32)> {
+    let mut order: Vec<usize> = (0..candidates.len()).collect();
+    order.sort_by(|&a, &b| {
+        candidates[b].confidence()
+            .partial_cmp(&candidates[a].confidence())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    match *strategy {
+        NmsStrategy::Greedy => suppress_hard(candidates, &order, |a, b| a.iou(b) > iou_threshold),
+        NmsStrategy::ClassAware => suppress_hard(candidates, &order, |a, b| {
+            a.class_id() == b.class_id() && a.iou(b) > iou_threshold
+        }),
+        NmsStrategy::DIoU => suppress_hard(candidates, &order, |a, b| {
+            a.iou(b) - a.center_distance_penalty(b) > iou_threshold
+        }),
+        NmsStrategy::SoftLinear => suppress_soft(candidates, order, score_threshold, |iou| {
+            if iou > iou_threshold { 1.0 - iou } else { 1.0 }
+        }),
+        NmsStrategy::SoftGaussian { sigma } => suppress_soft(candidates, order, score_threshold, |iou| {
+            (-(iou * iou) / sigma).exp()
+        }),
+        NmsStrategy::Matrix { sigma } => suppress_matrix(candidates, &order, sigma, score_threshold),
+    }
+}
+
+/// Hard NMS: walks boxes by descending confidence, a box is kept unless `overlaps` a kept one.
+fn suppress_hard<T: NmsCandidate>(
+    candidates: &[T],
+    order: &[usize],
+    overlaps: impl Fn(&T, &T) -> bool,
+) -> Vec<(usize, f32)> {
+    let mut keep: Vec<usize> = Vec::new();
+    for &idx in order {
+        if !keep.iter().any(|&kept| overlaps(&candidates[kept], &candidates[idx])) {
+            keep.push(idx);
+        }
+    }
+    keep.into_iter().map(|idx| (idx, candidates[idx].confidence())).collect()
+}
+
+/// Soft-NMS: repeatedly keeps the best remaining box and decays the others by `decay(IoU)`.
+fn suppress_soft<T: NmsCandidate>(
+    candidates: &[T],
+    order: Vec<usize>,
+    score_threshold: f32,
+    decay: impl Fn(f32) -> f32,
+) -> Vec<(usize, f32)> {
+    let mut remaining: Vec<(usize, f32)> = order.into_iter().map(|idx| (idx, candidates[idx].confidence())).collect();
+    let mut keep = Vec::new();
+    while !remaining.is_empty() {
+        let best = remaining
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(pos, _)| pos)
+            .unwrap_or(0);
+        let (best_idx, best_score) = remaining.swap_remove(best);
+        keep.push((best_idx, best_score));
+
+        for (idx, score) in remaining.iter_mut() {
+            *score *= decay(candidates[best_idx].iou(&candidates[*idx]));
+        }
+        remaining.retain(|&(_, score)| score >= score_threshold);
+    }
+    keep
+}
+
+/// Matrix NMS: decays every box by its overlap with higher scored boxes, compensated by
+/// how much those boxes are themselves suppressed.
+fn suppress_matrix<T: NmsCandidate>(
+    candidates: &[T],
+    order: &[usize],
+    sigma: f32,
+    score_threshold: f32,
+) -> Vec<(usize, f32)> {
+    let n = order.len();
+    // ious[i][j] for i < j: overlap of the j-th best box with the better i-th box
+    let mut ious = vec![vec![0.0f32; n]; n];
+    for i in 0..n {
+        for j in (i + 1)..n {
+            ious[i][j] = candidates[order[i]].iou(&candidates[order[j]]);
+        }
+    }
+    let compensation: Vec<f32> = (0..n)
+        .map(|i| (0..i).map(|k| ious[k][i]).fold(0.0, f32::max))
+        .collect();
+
+    let mut keep: Vec<(usize, f32)> = (0..n)
+        .filter_map(|j| {
+            let decay = (0..j)
+                .map(|i| (-sigma * (ious[i][j] * ious[i][j] - compensation[i] * compensation[i])).exp())
+                .fold(1.0, f32::min);
+            let score = candidates[order[j]].confidence() * decay;
+            (score >= score_threshold).then_some((order[j], score))
+        })
+        .collect();
+    keep.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    keep
+}
+
+/// How [`weighted_boxes_fusion`] computes the confidence of a fused box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FusionConfidence {
+    /// Average weighted confidence of the cluster, scaled down when fewer models than
+    /// available contributed a box
+    #[default]
+    Avg,
+    /// Highest weighted confidence of the cluster, divided by the largest model weight
+    Max,
+    /// Box-level average, then scaled by the total weight of the models present in the cluster
+    BoxAndModelAvg,
+    /// Average where the weights of models with no box in the cluster count as zero confidence
+    AbsentModelAwareAvg,
+}
+
+/// A box taking part in a fusion cluster.
+#[derive(Debug, Clone, Copy)]
+struct FusionMember {
+    model: usize,
+    /// Confidence multiplied by the model weight
+    score: f32,
+    weight: f32,
+    corners: [f32; 4],
+}
+
+/// Fuses a cluster into one box: coordinates averaged with confidences as weights.
+fn fuse_cluster(cluster: &[FusionMember], conf_type: FusionConfidence) -> ([f32; 4], f32) {
+    let score_sum: f32 = cluster.iter().map(|m| m.score).sum();
+    let mut corners = [0.0f32; 4];
+    for member in cluster {
+        for (corner, value) in corners.iter_mut().zip(member.corners) {
+            *corner += member.score * value;
+        }
+    }
+    if score_sum > 0.0 {
+        corners.iter_mut().for_each(|corner| *corner /= score_sum);
+    }
+    let confidence = match conf_type {
+        FusionConfidence::Max => cluster.iter().map(|m| m.score).fold(0.0, f32::max),
+        _ => score_sum / cluster.len() as f32,
+    };
+    (corners, confidence)
+}
+
+/// Merges the detections of several models with Weighted Boxes Fusion.
+///
+/// Unlike NMS, which keeps one of the overlapping boxes, WBF averages the coordinates of
+/// all boxes of the same class matching with IoU above `iou_threshold`, weighting each box
+/// by its confidence times the weight of the model that produced it. Boxes found by
+/// several models are therefore both better localized and more confident.
+///
+/// See the ref. https://arxiv.org/abs/1910.13302
+///
+/// # Arguments
+/// * `detections` - detections of each model (one entry per model)
+/// * `weights` - weight of each model; missing entries (e.g. an empty slice) count as 1.0
+/// * `iou_threshold` - IoU above which a box joins a cluster (0.0 - 1.0)
+/// * `skip_threshold` - detections below this confidence are ignored
+/// * `conf_type` - how the fused confidence is computed
+///
+/// # Returns
+/// Fused detections, sorted by descending confidence
+pub fn weighted_boxes_fusion(
+    detections: &[Vec<Detection>],
+    weights: &[f32],
+    iou_threshold: f32,
+    skip_threshold: f32,
+    conf_type: FusionConfidence,
+) -> Vec<Detection> {
+    let weights: Vec<f32> = (0..detections.len())
+        .map(|model| weights.get(model).copied().unwrap_or(1.0))
+        .collect();
+    let weights_sum: f32 = weights.iter().sum();
+    let weights_max = weights.iter().copied().fold(0.0, f32::max);
+
+    // Group boxes by class
+    let mut by_class: Vec<Vec<FusionMember>> = Vec::new();
+    for (model, model_detections) in detections.iter().enumerate() {
+        for detection in model_detections.iter().filter(|d| d.confidence >= skip_threshold) {
+            if by_class.len() <= detection.class_id {
+                by_class.resize(detection.class_id + 1, Vec::new());
+            }
+            let bbox = detection.bbox;
+            by_class[detection.class_id].push(FusionMember {
+                model,
+                score: detection.confidence * weights[model],
+                weight: weights[model],
+                corners: [
+                    bbox.x as f32,
+                    bbox.y as f32,
+                    (bbox.x + bbox.width) as f32,
+                    (bbox.y + bbox.height) as f32,
+                ],
+            });
+        }
+    }
+
+    let mut fused = Vec::new();
+    for (class_id, mut members) in by_class.into_iter().enumerate() {
+        members.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut clusters: Vec<Vec<FusionMember>> = Vec::new();
+        let mut fused_boxes: Vec<([f32; 4], f32)> = Vec::new();
+        for member in members {
+            let member_bbox = corners_to_bbox(member.corners);
+            let best = fused_boxes
+                .iter()
+                .enumerate()
+                .map(|(idx, (corners, _))| (idx, corners_to_bbox(*corners).iou(&member_bbox)))
+                .filter(|&(_, iou)| iou > iou_threshold)
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+            match best {
+                Some((idx, _)) => {
+                    clusters[idx].push(member);
+                    fused_boxes[idx] = fuse_cluster(&clusters[idx], conf_type);
+                }
+                None => {
+                    clusters.push(vec![member]);
+                    fused_boxes.push((member.corners, member.score));
+                }
+            }
+        }
+
+        for (cluster, (corners, confidence)) in clusters.iter().zip(fused_boxes) {
+            let cluster_size = cluster.len() as f32;
+            let cluster_weight: f32 = cluster.iter().map(|m| m.weight).sum();
+            let mut models: Vec<usize> = cluster.iter().map(|m| m.model).collect();
+            models.sort_unstable();
+            models.dedup();
+            let models_weight: f32 = models.iter().map(|&model| weights[model]).sum();
+
+            let confidence = match conf_type {
+                FusionConfidence::Avg => confidence * cluster_size.min(weights.len() as f32) / weights_sum,
+                FusionConfidence::Max => confidence / weights_max,
+                FusionConfidence::BoxAndModelAvg => {
+                    confidence * cluster_size / cluster_weight * models_weight / weights_sum
+                }
+                FusionConfidence::AbsentModelAwareAvg => {
+                    confidence * cluster_size / (cluster_weight + weights_sum - models_weight)
+                }
+            };
+            fused.push(Detection::new(corners_to_bbox(corners), class_id, confidence));
+        }
+    }
+
+    fused.sort_by(|a, b| {
+        b.confidence
+            .partial_cmp(&a.confidence)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    fused
+}
+
+/// Converts (x1, y1, x2, y2) corners to a `BBox`.
+fn corners_to_bbox(corners: [f32; 4]) -> BBox {
+    let [x1, y1, x2, y2] = corners;
+    BBox::new(x1.round() as i32, y1.round() as i32, (x2 - x1).round() as i32, (y2 - y1).round() as i32)
+}
+
 /// Filters detections by confidence threshold.
 ///
 /// # Arguments
@@ -155,10 +687,31 @@ pub fn filter_by_class(detections: &[Detection], class_filter: &[usize]) -> Vec<
         .collect()
 }
 
+/// Keeps the `k` highest confidence detections, sorted by descending confidence.
+///
+/// Used instead of NMS by set-prediction models (e.g. DETR family) whose
+/// queries do not produce duplicates.
+///
+/// # Arguments
+/// * `detections` - detections to select from
+/// * `k` - Maximum number of detections to keep
+///
+/// # Returns
+/// At most `k` detections
+pub fn top_k(mut detections: Vec<Detection>, k: usize) -> Vec<Detection> {
+    detections.sort_by(|a, b| {
+        b.confidence
+            .partial_cmp(&a.confidence)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    detections.truncate(k);
+    detections
+}
+
 /// Converts detections to the output format (Vec<BBox>, Vec<usize>, Vec<f32>).
 ///
 /// This matches the existing API format.
-pub fn detections_to_vecs(detections: Vec<Detection>) -> (Vec<BBox>, Vec<usize>, Vec<f32>) {
+pub fn detections_to_vecs(detections: Vec<Detection>) -> DetectionVecs {
     let mut bboxes = Vec::with_capacity(detections.len());
     let mut class_ids = Vec::with_capacity(detections.len());
     let mut confidences = Vec::with_capacity(detections.len());
@@ -188,6 +741,235 @@ pub fn argmax(values: &[f32]) -> Option<(usize, f32)> {
         .map(|(idx, &val)| (idx, val))
 }
 
+/// Computes the softmax of a slice of logits.
+pub fn softmax(logits: &[f32]) -> Vec<f32> {
+    let max = logits.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let exps: Vec<f32> = logits.iter().map(|&v| (v - max).exp()).collect();
+    let sum: f32 = exps.iter().sum();
+    exps.into_iter().map(|v| v / sum).collect()
+}
+
+/// Returns the `k` highest scores as (class_id, score), sorted by descending score.
+///
+/// # Arguments
+/// * `scores` - Per-class scores
+/// * `k` - Maximum number of classes to return
+pub fn top_k_classes(scores: &[f32], k: usize) -> Vec<(usize, f32)> {
+    let mut indexed: Vec<(usize, f32)> = scores.iter().copied().enumerate().collect();
+    indexed.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    indexed.truncate(k);
+    indexed
+}
+
+/// Configuration of a single anchor-based YOLO head (Darknet `[yolo]` section).
+///
+/// Used by [`decode_yolo_layer`] to decode raw `[1, A * (5 + num_classes), H, W]`
+/// outputs of models exported without the decoding step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct YoloLayer {
+    /// All anchors of the network as (width, height) in input pixels
+    pub anchors: Vec<(f32, f32)>,
+    /// Indices into `anchors` used by this layer
+    pub mask: Vec<usize>,
+    /// Downsampling factor of the layer (network input size / grid size)
+    pub stride: u32,
+    /// Grid sensitivity factor (`scale_x_y` in Darknet). `1.0` disables it
+    pub scale_x_y: f32,
+    /// Scaled-YOLOv4 / YOLOv7 box parametrization (`new_coords=1` in Darknet)
+    pub new_coords: bool,
+    /// Number of classes
+    pub num_classes: usize,
+    /// Report the objectness as the detection confidence, as OpenCV's Darknet importer
+    /// does, instead of `objectness * class_score`
+    pub objectness_confidence: bool,
+}
+
+impl YoloLayer {
+    /// Creates a new layer configuration with `scale_x_y = 1.0` and classic box parametrization.
+    ///
+    /// # Arguments
+    /// * `anchors` - All anchors of the network as (width, height) in input pixels
+    /// * `mask` - Indices into `anchors` used by this layer
+    /// * `stride` - Downsampling factor of the layer
+    /// * `num_classes` - Number of classes
+    pub fn new(anchors: Vec<(f32, f32)>, mask: Vec<usize>, stride: u32, num_classes: usize) -> Self {
+        Self {
+            anchors,
+            mask,
+            stride,
+            scale_x_y: 1.0,
+            new_coords: false,
+            num_classes,
+            objectness_confidence: false,
+        }
+    }
+
+    /// Sets the grid sensitivity factor (`scale_x_y`).
+    pub fn with_scale_x_y(mut self, scale_x_y: f32) -> Self {
+        self.scale_x_y = scale_x_y;
+        self
+    }
+
+    /// Enables or disables the `new_coords` box parametrization.
+    pub fn with_new_coords(mut self, new_coords: bool) -> Self {
+        self.new_coords = new_coords;
+        self
+    }
+
+    /// Enables or disables reporting the objectness alone as the detection confidence.
+    pub fn with_objectness_confidence(mut self, objectness_confidence: bool) -> Self {
+        self.objectness_confidence = objectness_confidence;
+        self
+    }
+}
+
+/// Error type for [`decode_yolo_layer`]: the output does not match the layer configuration.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecodeError {
+    /// Output shape is not `[1, A * (5 + num_classes), H, W]`
+    ShapeMismatch {
+        num_anchors: usize,
+        num_classes: usize,
+        shape: Vec<usize>,
+    },
+    /// Mask index does not refer to one of the anchors
+    MaskOutOfRange { index: usize, num_anchors: usize },
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecodeError::ShapeMismatch { num_anchors, num_classes, shape } => write!(
+                f,
+                "Expected shape [1, {}, H, W] for {} anchors and {} classes, got {:?}",
+                num_anchors * (5 + num_classes),
+                num_anchors,
+                num_classes,
+                shape
+            ),
+            DecodeError::MaskOutOfRange { index, num_anchors } => {
+                write!(f, "Mask index {} is out of range for {} anchors", index, num_anchors)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Logistic sigmoid, maps raw logits to (0, 1).
+#[inline]
+pub fn sigmoid(x: f32) -> f32 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Decodes a raw anchor-based YOLO head output into detections.
+///
+/// The output is expected in `[1, A * (5 + num_classes), H, W]` layout where `A` is
+/// the number of anchors in `layer.mask`. Box centers are decoded relative to the
+/// grid cell (with `scale_x_y` grid sensitivity), sizes relative to the anchors,
+/// and the detection confidence is `sigmoid(objectness) * sigmoid(class_score)`, or
+/// `sigmoid(objectness)` alone with `objectness_confidence`.
+/// With `new_coords` the outputs are treated as already activated, as Darknet does.
+///
+/// # Arguments
+/// * `output` - Raw layer output
+/// * `layer` - Layer configuration (anchors, mask, stride, ...)
+/// * `conf_threshold` - Minimum confidence to keep a detection
+/// * `meta` - Preprocessing metadata used to map boxes back to the original image
+///
+/// # Returns
+/// Detections in original image coordinates (before NMS), or a [`DecodeError`] when the
+/// output does not match the layer configuration
+pub fn decode_yolo_layer(
+    output: &ndarray::ArrayViewD<f32>,
+    layer: &YoloLayer,
+    conf_threshold: f32,
+    meta: &PreprocessMeta,
+) -> Result<Vec<Detection>, DecodeError> {
+    let shape = output.shape();
+    let num_anchors = layer.mask.len();
+    let num_features = 5 + layer.num_classes;
+
+    if shape.len() != 4 || shape[0] != 1 || shape[1] != num_anchors * num_features {
+        return Err(DecodeError::ShapeMismatch {
+            num_anchors,
+            num_classes: layer.num_classes,
+            shape: shape.to_vec(),
+        });
+    }
+
+    let anchors = layer
+        .mask
+        .iter()
+        .map(|&idx| {
+            layer.anchors.get(idx).copied().ok_or(DecodeError::MaskOutOfRange {
+                index: idx,
+                num_anchors: layer.anchors.len(),
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let grid_height = shape[2];
+    let grid_width = shape[3];
+    let stride = layer.stride as f32;
+    let activate = |x: f32| if layer.new_coords { x } else { sigmoid(x) };
+    let grid_offset = 0.5 * (layer.scale_x_y - 1.0);
+
+    let mut detections = Vec::new();
+
+    for (a, &(anchor_w, anchor_h)) in anchors.iter().enumerate() {
+        let base = a * num_features;
+        for row in 0..grid_height {
+            for col in 0..grid_width {
+                let objectness = activate(output[[0, base + 4, row, col]]);
+                if objectness < conf_threshold {
+                    continue;
+                }
+
+                let class_scores: Vec<f32> = (0..layer.num_classes)
+                    .map(|c| activate(output[[0, base + 5 + c, row, col]]))
+                    .collect();
+                let Some((class_idx, class_score)) = argmax(&class_scores) else {
+                    continue;
+                };
+                let score = if layer.objectness_confidence {
+                    if class_score <= 0.0 {
+                        continue;
+                    }
+                    objectness
+                } else {
+                    objectness * class_score
+                };
+                if score < conf_threshold {
+                    continue;
+                }
+
+                let tx = activate(output[[0, base, row, col]]);
+                let ty = activate(output[[0, base + 1, row, col]]);
+                let tw = output[[0, base + 2, row, col]];
+                let th = output[[0, base + 3, row, col]];
+
+                let cx = (col as f32 + tx * layer.scale_x_y - grid_offset) * stride;
+                let cy = (row as f32 + ty * layer.scale_x_y - grid_offset) * stride;
+                let (w, h) = if layer.new_coords {
+                    (tw * tw * 4.0 * anchor_w, th * th * 4.0 * anchor_h)
+                } else {
+                    (tw.exp() * anchor_w, th.exp() * anchor_h)
+                };
+
+                let (x_orig, y_orig, w_orig, h_orig) = meta.inverse_transform(cx, cy, w, h);
+                detections.push(Detection::new(
+                    BBox::from_center(x_orig, y_orig, w_orig, h_orig),
+                    class_idx,
+                    score,
+                ));
+            }
+        }
+    }
+
+    Ok(detections)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -236,6 +1018,18 @@ mod tests {
         assert_eq!(result_low.len(), 1);
     }
 
+    #[test]
+    fn test_nms_indices() {
+        let detections = vec![
+            Detection::new(BBox::new(0, 0, 100, 100), 0, 0.8),
+            Detection::new(BBox::new(200, 200, 50, 50), 0, 0.7),
+            Detection::new(BBox::new(5, 5, 100, 100), 0, 0.9),
+        ];
+
+        let result = nms_indices(&detections, 0.5);
+        assert_eq!(result, vec![2, 1]);
+    }
+
     #[test]
     fn test_nms_class_aware() {
         let detections = vec![
@@ -254,6 +1048,135 @@ mod tests {
         assert_eq!(result_regular.len(), 1);
     }
 
+    #[test]
+    fn test_nms_rotated() {
+        use std::f32::consts::FRAC_PI_2;
+
+        let detections = vec![
+            RotatedDetection::new(RotatedBBox::new(50.0, 50.0, 100.0, 10.0, 0.0), 0, 0.9),
+            // Same bar, slightly rotated: suppressed
+            RotatedDetection::new(RotatedBBox::new(50.0, 50.0, 100.0, 10.0, 0.05), 0, 0.8),
+            // Crossing bar: kept
+            RotatedDetection::new(RotatedBBox::new(50.0, 50.0, 100.0, 10.0, FRAC_PI_2), 0, 0.7),
+        ];
+
+        let result = nms_rotated(&detections, 0.5);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].confidence, 0.9);
+        assert_eq!(result[1].confidence, 0.7);
+    }
+
+    #[test]
+    fn test_nms_strategy_hard() {
+        let detections = vec![
+            make_detection(0, 0, 10, 10, 0, 0.9),
+            make_detection(0, 0, 10, 10, 1, 0.8),
+            make_detection(100, 100, 10, 10, 0, 0.7),
+        ];
+
+        let greedy = NmsStrategy::default().apply(&detections, 0.5, 0.25);
+        let expected: Vec<usize> = nms_indices(&detections, 0.5);
+        assert_eq!(greedy.iter().map(|d| d.confidence).collect::<Vec<_>>(), expected.iter().map(|&i| detections[i].confidence).collect::<Vec<_>>());
+
+        let class_aware = NmsStrategy::ClassAware.apply_indices(&detections, 0.5, 0.25);
+        assert_eq!(class_aware, vec![(0, 0.9), (1, 0.8), (2, 0.7)]);
+
+        let per_class = NmsStrategy::Greedy.apply_per_class(&detections, 0.5, 0.25);
+        assert_eq!(per_class.iter().map(|d| d.class_id).collect::<Vec<_>>(), vec![0, 0, 1]);
+
+        let per_class = NmsStrategy::Greedy.apply_per_class_indices(&detections, 0.5, 0.25);
+        assert_eq!(per_class, vec![(0, 0.9), (2, 0.7), (1, 0.8)]);
+    }
+
+    #[test]
+    fn test_nms_strategy_diou() {
+        // IoU = 0.5, centers 5 px apart in a 10x20 enclosing box: DIoU = 0.5 - 25 / 500 = 0.45
+        let detections = vec![
+            make_detection(0, 0, 10, 10, 0, 0.9),
+            make_detection(0, 0, 10, 20, 0, 0.8),
+        ];
+
+        assert_eq!(NmsStrategy::Greedy.apply(&detections, 0.48, 0.25).len(), 1);
+        assert_eq!(NmsStrategy::DIoU.apply(&detections, 0.48, 0.25).len(), 2);
+    }
+
+    #[test]
+    fn test_nms_strategy_soft() {
+        let detections = vec![
+            make_detection(0, 0, 10, 10, 0, 0.9),
+            // IoU = 25 / 175
+            make_detection(5, 5, 10, 10, 0, 0.8),
+            // Duplicate of the first box
+            make_detection(0, 0, 10, 10, 0, 0.7),
+        ];
+
+        let linear = NmsStrategy::SoftLinear.apply(&detections, 0.1, 0.25);
+        assert_eq!(linear.len(), 2);
+        assert!((linear[1].confidence - 0.8 * (1.0 - 25.0 / 175.0)).abs() < 1e-5);
+
+        let gaussian = NmsStrategy::SoftGaussian { sigma: 0.5 };
+        // Duplicate decays to 0.7 * exp(-2) ~ 0.095
+        assert_eq!(gaussian.apply(&detections, 0.5, 0.25).len(), 2);
+        assert_eq!(gaussian.apply(&detections, 0.5, 0.05).len(), 3);
+    }
+
+    #[test]
+    fn test_nms_strategy_matrix() {
+        let detections = vec![
+            make_detection(0, 0, 10, 10, 0, 0.9),
+            make_detection(0, 0, 10, 10, 0, 0.8),
+            make_detection(100, 100, 10, 10, 0, 0.7),
+        ];
+
+        let result = NmsStrategy::Matrix { sigma: 2.0 }.apply_indices(&detections, 0.5, 0.25);
+        assert_eq!(result, vec![(0, 0.9), (2, 0.7)]);
+
+        let result = NmsStrategy::Matrix { sigma: 2.0 }.apply_indices(&detections, 0.5, 0.05);
+        assert_eq!(result.len(), 3);
+        assert!((result[2].1 - 0.8 * (-2.0f32).exp()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_nms_strategy_rotated() {
+        let detections = vec![
+            RotatedDetection::new(RotatedBBox::new(50.0, 50.0, 100.0, 10.0, 0.0), 0, 0.9),
+            RotatedDetection::new(RotatedBBox::new(50.0, 50.0, 100.0, 10.0, 0.05), 0, 0.8),
+            RotatedDetection::new(RotatedBBox::new(50.0, 50.0, 100.0, 10.0, std::f32::consts::FRAC_PI_2), 0, 0.7),
+        ];
+
+        // The slightly rotated duplicate is removed, the perpendicular box barely overlaps
+        let greedy = NmsStrategy::Greedy.apply_rotated(&detections, 0.5, 0.25);
+        assert_eq!(greedy.iter().map(|d| d.confidence).collect::<Vec<_>>(), vec![0.9, 0.7]);
+    }
+
+    #[test]
+    fn test_weighted_boxes_fusion() {
+        let detections = vec![
+            vec![make_detection(0, 0, 10, 10, 0, 0.9), make_detection(100, 100, 10, 10, 1, 0.6)],
+            // IoU with the first box of the first model is 64 / 136
+            vec![make_detection(2, 2, 10, 10, 0, 0.3), make_detection(200, 200, 10, 10, 0, 0.1)],
+        ];
+
+        let fused = weighted_boxes_fusion(&detections, &[], 0.4, 0.2, FusionConfidence::Avg);
+        assert_eq!(fused.len(), 2);
+        // Coordinates weighted by confidence: (0 * 0.9 + 2 * 0.3) / 1.2 = 0.5
+        assert_eq!(fused[0].bbox, BBox::new(1, 1, 10, 10));
+        assert_eq!(fused[0].class_id, 0);
+        assert!((fused[0].confidence - 0.6).abs() < 1e-5);
+        // Found by one model out of two
+        assert!((fused[1].confidence - 0.3).abs() < 1e-5);
+
+        let fused = weighted_boxes_fusion(&detections, &[2.0, 1.0], 0.4, 0.2, FusionConfidence::Max);
+        assert!((fused[0].confidence - 0.9).abs() < 1e-5);
+
+        let fused = weighted_boxes_fusion(&detections, &[], 0.4, 0.2, FusionConfidence::AbsentModelAwareAvg);
+        assert!((fused[0].confidence - 0.6).abs() < 1e-5);
+        assert!((fused[1].confidence - 0.3).abs() < 1e-5);
+
+        let fused = weighted_boxes_fusion(&detections, &[], 0.4, 0.2, FusionConfidence::BoxAndModelAvg);
+        assert!((fused[0].confidence - 0.6).abs() < 1e-5);
+    }
+
     #[test]
     fn test_filter_by_confidence() {
         let detections = vec![
@@ -287,6 +1210,97 @@ mod tests {
         assert!((max - 0.9).abs() < 0.001);
     }
 
+    #[test]
+    fn test_softmax_and_top_k_classes() {
+        let probs = softmax(&[1.0, 3.0, 2.0]);
+        assert!((probs.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+        assert!(probs[1] > probs[2] && probs[2] > probs[0]);
+
+        let top = top_k_classes(&probs, 2);
+        assert_eq!(top.iter().map(|&(id, _)| id).collect::<Vec<_>>(), vec![1, 2]);
+        assert!(top_k_classes(&probs, 10).len() == 3);
+    }
+
+    #[test]
+    fn test_top_k() {
+        let detections = vec![
+            Detection::new(BBox::new(0, 0, 10, 10), 0, 0.3),
+            Detection::new(BBox::new(0, 0, 10, 10), 1, 0.9),
+            Detection::new(BBox::new(0, 0, 10, 10), 2, 0.6),
+        ];
+
+        let result = top_k(detections, 2);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].class_id, 1);
+        assert_eq!(result[1].class_id, 2);
+    }
+
+    #[test]
+    fn test_decode_yolo_layer() {
+        // 1 anchor, 1 class, 2x2 grid
+        let layer = YoloLayer::new(vec![(5.0, 5.0), (10.0, 20.0)], vec![1], 32, 1);
+        let mut output = ndarray::Array4::<f32>::from_elem((1, 6, 2, 2), -10.0);
+        // Cell (row 1, col 0): tx = ty = tw = th = 0, strong objectness and class
+        output[[0, 0, 1, 0]] = 0.0;
+        output[[0, 1, 1, 0]] = 0.0;
+        output[[0, 2, 1, 0]] = 0.0;
+        output[[0, 3, 1, 0]] = 0.0;
+        output[[0, 4, 1, 0]] = 5.0;
+        output[[0, 5, 1, 0]] = 5.0;
+
+        let detections = decode_yolo_layer(&output.view().into_dyn(), &layer, 0.5, &PreprocessMeta::identity(64, 64)).unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].class_id, 0);
+        // Center (16, 48), anchor-sized box
+        assert_eq!(detections[0].bbox, BBox::new(11, 38, 10, 20));
+        assert!((detections[0].confidence - sigmoid(5.0) * sigmoid(5.0)).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_decode_yolo_layer_objectness_confidence() {
+        let layer = YoloLayer::new(vec![(10.0, 10.0)], vec![0], 8, 1).with_objectness_confidence(true);
+        let mut output = ndarray::Array4::<f32>::zeros((1, 6, 1, 1));
+        output[[0, 4, 0, 0]] = 5.0;
+        output[[0, 5, 0, 0]] = -1.0;
+
+        let detections = decode_yolo_layer(&output.view().into_dyn(), &layer, 0.5, &PreprocessMeta::identity(64, 64)).unwrap();
+
+        // objectness * class_score would be ~0.27, below the threshold
+        assert_eq!(detections.len(), 1);
+        assert!((detections[0].confidence - sigmoid(5.0)).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_decode_yolo_layer_scale_x_y() {
+        let layer = YoloLayer::new(vec![(10.0, 10.0)], vec![0], 8, 1).with_scale_x_y(2.0);
+        let mut output = ndarray::Array4::<f32>::from_elem((1, 6, 1, 1), 10.0);
+        // sigmoid(tx) ~ 1.0 => (0 + 1.0 * 2.0 - 0.5) * 8 = 12
+        output[[0, 2, 0, 0]] = 0.0;
+        output[[0, 3, 0, 0]] = 0.0;
+
+        let detections = decode_yolo_layer(&output.view().into_dyn(), &layer, 0.5, &PreprocessMeta::identity(64, 64)).unwrap();
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].bbox.center(), (12.0, 12.0));
+    }
+
+    #[test]
+    fn test_decode_yolo_layer_invalid_shape() {
+        let layer = YoloLayer::new(vec![(10.0, 10.0)], vec![0], 8, 80);
+        let output = ndarray::Array4::<f32>::zeros((1, 6, 13, 13));
+        assert_eq!(
+            decode_yolo_layer(&output.view().into_dyn(), &layer, 0.5, &PreprocessMeta::identity(64, 64)).err(),
+            Some(DecodeError::ShapeMismatch { num_anchors: 1, num_classes: 80, shape: vec![1, 6, 13, 13] })
+        );
+
+        let layer = YoloLayer::new(vec![(10.0, 10.0)], vec![1], 8, 1);
+        assert_eq!(
+            decode_yolo_layer(&output.view().into_dyn(), &layer, 0.5, &PreprocessMeta::identity(64, 64)).err(),
+            Some(DecodeError::MaskOutOfRange { index: 1, num_anchors: 1 })
+        );
+    }
+
     #[test]
     fn test_detections_to_vecs() {
         let detections = vec![
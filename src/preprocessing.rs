@@ -3,11 +3,20 @@
 //! This module provides pure-Rust image preprocessing functions that work
 //! without OpenCV. These functions prepare images for inference.
 
-use ndarray::Array4;
-use crate::image_buffer::ImageBuffer;
+use crate::rotated_bbox::RotatedBBox;
+
+/// ImageNet per-channel mean (RGB), for inputs normalized to 0.0 - 1.0.
+pub const IMAGENET_MEAN: [f32; 3] = [0.485, 0.456, 0.406];
+
+/// ImageNet per-channel standard deviation (RGB), for inputs normalized to 0.0 - 1.0.
+pub const IMAGENET_STD: [f32; 3] = [0.229, 0.224, 0.225];
 
 /// Metadata from letterbox preprocessing, needed to reverse the transformation.
+///
+/// Build it with [`LetterboxMeta::new`]: the struct is `#[non_exhaustive]` so that
+/// padding fields can be added without breaking downstream code.
 #[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
 pub struct LetterboxMeta {
     /// Scale factor applied to the image
     pub scale: f32,
@@ -15,6 +24,10 @@ pub struct LetterboxMeta {
     pub pad_left: i32,
     /// Padding added to the top side
     pub pad_top: i32,
+    /// Padding added to the right side
+    pub pad_right: i32,
+    /// Padding added to the bottom side
+    pub pad_bottom: i32,
     /// Original image width
     pub original_width: i32,
     /// Original image height
@@ -22,6 +35,66 @@ pub struct LetterboxMeta {
 }
 
 impl LetterboxMeta {
+    /// Computes the letterbox of an `original_width` x `original_height` image into
+    /// a `target_width` x `target_height` input.
+    ///
+    /// Without a stride the image is padded to the full target size. With a stride it
+    /// is only padded to the smallest rectangle whose sides are multiples of the stride
+    /// (Ultralytics `auto=True`, for a target size that is a multiple of the stride),
+    /// which suits models exported with dynamic axes.
+    /// When the padding is odd, the extra pixel goes to the right/bottom side.
+    pub fn new(
+        original_width: i32,
+        original_height: i32,
+        target_width: i32,
+        target_height: i32,
+        stride: Option<i32>,
+    ) -> Self {
+        let scale = f32::min(
+            target_width as f32 / original_width as f32,
+            target_height as f32 / original_height as f32,
+        );
+        let new_width = (original_width as f32 * scale).round() as i32;
+        let new_height = (original_height as f32 * scale).round() as i32;
+
+        let (pad_width, pad_height) = match stride {
+            Some(stride) if stride > 0 => (
+                (target_width - new_width).rem_euclid(stride),
+                (target_height - new_height).rem_euclid(stride),
+            ),
+            _ => (target_width - new_width, target_height - new_height),
+        };
+
+        Self {
+            scale,
+            pad_left: pad_width / 2,
+            pad_top: pad_height / 2,
+            pad_right: pad_width - pad_width / 2,
+            pad_bottom: pad_height - pad_height / 2,
+            original_width,
+            original_height,
+        }
+    }
+
+    /// Returns the size of the scaled image before padding as (width, height).
+    #[inline]
+    pub fn resized_size(&self) -> (i32, i32) {
+        (
+            (self.original_width as f32 * self.scale).round() as i32,
+            (self.original_height as f32 * self.scale).round() as i32,
+        )
+    }
+
+    /// Returns the size of the padded image as (width, height).
+    #[inline]
+    pub fn padded_size(&self) -> (i32, i32) {
+        let (width, height) = self.resized_size();
+        (
+            width + self.pad_left + self.pad_right,
+            height + self.pad_top + self.pad_bottom,
+        )
+    }
+
     /// Transforms coordinates from model output space back to original image space.
     #[inline]
     pub fn inverse_transform(&self, x: f32, y: f32, w: f32, h: f32) -> (f32, f32, f32, f32) {
@@ -32,6 +105,15 @@ impl LetterboxMeta {
             h / self.scale,
         )
     }
+
+    /// Transforms a point from original image space to model input space.
+    #[inline]
+    pub fn transform_point(&self, x: f32, y: f32) -> (f32, f32) {
+        (
+            x * self.scale + self.pad_left as f32,
+            y * self.scale + self.pad_top as f32,
+        )
+    }
 }
 
 /// Metadata from stretch preprocessing.
@@ -58,6 +140,12 @@ impl StretchMeta {
             h * self.scale_y,
         )
     }
+
+    /// Transforms a point from original image space to model input space.
+    #[inline]
+    pub fn transform_point(&self, x: f32, y: f32) -> (f32, f32) {
+        (x / self.scale_x, y / self.scale_y)
+    }
 }
 
 /// Union type for preprocessing metadata.
@@ -76,12 +164,79 @@ impl PreprocessMeta {
             PreprocessMeta::Stretch(meta) => meta.inverse_transform(x, y, w, h),
         }
     }
+
+    /// Transforms a point from original image space to model input space.
+    #[inline]
+    pub fn transform_point(&self, x: f32, y: f32) -> (f32, f32) {
+        match self {
+            PreprocessMeta::Letterbox(meta) => meta.transform_point(x, y),
+            PreprocessMeta::Stretch(meta) => meta.transform_point(x, y),
+        }
+    }
+
+    /// Transforms a rotated box from model output space back to original image space.
+    ///
+    /// Letterbox scales uniformly so only the center and size change. Stretch scales axes
+    /// independently, which turns a rotated rectangle into a parallelogram: the result keeps
+    /// the transformed width edge (length and direction) and the parallelogram area.
+    pub fn inverse_transform_rotated(&self, rbox: &RotatedBBox) -> RotatedBBox {
+        let (cx, cy, sx, sy) = self.inverse_transform(rbox.cx, rbox.cy, 1.0, 1.0);
+        if (sx - sy).abs() <= f32::EPSILON * sx.abs().max(sy.abs()) {
+            return RotatedBBox::new(cx, cy, rbox.width * sx, rbox.height * sy, rbox.angle);
+        }
+
+        let (sin, cos) = rbox.angle.sin_cos();
+        let width_edge = (rbox.width * cos * sx, rbox.width * sin * sy);
+        let height_edge = (-rbox.height * sin * sx, rbox.height * cos * sy);
+
+        let width = width_edge.0.hypot(width_edge.1);
+        let area = (width_edge.0 * height_edge.1 - width_edge.1 * height_edge.0).abs();
+        let height = if width > 0.0 { area / width } else { rbox.height * sy };
+        let angle = if width > 0.0 { width_edge.1.atan2(width_edge.0) } else { rbox.angle };
+
+        RotatedBBox::new(cx, cy, width, height, angle)
+    }
+
+    /// Returns the original image size as (width, height).
+    #[inline]
+    pub fn original_size(&self) -> (i32, i32) {
+        match self {
+            PreprocessMeta::Letterbox(meta) => (meta.original_width, meta.original_height),
+            PreprocessMeta::Stretch(meta) => (meta.original_width, meta.original_height),
+        }
+    }
+}
+
+/// Metadata fixtures shared by the decoder tests.
+#[cfg(test)]
+impl PreprocessMeta {
+    /// Stretch metadata of an image already at the network size: coordinates are unchanged.
+    pub(crate) fn identity(width: i32, height: i32) -> Self {
+        Self::stretch((width, height), (width, height))
+    }
+
+    /// Stretch metadata of an `original` (width, height) image resized to `input`.
+    pub(crate) fn stretch(original: (i32, i32), input: (i32, i32)) -> Self {
+        PreprocessMeta::Stretch(StretchMeta {
+            scale_x: original.0 as f32 / input.0 as f32,
+            scale_y: original.1 as f32 / input.1 as f32,
+            original_width: original.0,
+            original_height: original.1,
+        })
+    }
+
+    /// Letterbox metadata of an `original` (width, height) image padded to `input`.
+    pub(crate) fn letterbox(original: (i32, i32), input: (i32, i32)) -> Self {
+        PreprocessMeta::Letterbox(LetterboxMeta::new(original.0, original.1, input.0, input.1, None))
+    }
 }
 
 // Pure Rust preprocessing using the `image` crate
 #[cfg(feature = "ort-backend")]
 mod image_preprocessing {
     use super::*;
+    use ndarray::Array4;
+    use crate::image_buffer::ImageBuffer;
     use image::{imageops::FilterType, Rgb, RgbImage};
 
     /// Resizes an image to the target size by stretching (may distort aspect ratio).
@@ -107,48 +262,111 @@ mod image_preprocessing {
     }
 
     /// Resizes an image while preserving aspect ratio and padding with gray.
+    ///
+    /// The image is padded to the full target size, as fixed-shape models expect.
+    /// [`resize_letterbox_auto`] is the stride-multiple variant for dynamic axes, kept
+    /// as a separate function so that this signature stays unchanged.
     pub fn resize_letterbox(
         img: &ImageBuffer,
         target_width: u32,
         target_height: u32,
     ) -> (ImageBuffer, LetterboxMeta) {
         let (orig_height, orig_width, _) = img.shape();
-
-        let scale = f32::min(
-            target_width as f32 / orig_width as f32,
-            target_height as f32 / orig_height as f32,
+        let meta = LetterboxMeta::new(
+            orig_width as i32,
+            orig_height as i32,
+            target_width as i32,
+            target_height as i32,
+            None,
         );
+        (apply_letterbox(img, &meta), meta)
+    }
 
-        let new_width = (orig_width as f32 * scale).round() as u32;
-        let new_height = (orig_height as f32 * scale).round() as u32;
-
-        let pad_left = ((target_width - new_width) / 2) as i32;
-        let pad_top = ((target_height - new_height) / 2) as i32;
+    /// Resizes an image while preserving aspect ratio and pads it with gray to the
+    /// smallest rectangle whose sides are multiples of `stride`.
+    ///
+    /// The result fits in `target_width` x `target_height` but is usually smaller
+    /// (e.g. 640x384 instead of 640x640 for a 16:9 image), so it is meant for models
+    /// exported with dynamic input axes.
+    pub fn resize_letterbox_auto(
+        img: &ImageBuffer,
+        target_width: u32,
+        target_height: u32,
+        stride: u32,
+    ) -> (ImageBuffer, LetterboxMeta) {
+        let (orig_height, orig_width, _) = img.shape();
+        let meta = LetterboxMeta::new(
+            orig_width as i32,
+            orig_height as i32,
+            target_width as i32,
+            target_height as i32,
+            Some(stride as i32),
+        );
+        (apply_letterbox(img, &meta), meta)
+    }
 
-        let meta = LetterboxMeta {
-            scale,
-            pad_left,
-            pad_top,
-            original_width: orig_width as i32,
-            original_height: orig_height as i32,
-        };
+    /// Scales and pads an image as described by the letterbox metadata.
+    fn apply_letterbox(img: &ImageBuffer, meta: &LetterboxMeta) -> ImageBuffer {
+        let (new_width, new_height) = meta.resized_size();
+        let (padded_width, padded_height) = meta.padded_size();
 
         let dyn_img = img.to_dynamic_image();
-        let resized = dyn_img.resize_exact(new_width, new_height, FilterType::Triangle);
+        let resized = dyn_img.resize_exact(new_width as u32, new_height as u32, FilterType::Triangle);
 
         // Create padded image with gray background (114, 114, 114)
-        let mut padded = RgbImage::from_pixel(target_width, target_height, Rgb([114, 114, 114]));
+        let mut padded = RgbImage::from_pixel(
+            padded_width as u32,
+            padded_height as u32,
+            Rgb([114, 114, 114]),
+        );
 
         let resized_rgb = resized.to_rgb8();
-        for y in 0..new_height {
-            for x in 0..new_width {
+        for y in 0..new_height as u32 {
+            for x in 0..new_width as u32 {
                 let pixel = resized_rgb.get_pixel(x, y);
-                padded.put_pixel(x + pad_left as u32, y + pad_top as u32, *pixel);
+                padded.put_pixel(x + meta.pad_left as u32, y + meta.pad_top as u32, *pixel);
             }
         }
 
-        let result = ImageBuffer::from_rgb_image(padded);
-        (result, meta)
+        ImageBuffer::from_rgb_image(padded)
+    }
+
+    /// Resizes an image so it covers the target size, then crops the center.
+    ///
+    /// This is the usual classification preprocessing: aspect ratio is preserved
+    /// and the borders of the longer side are dropped.
+    pub fn resize_center_crop(
+        img: &ImageBuffer,
+        target_width: u32,
+        target_height: u32,
+    ) -> ImageBuffer {
+        let orig_width = img.width() as u32;
+        let orig_height = img.height() as u32;
+
+        let scale = (target_width as f32 / orig_width as f32)
+            .max(target_height as f32 / orig_height as f32);
+        let new_width = ((orig_width as f32 * scale).round() as u32).max(target_width);
+        let new_height = ((orig_height as f32 * scale).round() as u32).max(target_height);
+
+        let dyn_img = img.to_dynamic_image();
+        let resized = dyn_img.resize_exact(new_width, new_height, FilterType::Triangle);
+        let cropped = resized.crop_imm(
+            (new_width - target_width) / 2,
+            (new_height - target_height) / 2,
+            target_width,
+            target_height,
+        );
+
+        ImageBuffer::from_rgb_image(cropped.to_rgb8())
+    }
+
+    /// Applies per-channel `(x - mean) / std` normalization to an NCHW tensor in place.
+    ///
+    /// See [`IMAGENET_MEAN`] and [`IMAGENET_STD`] for the usual values.
+    pub fn normalize_mean_std(tensor: &mut Array4<f32>, mean: [f32; 3], std: [f32; 3]) {
+        for (c, mut channel) in tensor.axis_iter_mut(ndarray::Axis(1)).enumerate().take(3) {
+            channel.mapv_inplace(|v| (v - mean[c]) / std[c]);
+        }
     }
 
     /// Converts an ImageBuffer (RGB) to a normalized float32 tensor in NCHW format.
@@ -199,24 +417,113 @@ mod image_preprocessing {
         tensor
     }
 
-    /// Full preprocessing pipeline: resize + normalize.
-    pub fn preprocess(
+    /// Converts an ImageBuffer (RGB) to an unnormalized float32 tensor in NCHW format
+    /// with BGR channel order.
+    ///
+    /// Pixel values are kept in the 0-255 range, as expected by YOLOX exports.
+    pub fn to_bgr_nchw_tensor_unnormalized(img: &ImageBuffer) -> Array4<f32> {
+        let (height, width, _channels) = img.shape();
+        let data = img.as_array();
+
+        let mut tensor = Array4::<f32>::zeros((1, 3, height, width));
+
+        for h in 0..height {
+            for w in 0..width {
+                tensor[[0, 0, h, w]] = data[[h, w, 2]] as f32;
+                tensor[[0, 1, h, w]] = data[[h, w, 1]] as f32;
+                tensor[[0, 2, h, w]] = data[[h, w, 0]] as f32;
+            }
+        }
+
+        tensor
+    }
+
+    /// Converts an ImageBuffer (RGB) to a uint8 tensor in NHWC format.
+    ///
+    /// This is the layout expected by TensorFlow Object Detection API exports.
+    pub fn to_nhwc_u8_tensor(img: &ImageBuffer) -> Array4<u8> {
+        img.as_array().to_owned().insert_axis(ndarray::Axis(0))
+    }
+
+    /// Converts an ImageBuffer (RGB) to a normalized float32 tensor in NHWC format.
+    pub fn to_nhwc_tensor(img: &ImageBuffer) -> Array4<f32> {
+        img.as_array().mapv(|v| v as f32 / 255.0).insert_axis(ndarray::Axis(0))
+    }
+
+    /// Resizes an image to the target size using letterbox or stretch mode.
+    pub fn resize(
         img: &ImageBuffer,
         target_width: u32,
         target_height: u32,
         use_letterbox: bool,
-    ) -> (Array4<f32>, PreprocessMeta) {
-        let (resized, meta) = if use_letterbox {
+    ) -> (ImageBuffer, PreprocessMeta) {
+        if use_letterbox {
             let (resized, meta) = resize_letterbox(img, target_width, target_height);
             (resized, PreprocessMeta::Letterbox(meta))
         } else {
             let (resized, meta) = resize_stretch(img, target_width, target_height);
             (resized, PreprocessMeta::Stretch(meta))
-        };
+        }
+    }
 
+    /// Full preprocessing pipeline: resize + normalize.
+    pub fn preprocess(
+        img: &ImageBuffer,
+        target_width: u32,
+        target_height: u32,
+        use_letterbox: bool,
+    ) -> (Array4<f32>, PreprocessMeta) {
+        let (resized, meta) = resize(img, target_width, target_height, use_letterbox);
         let tensor = to_nchw_tensor(&resized);
         (tensor, meta)
     }
+
+    /// Batch preprocessing pipeline: every image is resized and normalized, then the
+    /// tensors are stacked into one `[B, 3, H, W]` tensor.
+    ///
+    /// Returns the metadata of each image, in input order.
+    pub fn preprocess_batch(
+        images: &[ImageBuffer],
+        target_width: u32,
+        target_height: u32,
+        use_letterbox: bool,
+    ) -> (Array4<f32>, Vec<PreprocessMeta>) {
+        let mut tensor = Array4::zeros((images.len(), 3, target_height as usize, target_width as usize));
+        let mut metas = Vec::with_capacity(images.len());
+        for (i, img) in images.iter().enumerate() {
+            let (single, meta) = preprocess(img, target_width, target_height, use_letterbox);
+            tensor
+                .index_axis_mut(ndarray::Axis(0), i)
+                .assign(&single.index_axis(ndarray::Axis(0), 0));
+            metas.push(meta);
+        }
+        (tensor, metas)
+    }
+
+    /// Preprocessing pipeline for models with dynamic input axes: minimal-rectangle
+    /// letterbox (see [`resize_letterbox_auto`]) + normalize.
+    pub fn preprocess_letterbox_auto(
+        img: &ImageBuffer,
+        target_width: u32,
+        target_height: u32,
+        stride: u32,
+    ) -> (Array4<f32>, PreprocessMeta) {
+        let (resized, meta) = resize_letterbox_auto(img, target_width, target_height, stride);
+        let tensor = to_nchw_tensor(&resized);
+        (tensor, PreprocessMeta::Letterbox(meta))
+    }
+
+    /// Preprocessing pipeline for models expecting raw BGR input: resize only, no `/255`.
+    pub fn preprocess_bgr_unnormalized(
+        img: &ImageBuffer,
+        target_width: u32,
+        target_height: u32,
+        use_letterbox: bool,
+    ) -> (Array4<f32>, PreprocessMeta) {
+        let (resized, meta) = resize(img, target_width, target_height, use_letterbox);
+        let tensor = to_bgr_nchw_tensor_unnormalized(&resized);
+        (tensor, meta)
+    }
 }
 
 #[cfg(feature = "ort-backend")]
@@ -226,7 +533,8 @@ pub use image_preprocessing::*;
 #[cfg(feature = "ort-backend")]
 mod tests {
     use super::*;
-    use ndarray::Array3;
+    use ndarray::{Array3, Array4};
+    use crate::image_buffer::ImageBuffer;
 
     #[test]
     fn test_resize_stretch() {
@@ -254,6 +562,83 @@ mod tests {
         assert!(meta.pad_top > 0 || meta.pad_left > 0);
     }
 
+    #[test]
+    fn test_resize_letterbox_auto() {
+        let data = Array3::from_elem((720, 1280, 3), 128u8);
+        let img = ImageBuffer::from_rgb(data);
+
+        let (resized, meta) = resize_letterbox_auto(&img, 640, 640, 32);
+
+        // 1280x720 scales to 640x360, padded to the next multiple of 32
+        assert_eq!(resized.width(), 640);
+        assert_eq!(resized.height(), 384);
+        assert_eq!((meta.pad_left, meta.pad_right), (0, 0));
+        assert_eq!((meta.pad_top, meta.pad_bottom), (12, 12));
+        assert_eq!(meta.padded_size(), (640, 384));
+    }
+
+    #[test]
+    fn test_letterbox_meta_asymmetric_padding() {
+        // 100x75 into 64x64: scaled to 64x48, 16 rows of padding
+        let full = LetterboxMeta::new(100, 75, 64, 64, None);
+        assert_eq!((full.pad_top, full.pad_bottom), (8, 8));
+        assert_eq!(full.padded_size(), (64, 64));
+
+        // Odd padding: 100x77 scales to 64x49, 15 rows split 7 / 8
+        let odd = LetterboxMeta::new(100, 77, 64, 64, None);
+        assert_eq!((odd.pad_top, odd.pad_bottom), (7, 8));
+        assert_eq!(odd.padded_size(), (64, 64));
+
+        // With stride 10, 100x75 into 60x60 scales to 60x45, padded to 60x50 (2 / 3 rows)
+        let auto = LetterboxMeta::new(100, 75, 60, 60, Some(10));
+        assert_eq!((auto.pad_left, auto.pad_right), (0, 0));
+        assert_eq!((auto.pad_top, auto.pad_bottom), (2, 3));
+        assert_eq!(auto.padded_size(), (60, 50));
+
+        // Inverse transform stays exact
+        let (x, y) = auto.transform_point(50.0, 37.5);
+        let (ox, oy, _, _) = auto.inverse_transform(x, y, 0.0, 0.0);
+        assert!((ox - 50.0).abs() < 1e-4 && (oy - 37.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_preprocess_batch() {
+        let dark = ImageBuffer::from_rgb(Array3::from_elem((480, 640, 3), 0u8));
+        let bright = ImageBuffer::from_rgb(Array3::from_elem((100, 100, 3), 255u8));
+
+        let (tensor, metas) = preprocess_batch(&[dark, bright], 64, 64, false);
+
+        assert_eq!(tensor.shape(), &[2, 3, 64, 64]);
+        assert_eq!(tensor[[0, 0, 32, 32]], 0.0);
+        assert_eq!(tensor[[1, 0, 32, 32]], 1.0);
+        assert_eq!(metas[0].original_size(), (640, 480));
+        assert_eq!(metas[1].original_size(), (100, 100));
+    }
+
+    #[test]
+    fn test_resize_center_crop() {
+        // Left half black, right half white
+        let mut data = Array3::zeros((100, 200, 3));
+        data.slice_mut(ndarray::s![.., 100.., ..]).fill(255u8);
+        let img = ImageBuffer::from_rgb(data);
+
+        let cropped = resize_center_crop(&img, 50, 50);
+
+        assert_eq!(cropped.width(), 50);
+        assert_eq!(cropped.height(), 50);
+        // The crop covers the middle of the image: black on the left, white on the right
+        assert_eq!(cropped.as_array()[[25, 0, 0]], 0);
+        assert_eq!(cropped.as_array()[[25, 49, 0]], 255);
+    }
+
+    #[test]
+    fn test_normalize_mean_std() {
+        let mut tensor = Array4::<f32>::from_elem((1, 3, 2, 2), 0.5);
+        normalize_mean_std(&mut tensor, IMAGENET_MEAN, IMAGENET_STD);
+        assert!((tensor[[0, 0, 1, 1]] - (0.5 - 0.485) / 0.229).abs() < 1e-5);
+        assert!((tensor[[0, 2, 0, 0]] - (0.5 - 0.406) / 0.225).abs() < 1e-5);
+    }
+
     #[test]
     fn test_to_nchw_tensor() {
         let mut data = Array3::zeros((2, 3, 3));
@@ -267,12 +652,43 @@ mod tests {
         assert!((tensor[[0, 0, 0, 0]] - 1.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_to_nhwc_tensors() {
+        let mut data = Array3::zeros((2, 3, 3));
+        data[[1, 2, 0]] = 255;
+
+        let img = ImageBuffer::from_rgb(data);
+        let tensor_u8 = to_nhwc_u8_tensor(&img);
+        let tensor_f32 = to_nhwc_tensor(&img);
+
+        assert_eq!(tensor_u8.shape(), &[1, 2, 3, 3]);
+        assert_eq!(tensor_u8[[0, 1, 2, 0]], 255);
+        assert!((tensor_f32[[0, 1, 2, 0]] - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_to_bgr_nchw_tensor_unnormalized() {
+        let mut data = Array3::zeros((2, 3, 3));
+        // R=200, B=10 at (0,0)
+        data[[0, 0, 0]] = 200;
+        data[[0, 0, 2]] = 10;
+
+        let img = ImageBuffer::from_rgb(data);
+        let tensor = to_bgr_nchw_tensor_unnormalized(&img);
+
+        assert_eq!(tensor.shape(), &[1, 3, 2, 3]);
+        assert!((tensor[[0, 0, 0, 0]] - 10.0).abs() < 0.001);
+        assert!((tensor[[0, 2, 0, 0]] - 200.0).abs() < 0.001);
+    }
+
     #[test]
     fn test_letterbox_inverse_transform() {
         let meta = LetterboxMeta {
             scale: 0.5,
             pad_left: 10,
             pad_top: 20,
+            pad_right: 10,
+            pad_bottom: 20,
             original_width: 640,
             original_height: 480,
         };
@@ -285,6 +701,58 @@ mod tests {
         assert!((h - 100.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_letterbox_transform_point_roundtrip() {
+        let meta = PreprocessMeta::Letterbox(LetterboxMeta {
+            scale: 0.5,
+            pad_left: 10,
+            pad_top: 20,
+            pad_right: 10,
+            pad_bottom: 20,
+            original_width: 640,
+            original_height: 480,
+        });
+
+        let (x, y) = meta.transform_point(200.0, 200.0);
+        assert!((x - 110.0).abs() < 0.01);
+        assert!((y - 120.0).abs() < 0.01);
+
+        let (x, y, _, _) = meta.inverse_transform(x, y, 0.0, 0.0);
+        assert!((x - 200.0).abs() < 0.01);
+        assert!((y - 200.0).abs() < 0.01);
+        assert_eq!(meta.original_size(), (640, 480));
+    }
+
+    #[test]
+    fn test_inverse_transform_rotated() {
+        use std::f32::consts::FRAC_PI_2;
+
+        let letterbox = PreprocessMeta::Letterbox(LetterboxMeta {
+            scale: 0.5,
+            pad_left: 10,
+            pad_top: 20,
+            pad_right: 10,
+            pad_bottom: 20,
+            original_width: 640,
+            original_height: 480,
+        });
+        let rbox = letterbox.inverse_transform_rotated(&RotatedBBox::new(110.0, 120.0, 40.0, 20.0, 0.3));
+        assert_eq!(rbox, RotatedBBox::new(200.0, 200.0, 80.0, 40.0, 0.3));
+
+        // Stretch x2 horizontally: a vertical bar becomes wider, stays vertical
+        let stretch = PreprocessMeta::Stretch(StretchMeta {
+            scale_x: 2.0,
+            scale_y: 1.0,
+            original_width: 1280,
+            original_height: 640,
+        });
+        let rbox = stretch.inverse_transform_rotated(&RotatedBBox::new(100.0, 100.0, 40.0, 10.0, FRAC_PI_2));
+        assert!((rbox.cx - 200.0).abs() < 1e-3);
+        assert!((rbox.width - 40.0).abs() < 1e-3);
+        assert!((rbox.height - 20.0).abs() < 1e-3);
+        assert!((rbox.angle - FRAC_PI_2).abs() < 1e-3);
+    }
+
     #[test]
     fn test_bgr_hwc_to_rgb_nchw_tensor() {
         // Create a 2x2 BGR image
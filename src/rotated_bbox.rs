@@ -0,0 +1,214 @@
+//! Rotated bounding box type for oriented object detection (OBB).
+//!
+//! This module provides a backend-agnostic rotated bounding box with
+//! polygon-based IoU, for models that predict an angle along with the box.
+
+use crate::bbox::BBox;
+
+/// A rotated bounding box.
+///
+/// The box is centered on `(cx, cy)` and rotated by `angle` radians around its
+/// center, clockwise in image coordinates (y axis pointing down). `width` is
+/// measured along the rotated x axis, `height` along the rotated y axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RotatedBBox {
+    /// X coordinate of the center
+    pub cx: f32,
+    /// Y coordinate of the center
+    pub cy: f32,
+    /// Width of the box
+    pub width: f32,
+    /// Height of the box
+    pub height: f32,
+    /// Rotation angle in radians
+    pub angle: f32,
+}
+
+impl RotatedBBox {
+    /// Creates a new rotated bounding box.
+    #[inline]
+    pub fn new(cx: f32, cy: f32, width: f32, height: f32, angle: f32) -> Self {
+        Self { cx, cy, width, height, angle }
+    }
+
+    /// Returns the area of the box.
+    #[inline]
+    pub fn area(&self) -> f32 {
+        self.width * self.height
+    }
+
+    /// Returns the four corners of the box, in clockwise order (y axis pointing down)
+    /// starting from the top-left one of the unrotated box.
+    pub fn corners(&self) -> [(f32, f32); 4] {
+        let (sin, cos) = self.angle.sin_cos();
+        let hw = self.width / 2.0;
+        let hh = self.height / 2.0;
+        [(-hw, -hh), (hw, -hh), (hw, hh), (-hw, hh)].map(|(dx, dy)| {
+            (
+                self.cx + dx * cos - dy * sin,
+                self.cy + dx * sin + dy * cos,
+            )
+        })
+    }
+
+    /// Returns the smallest axis-aligned box containing this box.
+    pub fn bounding_box(&self) -> BBox {
+        let corners = self.corners();
+        let (mut x1, mut y1) = corners[0];
+        let (mut x2, mut y2) = corners[0];
+        for &(x, y) in &corners[1..] {
+            x1 = x1.min(x);
+            y1 = y1.min(y);
+            x2 = x2.max(x);
+            y2 = y2.max(y);
+        }
+        let x = x1.floor() as i32;
+        let y = y1.floor() as i32;
+        BBox::new(x, y, x2.ceil() as i32 - x, y2.ceil() as i32 - y)
+    }
+
+    /// Calculates the Intersection over Union (IoU) with another rotated box.
+    ///
+    /// The intersection is computed exactly by clipping one box polygon with the other.
+    pub fn iou(&self, other: &RotatedBBox) -> f32 {
+        let union_base = self.area() + other.area();
+        if union_base <= 0.0 {
+            return 0.0;
+        }
+
+        let intersection = polygon_area(&clip_polygon(&self.corners(), &other.corners()));
+        let union = union_base - intersection;
+        if union <= 0.0 {
+            return 0.0;
+        }
+
+        (intersection / union).clamp(0.0, 1.0)
+    }
+}
+
+impl From<BBox> for RotatedBBox {
+    fn from(bbox: BBox) -> Self {
+        let (cx, cy) = bbox.center();
+        Self::new(cx, cy, bbox.width as f32, bbox.height as f32, 0.0)
+    }
+}
+
+/// Signed area of a polygon (shoelace formula), positive for clockwise order with y pointing down.
+fn signed_area(polygon: &[(f32, f32)]) -> f32 {
+    let n = polygon.len();
+    (0..n)
+        .map(|i| {
+            let (x1, y1) = polygon[i];
+            let (x2, y2) = polygon[(i + 1) % n];
+            x1 * y2 - x2 * y1
+        })
+        .sum::<f32>()
+        / 2.0
+}
+
+fn polygon_area(polygon: &[(f32, f32)]) -> f32 {
+    if polygon.len() < 3 {
+        return 0.0;
+    }
+    signed_area(polygon).abs()
+}
+
+/// Clips `subject` by the convex polygon `clip` (Sutherland-Hodgman).
+fn clip_polygon(subject: &[(f32, f32)], clip: &[(f32, f32)]) -> Vec<(f32, f32)> {
+    // Inside test depends on the winding of the clip polygon
+    let orientation = signed_area(clip).signum();
+    if orientation == 0.0 {
+        return Vec::new();
+    }
+
+    let side = |a: (f32, f32), b: (f32, f32), p: (f32, f32)| {
+        orientation * ((b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0))
+    };
+
+    let mut output = subject.to_vec();
+    for i in 0..clip.len() {
+        if output.is_empty() {
+            break;
+        }
+        let a = clip[i];
+        let b = clip[(i + 1) % clip.len()];
+        let input = std::mem::take(&mut output);
+
+        for j in 0..input.len() {
+            let current = input[j];
+            let previous = input[(j + input.len() - 1) % input.len()];
+            let current_side = side(a, b, current);
+            let previous_side = side(a, b, previous);
+
+            if current_side >= 0.0 {
+                if previous_side < 0.0 {
+                    output.push(intersect(previous, current, previous_side, current_side));
+                }
+                output.push(current);
+            } else if previous_side >= 0.0 {
+                output.push(intersect(previous, current, previous_side, current_side));
+            }
+        }
+    }
+    output
+}
+
+/// Point of segment `p`-`q` where the side function crosses zero.
+#[inline]
+fn intersect(p: (f32, f32), q: (f32, f32), p_side: f32, q_side: f32) -> (f32, f32) {
+    let t = p_side / (p_side - q_side);
+    (p.0 + t * (q.0 - p.0), p.1 + t * (q.1 - p.1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_2;
+    use std::f32::consts::FRAC_PI_4;
+
+    #[test]
+    fn test_rotated_bbox_corners() {
+        let rbox = RotatedBBox::new(10.0, 10.0, 4.0, 2.0, FRAC_PI_2);
+        let corners = rbox.corners();
+        let expected = [(11.0, 8.0), (11.0, 12.0), (9.0, 12.0), (9.0, 8.0)];
+        for (corner, expected) in corners.iter().zip(expected) {
+            assert!((corner.0 - expected.0).abs() < 1e-4);
+            assert!((corner.1 - expected.1).abs() < 1e-4);
+        }
+        assert_eq!(rbox.bounding_box(), BBox::new(9, 8, 2, 4));
+    }
+
+    #[test]
+    fn test_rotated_iou_axis_aligned_matches_bbox() {
+        let a = BBox::new(0, 0, 10, 10);
+        let b = BBox::new(5, 5, 10, 10);
+        let iou = RotatedBBox::from(a).iou(&RotatedBBox::from(b));
+        assert!((iou - a.iou(&b)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_rotated_iou_full_and_no_overlap() {
+        let a = RotatedBBox::new(0.0, 0.0, 10.0, 4.0, 0.3);
+        assert!((a.iou(&a) - 1.0).abs() < 1e-4);
+
+        let b = RotatedBBox::new(50.0, 50.0, 10.0, 4.0, 0.3);
+        assert_eq!(a.iou(&b), 0.0);
+    }
+
+    #[test]
+    fn test_rotated_iou_cross() {
+        // Two 10x2 bars crossing at a right angle: intersection is 2x2
+        let a = RotatedBBox::new(0.0, 0.0, 10.0, 2.0, 0.0);
+        let b = RotatedBBox::new(0.0, 0.0, 10.0, 2.0, FRAC_PI_2);
+        assert!((a.iou(&b) - 4.0 / 36.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_rotated_iou_square_diamond() {
+        // Square and the same square rotated by 45 degrees: intersection is a regular octagon
+        let a = RotatedBBox::new(0.0, 0.0, 2.0, 2.0, 0.0);
+        let b = RotatedBBox::new(0.0, 0.0, 2.0, 2.0, FRAC_PI_4);
+        let octagon = 8.0 * (2.0f32.sqrt() - 1.0);
+        assert!((a.iou(&b) - octagon / (8.0 - octagon)).abs() < 1e-4);
+    }
+}